@@ -0,0 +1,206 @@
+//! Batch Transfers
+//!
+//! Per-leg confirmation tracking for multi-recipient transfers.
+
+use std::collections::HashMap;
+
+use crate::core::{Credits, NodeId, Timestamp};
+
+use super::messages::{CreditTransfer, TransferId};
+
+/// Local identifier for a batch of transfers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BatchId(pub u64);
+
+/// State of a single leg in a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegStatus {
+    /// Broadcast, awaiting the recipient's confirmation
+    Pending,
+    /// Recipient confirmed receipt
+    Confirmed,
+    /// Timed out unconfirmed and refunded
+    Failed,
+}
+
+/// Legs of a batch grouped by status
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchStatus {
+    pub confirmed: Vec<TransferId>,
+    pub pending: Vec<TransferId>,
+    pub failed: Vec<TransferId>,
+}
+
+impl BatchStatus {
+    /// Every leg has either confirmed or failed
+    pub fn is_settled(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// Net opposing transfers between each pair of nodes
+///
+/// All `(from, to, amount)` legs between the same two nodes collapse into at
+/// most one leg in the direction of the net flow, so A→B 50 and B→A 30
+/// become A→B 20. Every node's net position is unchanged. Pairs whose flows
+/// cancel exactly are dropped; the rest keep the order their pair first
+/// appeared in.
+pub fn net_transfers(legs: &[(NodeId, NodeId, Credits)]) -> Vec<(NodeId, NodeId, Credits)> {
+    // Signed flow from the pair's first-seen sender to its receiver
+    let mut pairs: Vec<(NodeId, NodeId, i128)> = Vec::new();
+    for (from, to, amount) in legs {
+        let amount = amount.amount as i128;
+        match pairs
+            .iter_mut()
+            .find(|(a, b, _)| (a, b) == (from, to) || (a, b) == (to, from))
+        {
+            Some((a, _, net)) if a == from => *net += amount,
+            Some((_, _, net)) => *net -= amount,
+            None => pairs.push((*from, *to, amount)),
+        }
+    }
+
+    pairs
+        .into_iter()
+        .filter(|(_, _, net)| *net != 0)
+        .map(|(a, b, net)| {
+            let amount = Credits::new(net.unsigned_abs().min(u64::MAX as u128) as u64);
+            if net > 0 {
+                (a, b, amount)
+            } else {
+                (b, a, amount)
+            }
+        })
+        .collect()
+}
+
+/// A batch of outgoing transfers created together
+#[derive(Debug, Clone)]
+pub struct TransferBatch {
+    pub id: BatchId,
+    pub legs: Vec<CreditTransfer>,
+    pub created: Timestamp,
+    status: HashMap<TransferId, LegStatus>,
+}
+
+impl TransferBatch {
+    pub fn new(id: BatchId, legs: Vec<CreditTransfer>, created: Timestamp) -> Self {
+        let status = legs.iter().map(|t| (t.id, LegStatus::Pending)).collect();
+        Self {
+            id,
+            legs,
+            created,
+            status,
+        }
+    }
+
+    pub fn leg_status(&self, transfer_id: &TransferId) -> Option<LegStatus> {
+        self.status.get(transfer_id).copied()
+    }
+
+    /// Mark a pending leg confirmed; returns false if it isn't pending here
+    pub fn confirm(&mut self, transfer_id: &TransferId) -> bool {
+        match self.status.get_mut(transfer_id) {
+            Some(status @ LegStatus::Pending) => {
+                *status = LegStatus::Confirmed;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Mark every pending leg failed, returning those legs
+    pub fn fail_pending(&mut self) -> Vec<CreditTransfer> {
+        let mut failed = Vec::new();
+        for leg in &self.legs {
+            if let Some(status @ LegStatus::Pending) = self.status.get_mut(&leg.id) {
+                *status = LegStatus::Failed;
+                failed.push(leg.clone());
+            }
+        }
+        failed
+    }
+
+    /// Legs grouped by status, in batch order
+    pub fn status(&self) -> BatchStatus {
+        let mut out = BatchStatus::default();
+        for leg in &self.legs {
+            match self.status[&leg.id] {
+                LegStatus::Pending => out.pending.push(leg.id),
+                LegStatus::Confirmed => out.confirmed.push(leg.id),
+                LegStatus::Failed => out.failed.push(leg.id),
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridge::Signature;
+
+    fn leg(to: u8, nonce: u64) -> CreditTransfer {
+        let from = NodeId::from_bytes([1u8; 32]);
+        let to = NodeId::from_bytes([to; 32]);
+        CreditTransfer {
+            id: TransferId::from_transfer(&from, &to, 10, nonce),
+            from,
+            to,
+            amount: 10,
+            nonce,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        }
+    }
+
+    #[test]
+    fn test_batch_leg_lifecycle() {
+        let legs = vec![leg(2, 1), leg(3, 2), leg(4, 3)];
+        let ids: Vec<TransferId> = legs.iter().map(|t| t.id).collect();
+        let mut batch = TransferBatch::new(BatchId(1), legs, Timestamp::now());
+
+        assert!(batch.confirm(&ids[0]));
+        assert!(!batch.confirm(&ids[0]));
+
+        let failed = batch.fail_pending();
+        assert_eq!(failed.len(), 2);
+        assert!(!batch.confirm(&ids[1]));
+
+        let status = batch.status();
+        assert_eq!(status.confirmed, vec![ids[0]]);
+        assert_eq!(status.failed, vec![ids[1], ids[2]]);
+        assert!(status.is_settled());
+    }
+
+    #[test]
+    fn test_net_transfers_preserves_positions() {
+        let a = NodeId::from_bytes([1u8; 32]);
+        let b = NodeId::from_bytes([2u8; 32]);
+        let c = NodeId::from_bytes([3u8; 32]);
+        let legs = vec![
+            (a, b, Credits::new(50)),
+            (b, c, Credits::new(10)),
+            (b, a, Credits::new(30)),
+            (c, b, Credits::new(10)),
+            (a, c, Credits::new(5)),
+        ];
+
+        let netted = net_transfers(&legs);
+        assert_eq!(
+            netted,
+            vec![(a, b, Credits::new(20)), (a, c, Credits::new(5))]
+        );
+
+        let position = |legs: &[(NodeId, NodeId, Credits)], node: NodeId| {
+            legs.iter().fold(0i128, |acc, (from, to, amount)| {
+                let amount = amount.amount as i128;
+                acc + if *to == node { amount } else { 0 } - if *from == node { amount } else { 0 }
+            })
+        };
+        for node in [a, b, c] {
+            assert_eq!(position(&legs, node), position(&netted, node));
+        }
+    }
+}
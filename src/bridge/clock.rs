@@ -0,0 +1,114 @@
+//! Clock Skew Estimation
+//!
+//! Per-peer clock offsets learned from message timestamps, so age checks
+//! hold up against peers whose clocks drift.
+
+use std::collections::HashMap;
+
+use crate::core::{NodeId, Timestamp};
+
+/// Smoothed estimate of how far each peer's clock runs ahead of ours
+///
+/// Each observation's offset is the sender's timestamp minus our receipt
+/// time, so it includes network latency. Estimates start at zero, are
+/// exponentially smoothed, move at most `max_step_ms` per observation, and
+/// are clamped to `max_skew_ms` either way. Only feed it timestamps the
+/// sender has signed.
+#[derive(Debug, Clone)]
+pub struct ClockSkewEstimator {
+    /// Weight of each new observation (0, 1]
+    smoothing: f64,
+    max_skew_ms: i64,
+    max_step_ms: f64,
+    offsets: HashMap<NodeId, f64>,
+}
+
+impl ClockSkewEstimator {
+    pub fn new(smoothing: f64, max_skew_ms: u64) -> Self {
+        let smoothing = if smoothing.is_nan() {
+            1.0
+        } else {
+            smoothing.clamp(f64::EPSILON, 1.0)
+        };
+        let max_skew_ms = max_skew_ms.min(i64::MAX as u64) as i64;
+        Self {
+            smoothing,
+            max_skew_ms,
+            max_step_ms: max_skew_ms as f64,
+            offsets: HashMap::new(),
+        }
+    }
+
+    /// Limit how far one observation can move an estimate (default:
+    /// `max_skew_ms`, unlimited within the clamp)
+    pub fn with_max_step(mut self, max_step_ms: u64) -> Self {
+        self.max_step_ms = max_step_ms as f64;
+        self
+    }
+
+    /// Fold in a message `node` stamped at `sent` that we received at
+    /// `received`, returning the updated offset
+    pub fn observe(&mut self, node: NodeId, sent: Timestamp, received: Timestamp) -> i64 {
+        let sample = (sent.millis as i128 - received.millis as i128)
+            .clamp(-(self.max_skew_ms as i128), self.max_skew_ms as i128)
+            as f64;
+        let offset = match self.offsets.get(&node) {
+            Some(previous) => {
+                let step = (self.smoothing * (sample - previous))
+                    .clamp(-self.max_step_ms, self.max_step_ms);
+                previous + step
+            }
+            None => sample.clamp(-self.max_step_ms, self.max_step_ms),
+        };
+        self.offsets.insert(node, offset);
+        offset.round() as i64
+    }
+
+    /// Estimated milliseconds `node`'s clock runs ahead (0 if never seen)
+    pub fn offset_ms(&self, node: &NodeId) -> i64 {
+        self.offsets
+            .get(node)
+            .map_or(0, |offset| offset.round() as i64)
+    }
+
+    /// A timestamp from `node` translated to our clock
+    pub fn adjust(&self, node: &NodeId, timestamp: Timestamp) -> Timestamp {
+        timestamp.shifted(-self.offset_ms(node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skew_estimate_smooths_and_clamps() {
+        let node = NodeId::from_bytes([2u8; 32]);
+        let mut skew = ClockSkewEstimator::new(0.5, 10_000);
+        let now = Timestamp::new(100_000);
+
+        // Peer clock 4s behind
+        assert_eq!(skew.observe(node, Timestamp::new(96_000), now), -4_000);
+        assert_eq!(skew.observe(node, Timestamp::new(98_000), now), -3_000);
+        assert_eq!(skew.adjust(&node, Timestamp::new(97_000)), now);
+        assert_eq!(Timestamp::new(97_000).age_with_skew(now, -3_000), 0);
+
+        // Wild outliers are clamped
+        let other = NodeId::from_bytes([3u8; 32]);
+        assert_eq!(skew.observe(other, Timestamp::new(0), now), -10_000);
+        assert_eq!(skew.offset_ms(&NodeId::from_bytes([4u8; 32])), 0);
+    }
+
+    #[test]
+    fn test_skew_estimate_moves_at_most_max_step() {
+        let node = NodeId::from_bytes([2u8; 32]);
+        let mut skew = ClockSkewEstimator::new(1.0, 60_000).with_max_step(1_000);
+        let now = Timestamp::new(100_000);
+
+        // A 50s jump is learned 1s at a time
+        assert_eq!(skew.observe(node, Timestamp::new(150_000), now), 1_000);
+        assert_eq!(skew.observe(node, Timestamp::new(150_000), now), 2_000);
+        assert_eq!(skew.observe(node, Timestamp::new(100_500), now), 1_000);
+        assert_eq!(skew.observe(node, Timestamp::new(100_500), now), 500);
+    }
+}
@@ -4,6 +4,8 @@
 
 use thiserror::Error;
 
+use crate::core::NodeId;
+
 /// Bridge errors
 #[derive(Debug, Error)]
 pub enum BridgeError {
@@ -35,9 +37,35 @@ pub enum BridgeError {
     #[error("Unknown topic: {0}")]
     UnknownTopic(String),
 
+    /// Message arrived on a topic other than its own
+    #[error("Topic mismatch: {expected} message received on {received}")]
+    TopicMismatch { expected: String, received: String },
+
     /// Node is isolated
     #[error("Node is isolated")]
     NodeIsolated,
+
+    /// Dispatch queue full and configured to reject
+    #[error("Dispatch queue full")]
+    QueueFull,
+
+    /// No gossip path to the destination under the current topology
+    #[error("No route to {0}")]
+    NoRoute(NodeId),
+
+    /// Requester has spent its entropy budget; retry once it refills
+    #[error("Entropy budget exhausted for {0}")]
+    EntropyBudgetExhausted(NodeId),
+}
+
+impl BridgeError {
+    /// Check if the error is transient and the operation may succeed on retry
+    ///
+    /// Network errors (e.g. gossip backpressure, insufficient peers) are
+    /// transient. Everything else is fatal for the message in question.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, BridgeError::Network(_))
+    }
 }
 
 /// Credit transfer errors
@@ -70,6 +98,22 @@ pub enum TransferError {
     /// Duplicate transfer
     #[error("Duplicate transfer ID")]
     Duplicate,
+
+    /// Batch leg between two other nodes
+    #[error("Transfer leg does not involve the local node")]
+    NotParticipant,
+
+    /// Idempotency key already used for a different recipient or amount
+    #[error("Idempotency key reused for a different transfer")]
+    KeyReused,
+
+    /// Authoritative ledger refused the transfer
+    #[error("Ledger rejected transfer: {0}")]
+    Ledger(crate::core::EnrError),
+
+    /// Reservation missing, expired, or already consumed
+    #[error("Reservation unavailable: {0}")]
+    Reservation(crate::core::EnrError),
 }
 
 #[cfg(test)]
@@ -84,4 +128,11 @@ mod tests {
         let err = TransferError::InsufficientBalance;
         assert!(err.to_string().contains("Insufficient"));
     }
+
+    #[test]
+    fn test_error_is_transient() {
+        assert!(BridgeError::Network("queue full".to_string()).is_transient());
+        assert!(!BridgeError::NotConnected.is_transient());
+        assert!(!BridgeError::Serialization("bad".to_string()).is_transient());
+    }
 }
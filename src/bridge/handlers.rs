@@ -60,6 +60,22 @@ pub trait CreditHandler: Send + Sync {
         node_id: NodeId,
         balance: u64,
     ) -> Result<(), BridgeError>;
+
+    /// Handle settlement request (default: ignore)
+    async fn handle_settlement_request(
+        &self,
+        _request: SettlementRequest,
+    ) -> Result<(), BridgeError> {
+        Ok(())
+    }
+
+    /// Handle settlement response (default: ignore)
+    async fn handle_settlement_response(
+        &self,
+        _response: SettlementResponse,
+    ) -> Result<(), BridgeError> {
+        Ok(())
+    }
 }
 
 /// Handler for septal messages
@@ -106,6 +122,8 @@ pub trait EnrMessageHandler:
                 CreditMessage::BalanceResponse { node_id, balance } => {
                     self.handle_balance_response(node_id, balance).await
                 }
+                CreditMessage::SettlementRequest(r) => self.handle_settlement_request(r).await,
+                CreditMessage::SettlementResponse(r) => self.handle_settlement_response(r).await,
             },
             EnrMessage::Septal(m) => match m {
                 SeptalMessage::FailureReport(r) => self.handle_failure_report(r).await,
@@ -0,0 +1,116 @@
+//! Bridge Event Log
+//!
+//! Append-only record of inbound and outbound ENR messages, for
+//! reproducing bridge behavior offline via [`EnrBridge::replay`].
+//!
+//! [`EnrBridge::replay`]: super::EnrBridge::replay
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::messages::EnrMessage;
+use crate::core::Timestamp;
+
+/// Direction of a logged message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogDirection {
+    /// Received via `handle_message`
+    Inbound,
+    /// Sent via `publish` or a broadcast loop
+    Outbound,
+}
+
+/// A single logged message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub direction: LogDirection,
+    pub topic: String,
+    pub message: EnrMessage,
+    /// When the bridge saw the message (used as "now" on replay)
+    pub recorded_at: Timestamp,
+}
+
+impl LogEntry {
+    pub fn new(direction: LogDirection, topic: impl Into<String>, message: EnrMessage) -> Self {
+        Self {
+            direction,
+            topic: topic.into(),
+            message,
+            recorded_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Sink receiving every logged message
+///
+/// Implementations must be append-only; entries arrive in the order the
+/// bridge observed them.
+pub trait EventSink: Send + Sync {
+    fn record(&self, entry: LogEntry);
+}
+
+/// In-memory event sink
+#[derive(Debug, Default)]
+pub struct MemoryEventLog {
+    entries: Mutex<Vec<LogEntry>>,
+}
+
+impl MemoryEventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of all recorded entries
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl EventSink for MemoryEventLog {
+    fn record(&self, entry: LogEntry) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridge::messages::CreditMessage;
+    use crate::core::NodeId;
+
+    #[test]
+    fn test_memory_event_log() {
+        let log = MemoryEventLog::new();
+        assert!(log.is_empty());
+
+        let message = EnrMessage::Credit(CreditMessage::BalanceResponse {
+            node_id: NodeId::from_bytes([1u8; 32]),
+            balance: 10,
+        });
+        log.record(LogEntry::new(
+            LogDirection::Inbound,
+            message.topic(),
+            message.clone(),
+        ));
+
+        let entries = log.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message, message);
+        assert_eq!(entries[0].direction, LogDirection::Inbound);
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! Message types for the ENR gossipsub protocol.
 
+use std::collections::{HashMap, HashSet};
+
+use bincode::Options;
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
 use serde::{Deserialize, Serialize};
 
 use crate::core::{NodeId, Timestamp};
@@ -11,12 +15,38 @@ use crate::septal::SeptalGateState;
 use super::error::BridgeError;
 use super::topics::EnrTopics;
 
+// ============================================================================
+// Wire Format
+// ============================================================================
+
+/// Maximum encoded message size, matching gossipsub's default transmit limit
+pub const MAX_MESSAGE_SIZE: u64 = 64 * 1024;
+
+/// Encoded transfers carried per settlement message, leaving headroom
+/// under [`MAX_MESSAGE_SIZE`] for the envelope and other fields
+pub const SETTLEMENT_CHUNK_BYTES: u64 = MAX_MESSAGE_SIZE - 4 * 1024;
+
+/// Pinned bincode configuration for the ENR wire format
+///
+/// Fixed-width little-endian integers with trailing bytes allowed — the
+/// same layout as `bincode::serialize` in 1.x — but spelled out so a
+/// bincode upgrade can't silently change the encoding.
+fn wire_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_little_endian()
+        .with_limit(MAX_MESSAGE_SIZE)
+        .allow_trailing_bytes()
+}
+
 // ============================================================================
 // Common Types
 // ============================================================================
 
-/// Cryptographic signature (placeholder - integrate with ed25519-dalek)
-/// Uses Vec<u8> for serde compatibility with large arrays
+/// Ed25519 signature over a message's signing bytes
+///
+/// A node's ID doubles as its ed25519 public key. Uses Vec<u8> for serde
+/// compatibility with large arrays
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signature(pub Vec<u8>);
 
@@ -46,6 +76,26 @@ impl Signature {
     pub fn is_empty(&self) -> bool {
         self.0.iter().all(|&b| b == 0)
     }
+
+    /// Sign `message` with `key`
+    pub fn sign(key: &SigningKey, message: &[u8]) -> Self {
+        Self::from_bytes(key.sign(message).to_bytes())
+    }
+
+    /// Whether this is `signer`'s signature over `message`
+    ///
+    /// Empty or malformed signatures, and node IDs that aren't valid
+    /// public keys, never verify.
+    pub fn verify(&self, signer: &NodeId, message: &[u8]) -> bool {
+        let Some(bytes) = self.as_bytes() else {
+            return false;
+        };
+        let Ok(key) = VerifyingKey::from_bytes(&signer.0) else {
+            return false;
+        };
+        key.verify_strict(message, &ed25519_dalek::Signature::from_bytes(&bytes))
+            .is_ok()
+    }
 }
 
 impl Default for Signature {
@@ -130,6 +180,25 @@ pub struct GradientMessage {
     pub signature: Signature,
 }
 
+impl GradientMessage {
+    /// Bytes covered by `signature`: every other field, wire-encoded
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        wire_options()
+            .serialize(&(&self.node_id, &self.gradient, &self.timestamp))
+            .unwrap_or_default()
+    }
+
+    /// Sign with `key`, whose public half should be `node_id`
+    pub fn sign(&mut self, key: &SigningKey) {
+        self.signature = Signature::sign(key, &self.signing_bytes());
+    }
+
+    /// Whether `node_id` signed this message
+    pub fn verify_signature(&self) -> bool {
+        self.signature.verify(&self.node_id, &self.signing_bytes())
+    }
+}
+
 // ============================================================================
 // Election Messages
 // ============================================================================
@@ -166,6 +235,66 @@ pub struct ElectionVote {
     pub signature: Signature,
 }
 
+impl ElectionVote {
+    /// Bytes covered by `signature`: every other field, wire-encoded
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        wire_options()
+            .serialize(&(
+                &self.election_id,
+                &self.voter,
+                &self.candidate,
+                &self.timestamp,
+            ))
+            .unwrap_or_default()
+    }
+
+    /// Sign with `key`, whose public half should be `voter`
+    pub fn sign(&mut self, key: &SigningKey) {
+        self.signature = Signature::sign(key, &self.signing_bytes());
+    }
+
+    /// Whether `voter` signed this vote
+    pub fn verify_signature(&self) -> bool {
+        self.signature.verify(&self.voter, &self.signing_bytes())
+    }
+
+    /// Receipt counting this vote at `weight`
+    pub fn receipt(&self, weight: f64) -> VoteReceipt {
+        VoteReceipt {
+            voter: self.voter,
+            candidate: self.candidate,
+            weight,
+            timestamp: self.timestamp,
+            signature: self.signature.clone(),
+        }
+    }
+}
+
+/// A single counted vote, published with the result for auditing
+///
+/// Carries the voter's signature so anyone can check the vote was cast.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteReceipt {
+    pub voter: NodeId,
+    pub candidate: NodeId,
+    pub weight: f64,
+    pub timestamp: Timestamp,
+    pub signature: Signature,
+}
+
+impl VoteReceipt {
+    /// The vote this receipt records in `election_id`
+    pub fn vote(&self, election_id: [u8; 32]) -> ElectionVote {
+        ElectionVote {
+            election_id,
+            voter: self.voter,
+            candidate: self.candidate,
+            timestamp: self.timestamp,
+            signature: self.signature.clone(),
+        }
+    }
+}
+
 /// Election result
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ElectionResult {
@@ -173,6 +302,68 @@ pub struct ElectionResult {
     pub winner: NodeId,
     pub vote_count: u32,
     pub timestamp: Timestamp,
+    /// Every counted vote, so any node can recompute the tally
+    pub receipts: Vec<VoteReceipt>,
+}
+
+impl ElectionResult {
+    /// Build a result by tallying `receipts`; `None` if there are none
+    pub fn from_receipts(
+        election_id: [u8; 32],
+        receipts: Vec<VoteReceipt>,
+        timestamp: Timestamp,
+    ) -> Option<Self> {
+        let winner = Self::tally_winner(&receipts)?;
+        Some(Self {
+            election_id,
+            winner,
+            vote_count: receipts.len() as u32,
+            timestamp,
+            receipts,
+        })
+    }
+
+    /// Total weight per candidate
+    pub fn tally(&self) -> HashMap<NodeId, f64> {
+        let mut tally = HashMap::new();
+        for receipt in &self.receipts {
+            *tally.entry(receipt.candidate).or_insert(0.0) += receipt.weight;
+        }
+        tally
+    }
+
+    /// Highest-weighted candidate; ties go to the lowest node ID
+    fn tally_winner(receipts: &[VoteReceipt]) -> Option<NodeId> {
+        let mut tally: Vec<(NodeId, f64)> = Vec::new();
+        for receipt in receipts {
+            match tally.iter_mut().find(|(c, _)| *c == receipt.candidate) {
+                Some((_, weight)) => *weight += receipt.weight,
+                None => tally.push((receipt.candidate, receipt.weight)),
+            }
+        }
+        tally
+            .into_iter()
+            .max_by(|(a, wa), (b, wb)| wa.total_cmp(wb).then_with(|| b.0.cmp(&a.0)))
+            .map(|(candidate, _)| candidate)
+    }
+
+    /// Recompute the winner from the receipts and check it matches
+    ///
+    /// Fails on duplicate voters, negative or non-finite weights, receipts
+    /// whose voter didn't sign the vote for this election, or a
+    /// `vote_count` that disagrees with the receipts.
+    pub fn verify(&self) -> bool {
+        let mut voters = HashSet::new();
+        let well_formed = self.receipts.iter().all(|r| {
+            voters.insert(r.voter)
+                && r.weight.is_finite()
+                && r.weight >= 0.0
+                && r.vote(self.election_id).verify_signature()
+        });
+        well_formed
+            && self.vote_count as usize == self.receipts.len()
+            && Self::tally_winner(&self.receipts) == Some(self.winner)
+    }
 }
 
 /// Election message variants
@@ -201,6 +392,33 @@ pub struct CreditTransfer {
     pub signature: Signature,
 }
 
+impl CreditTransfer {
+    /// Bytes covered by `signature`: every other field, wire-encoded
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        wire_options()
+            .serialize(&(
+                &self.id,
+                &self.from,
+                &self.to,
+                self.amount,
+                self.nonce,
+                &self.timestamp,
+                &self.memo,
+            ))
+            .unwrap_or_default()
+    }
+
+    /// Sign with `key`, whose public half should be `from`
+    pub fn sign(&mut self, key: &SigningKey) {
+        self.signature = Signature::sign(key, &self.signing_bytes());
+    }
+
+    /// Whether the sender signed this transfer
+    pub fn verify_signature(&self) -> bool {
+        self.signature.verify(&self.from, &self.signing_bytes())
+    }
+}
+
 /// Transfer confirmation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransferConfirmation {
@@ -220,6 +438,75 @@ pub struct CreditStateSync {
     pub signature: Signature,
 }
 
+/// Request to reconcile a counterparty's transfers after a partition heals
+///
+/// Carries every transfer the requester has seen involving `counterparty`
+/// with a timestamp from `since` up to, but excluding, `until` (or onwards
+/// if `None`). Large logs are split across several requests covering
+/// consecutive ranges; see [`chunk_transfers`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettlementRequest {
+    pub requester: NodeId,
+    pub counterparty: NodeId,
+    pub transfers: Vec<CreditTransfer>,
+    pub since: Timestamp,
+    pub until: Option<Timestamp>,
+    pub timestamp: Timestamp,
+}
+
+impl SettlementRequest {
+    /// Whether `timestamp` falls in the range this request covers
+    pub fn covers(&self, timestamp: Timestamp) -> bool {
+        timestamp >= self.since && self.until.is_none_or(|until| timestamp < until)
+    }
+}
+
+/// Reply to a settlement request
+///
+/// Returns the transfers the requester was missing and the responder's
+/// balance for `counterparty` after merging both logs, with the state-sync
+/// version that balance is based on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettlementResponse {
+    pub responder: NodeId,
+    pub requester: NodeId,
+    pub counterparty: NodeId,
+    pub missing: Vec<CreditTransfer>,
+    pub canonical_balance: u64,
+    pub balance_version: u64,
+    pub timestamp: Timestamp,
+}
+
+/// Split transfers sorted by timestamp into runs that each encode within
+/// `budget` bytes
+///
+/// Runs only break between different timestamps, so each covers a distinct
+/// timestamp range. Always returns at least one (possibly empty) run.
+pub fn chunk_transfers(transfers: &[CreditTransfer], budget: u64) -> Vec<&[CreditTransfer]> {
+    let mut chunks = Vec::new();
+    let (mut start, mut size, mut i) = (0, 0, 0);
+    while i < transfers.len() {
+        let mut end = i + 1;
+        while end < transfers.len() && transfers[end].timestamp == transfers[i].timestamp {
+            end += 1;
+        }
+        let group: u64 = transfers[i..end]
+            .iter()
+            .map(|t| wire_options().serialized_size(t).unwrap_or(budget))
+            .sum();
+        if start < i && size + group > budget {
+            chunks.push(&transfers[start..i]);
+            (start, size) = (i, 0);
+        }
+        size += group;
+        i = end;
+    }
+    if start < transfers.len() || chunks.is_empty() {
+        chunks.push(&transfers[start..]);
+    }
+    chunks
+}
+
 /// Credit message variants
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CreditMessage {
@@ -228,6 +515,8 @@ pub enum CreditMessage {
     StateSync(CreditStateSync),
     BalanceQuery { requester: NodeId, target: NodeId },
     BalanceResponse { node_id: NodeId, balance: u64 },
+    SettlementRequest(SettlementRequest),
+    SettlementResponse(SettlementResponse),
 }
 
 // ============================================================================
@@ -311,14 +600,64 @@ impl EnrMessage {
         }
     }
 
+    /// Node that originated this message, if the payload identifies one
+    pub fn sender(&self) -> Option<NodeId> {
+        match self {
+            EnrMessage::Gradient(msg) => Some(msg.node_id),
+            EnrMessage::Election(ElectionMessage::Announcement(a)) => Some(a.initiator),
+            EnrMessage::Election(ElectionMessage::Candidacy(c)) => Some(c.candidate),
+            EnrMessage::Election(ElectionMessage::Vote(v)) => Some(v.voter),
+            EnrMessage::Election(ElectionMessage::Result(_)) => None,
+            EnrMessage::Credit(CreditMessage::Transfer(t)) => Some(t.from),
+            EnrMessage::Credit(CreditMessage::Confirmation(c)) => Some(c.confirmer),
+            EnrMessage::Credit(CreditMessage::StateSync(s)) => Some(s.node_id),
+            EnrMessage::Credit(CreditMessage::BalanceQuery { requester, .. }) => Some(*requester),
+            EnrMessage::Credit(CreditMessage::BalanceResponse { node_id, .. }) => Some(*node_id),
+            EnrMessage::Credit(CreditMessage::SettlementRequest(r)) => Some(r.requester),
+            EnrMessage::Credit(CreditMessage::SettlementResponse(r)) => Some(r.responder),
+            EnrMessage::Septal(SeptalMessage::FailureReport(r)) => Some(r.reporter),
+            EnrMessage::Septal(SeptalMessage::Isolation(_)) => None,
+            EnrMessage::Septal(SeptalMessage::HealingProbe(p)) => Some(p.initiator),
+            EnrMessage::Septal(SeptalMessage::HealingResponse(r)) => Some(r.responder),
+            EnrMessage::Septal(SeptalMessage::Recovery(_)) => None,
+        }
+    }
+
+    /// Short name of the message variant, used as a log/span field
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EnrMessage::Gradient(_) => "gradient",
+            EnrMessage::Election(ElectionMessage::Announcement(_)) => "election_announcement",
+            EnrMessage::Election(ElectionMessage::Candidacy(_)) => "election_candidacy",
+            EnrMessage::Election(ElectionMessage::Vote(_)) => "election_vote",
+            EnrMessage::Election(ElectionMessage::Result(_)) => "election_result",
+            EnrMessage::Credit(CreditMessage::Transfer(_)) => "credit_transfer",
+            EnrMessage::Credit(CreditMessage::Confirmation(_)) => "credit_confirmation",
+            EnrMessage::Credit(CreditMessage::StateSync(_)) => "credit_state_sync",
+            EnrMessage::Credit(CreditMessage::BalanceQuery { .. }) => "balance_query",
+            EnrMessage::Credit(CreditMessage::BalanceResponse { .. }) => "balance_response",
+            EnrMessage::Credit(CreditMessage::SettlementRequest(_)) => "settlement_request",
+            EnrMessage::Credit(CreditMessage::SettlementResponse(_)) => "settlement_response",
+            EnrMessage::Septal(SeptalMessage::FailureReport(_)) => "failure_report",
+            EnrMessage::Septal(SeptalMessage::Isolation(_)) => "isolation",
+            EnrMessage::Septal(SeptalMessage::HealingProbe(_)) => "healing_probe",
+            EnrMessage::Septal(SeptalMessage::HealingResponse(_)) => "healing_response",
+            EnrMessage::Septal(SeptalMessage::Recovery(_)) => "recovery",
+        }
+    }
+
     /// Serialize message to bytes using bincode
     pub fn to_bytes(&self) -> Result<Vec<u8>, BridgeError> {
-        bincode::serialize(self).map_err(|e| BridgeError::Serialization(e.to_string()))
+        wire_options()
+            .serialize(self)
+            .map_err(|e| BridgeError::Serialization(e.to_string()))
     }
 
     /// Deserialize message from bytes
     pub fn from_bytes(data: &[u8]) -> Result<Self, BridgeError> {
-        bincode::deserialize(data).map_err(|e| BridgeError::Deserialization(e.to_string()))
+        wire_options()
+            .deserialize(data)
+            .map_err(|e| BridgeError::Deserialization(e.to_string()))
     }
 }
 
@@ -388,6 +727,52 @@ mod tests {
         assert_eq!(election_msg.topic(), "/enr/election/1.0");
     }
 
+    #[test]
+    fn test_wire_format_is_pinned() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let msg = EnrMessage::Credit(CreditMessage::BalanceResponse {
+            node_id: node,
+            balance: 42,
+        });
+
+        // Fixed-int little-endian: variant tags are u32, balance is a full u64
+        let bytes = msg.to_bytes().unwrap();
+        assert_eq!(&bytes[..4], &2u32.to_le_bytes());
+        assert_eq!(&bytes[bytes.len() - 8..], &42u64.to_le_bytes());
+        assert_eq!(EnrMessage::from_bytes(&bytes).unwrap(), msg);
+
+        let oversized = EnrMessage::Gradient(GradientMessage {
+            node_id: node,
+            gradient: GradientPayload::from(&ResourceGradient::default()),
+            timestamp: Timestamp::now(),
+            signature: Signature(vec![0u8; MAX_MESSAGE_SIZE as usize]),
+        });
+        assert!(matches!(
+            oversized.to_bytes(),
+            Err(BridgeError::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_message_kind() {
+        let node = NodeId::from_bytes([1u8; 32]);
+
+        let query = EnrMessage::Credit(CreditMessage::BalanceQuery {
+            requester: node,
+            target: node,
+        });
+        assert_eq!(query.kind(), "balance_query");
+
+        let election_msg =
+            EnrMessage::Election(ElectionMessage::Announcement(ElectionAnnouncement {
+                election_id: [0u8; 32],
+                initiator: node,
+                timestamp: Timestamp::now(),
+                round: 1,
+            }));
+        assert_eq!(election_msg.kind(), "election_announcement");
+    }
+
     #[test]
     fn test_message_serialization() {
         let node = NodeId::from_bytes([1u8; 32]);
@@ -431,4 +816,54 @@ mod tests {
             panic!("Expected CreditMessage::Transfer");
         }
     }
+
+    #[test]
+    fn test_election_result_receipts_verify() {
+        let node = |b| NodeId::from_bytes([b; 32]);
+        let receipt = |voter: u8, candidate, weight| {
+            let key = SigningKey::from_bytes(&[voter; 32]);
+            let mut vote = ElectionVote {
+                election_id: [7u8; 32],
+                voter: NodeId::from_bytes(key.verifying_key().to_bytes()),
+                candidate: node(candidate),
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            };
+            vote.sign(&key);
+            vote.receipt(weight)
+        };
+        let receipts = vec![
+            receipt(1, 10, 1.0),
+            receipt(2, 10, 0.5),
+            receipt(3, 11, 2.0),
+        ];
+
+        let result = ElectionResult::from_receipts([7u8; 32], receipts, Timestamp::now()).unwrap();
+        assert_eq!(result.winner, node(11));
+        assert_eq!(result.vote_count, 3);
+        assert_eq!(result.tally()[&node(10)], 1.5);
+        assert!(result.verify());
+
+        let mut forged = result.clone();
+        forged.winner = node(10);
+        assert!(!forged.verify());
+
+        let mut stuffed = result.clone();
+        stuffed.receipts.push(receipt(3, 10, 1.0));
+        stuffed.vote_count += 1;
+        assert!(!stuffed.verify());
+
+        // Receipts only verify for the election their votes were cast in
+        let mut moved = result.clone();
+        moved.election_id = [8u8; 32];
+        assert!(!moved.verify());
+
+        // A voter's vote can't be redirected to another candidate
+        let mut redirected = result.clone();
+        redirected.receipts[2].candidate = node(10);
+        redirected.winner = node(10);
+        assert!(!redirected.verify());
+
+        assert!(ElectionResult::from_receipts([7u8; 32], Vec::new(), Timestamp::now()).is_none());
+    }
 }
@@ -37,26 +37,47 @@
 //! bridge.start_gradient_broadcast().await;
 //! ```
 
+pub mod batch;
+pub mod clock;
 pub mod error;
 pub mod handlers;
+pub mod log;
 pub mod messages;
+pub mod sync;
 pub mod topics;
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use tokio::sync::RwLock;
+use ed25519_dalek::SigningKey;
+use tokio::sync::{mpsc, RwLock};
 use tokio::task::JoinHandle;
-
-use crate::core::{Credits, NodeId, Timestamp};
-use crate::nexus::{ResourceGradient, TopologyManager};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+
+use crate::core::{
+    AccountId, CreditConservation, CreditLedger, Credits, EnrError, NodeId, ReservationId,
+    ReservationManager, Timestamp,
+};
+use crate::entropy::{EntropyAccount, EntropyBudget};
+use crate::nexus::{
+    aggregate_gradients, determine_gossip_path, estimate_direct_entropy, ElectionConfig,
+    ElectionPhase, ElectionRound, GossipPath, LeafGradientReport, ResourceGradient, ResourceType,
+    RoundStatus, TopologyManager,
+};
+use crate::pricing::Pricer;
 use crate::septal::{SeptalGate, SeptalGateConfig, SeptalGateState};
 
+pub use batch::{net_transfers, BatchId, BatchStatus, LegStatus, TransferBatch};
+pub use clock::ClockSkewEstimator;
 pub use error::{BridgeError, TransferError};
 pub use handlers::*;
+pub use log::{EventSink, LogDirection, LogEntry, MemoryEventLog};
 pub use messages::*;
-pub use topics::EnrTopics;
+pub use sync::{merge_balances, merge_versions, BalanceVersions};
+pub use topics::{EnrTopics, LatencyHistogram, TopicStats, TopicType};
 
 /// Type alias for the publish function that connects to gossipsub
 ///
@@ -89,6 +110,41 @@ pub use topics::EnrTopics;
 /// ```
 pub type PublishFn = Arc<dyn Fn(&str, Vec<u8>) -> Result<(), BridgeError> + Send + Sync>;
 
+/// What to do with a received message when the dispatch queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueueOverflow {
+    /// Evict the oldest queued message to make room
+    #[default]
+    DropOldest,
+    /// Refuse the new message with `BridgeError::QueueFull`
+    Reject,
+}
+
+/// What happens to credits in batch legs that time out unconfirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeoutPolicy {
+    /// Return the credits to the local balance
+    #[default]
+    Refund,
+    /// Destroy the credits, recording the burn for conservation, for when
+    /// the recipient may have received them and a refund could double-credit
+    Burn,
+}
+
+/// Decoded received message and its topic, awaiting a dispatch worker
+type QueuedMessage = (String, EnrMessage);
+
+/// One dispatch worker's queue
+///
+/// Each sender's messages always land in the same shard, so a sender is
+/// never handled by two workers at once and its messages stay in order.
+#[derive(Clone)]
+struct DispatchShard {
+    tx: mpsc::Sender<QueuedMessage>,
+    /// Drained by the shard's worker; locked by others only to evict
+    rx: Arc<tokio::sync::Mutex<mpsc::Receiver<QueuedMessage>>>,
+}
+
 /// Configuration for the ENR bridge
 #[derive(Debug, Clone)]
 pub struct EnrBridgeConfig {
@@ -96,6 +152,8 @@ pub struct EnrBridgeConfig {
     pub gradient_interval: Duration,
     /// Interval for nexus election checks (default: 1 hour)
     pub election_interval: Duration,
+    /// Phase timeouts for running an election
+    pub election: ElectionConfig,
     /// Enable credit synchronization (default: true)
     pub credit_sync_enabled: bool,
     /// Interval for credit state sync (default: 30s)
@@ -104,6 +162,61 @@ pub struct EnrBridgeConfig {
     pub septal_config: SeptalGateConfig,
     /// Maximum message age to accept (prevents replay attacks)
     pub max_message_age: Duration,
+    /// Largest peer clock offset the age check will correct for (default: 30s)
+    pub max_clock_skew: Duration,
+    /// Weight of each new observation in clock offset estimates (default: 0.2)
+    pub clock_skew_smoothing: f64,
+    /// Furthest one signed message can move a peer's clock offset estimate
+    /// (default: 5s)
+    pub clock_skew_max_step: Duration,
+    /// Retries for a broadcast after a transient publish error (default: 3)
+    pub publish_retries: u32,
+    /// Initial backoff between publish retries, doubled per attempt (default: 100ms)
+    pub publish_retry_backoff: Duration,
+    /// Per-node entropy budget capacity (default: 50.0)
+    pub entropy_budget_capacity: f64,
+    /// Per-node entropy budget drain rate per second (default: 1.0)
+    pub entropy_budget_leak_per_sec: f64,
+    /// Trust assigned to a node before any gradients are seen (default: 0.1)
+    pub initial_trust: f64,
+    /// Trust gained per signed gradient, at most once per
+    /// `gradient_interval` (default: 0.05)
+    pub trust_gain: f64,
+    /// Trust lost per recorded failure (default: 0.25)
+    pub trust_penalty: f64,
+    /// How long batch legs may stay unconfirmed before refund (default: 120s)
+    pub batch_timeout: Duration,
+    /// Fate of batch legs unconfirmed after `batch_timeout` (default: refund)
+    pub transfer_timeout_policy: TimeoutPolicy,
+    /// Net a batch's legs per counterparty before broadcast (default: false)
+    pub net_batch_transfers: bool,
+    /// Drop suspected double-spends instead of applying and confirming them
+    /// (default: false, flag only)
+    pub reject_double_spends: bool,
+    /// Margin reserved on top of a resource quote, in basis points
+    /// (default: 1000, i.e. 10%)
+    pub reservation_margin_bps: u32,
+    /// How long a resource reservation stays live (default: 300s)
+    pub reservation_ttl: Duration,
+    /// Received messages buffered for dispatch workers, split evenly
+    /// between them (default: 1024)
+    pub dispatch_capacity: usize,
+    /// Worker tasks draining the dispatch queue, each owning the messages
+    /// of a fixed subset of senders (default: 2)
+    pub dispatch_workers: usize,
+    /// Backpressure when the dispatch queue is full (default: drop oldest)
+    pub dispatch_overflow: QueueOverflow,
+    /// Confirmed and refunded transfers kept for reporting (default: 10,000)
+    pub settlement_log_capacity: usize,
+    /// How often the maintenance loop runs (default: 1s)
+    pub maintenance_interval: Duration,
+    /// How long settled transfers stay in the replay log (default: 24h)
+    pub transfer_log_retention: Duration,
+    /// How long other nodes' unconfirmed transfers count as outstanding
+    /// (default: 120s)
+    pub remote_transfer_ttl: Duration,
+    /// Suspected double-spends kept for inspection (default: 1,000)
+    pub double_spend_suspect_capacity: usize,
 }
 
 impl Default for EnrBridgeConfig {
@@ -111,14 +224,156 @@ impl Default for EnrBridgeConfig {
         Self {
             gradient_interval: Duration::from_secs(10),
             election_interval: Duration::from_secs(3600),
+            election: ElectionConfig::default(),
             credit_sync_enabled: true,
             credit_sync_interval: Duration::from_secs(30),
             septal_config: SeptalGateConfig::default(),
             max_message_age: Duration::from_secs(60),
+            max_clock_skew: Duration::from_secs(30),
+            clock_skew_smoothing: 0.2,
+            clock_skew_max_step: Duration::from_secs(5),
+            publish_retries: 3,
+            publish_retry_backoff: Duration::from_millis(100),
+            entropy_budget_capacity: 50.0,
+            entropy_budget_leak_per_sec: 1.0,
+            initial_trust: 0.1,
+            trust_gain: 0.05,
+            trust_penalty: 0.25,
+            batch_timeout: Duration::from_secs(120),
+            transfer_timeout_policy: TimeoutPolicy::Refund,
+            net_batch_transfers: false,
+            reject_double_spends: false,
+            reservation_margin_bps: 1_000,
+            reservation_ttl: Duration::from_secs(300),
+            dispatch_capacity: 1024,
+            dispatch_workers: 2,
+            dispatch_overflow: QueueOverflow::DropOldest,
+            settlement_log_capacity: 10_000,
+            maintenance_interval: Duration::from_secs(1),
+            transfer_log_retention: Duration::from_secs(24 * 3600),
+            remote_transfer_ttl: Duration::from_secs(120),
+            double_spend_suspect_capacity: 1_000,
+        }
+    }
+}
+
+/// Shared per-topic message counters
+type TopicCounters = Arc<Mutex<HashMap<TopicType, TopicStats>>>;
+
+/// Apply an update to the counters for a topic string
+///
+/// Non-ENR topics are ignored.
+fn record_topic(counters: &TopicCounters, topic: &str, update: impl FnOnce(&mut TopicStats)) {
+    if let Some(topic_type) = EnrTopics::topic_type(topic) {
+        let mut stats = counters.lock().unwrap_or_else(|e| e.into_inner());
+        update(stats.entry(topic_type).or_default());
+    }
+}
+
+/// Publish with bounded retries on transient errors
+///
+/// Retries up to `retries` times with exponential backoff starting at
+/// `backoff`. Fatal errors are returned immediately.
+async fn publish_with_retry(
+    publish_fn: &PublishFn,
+    topic: &str,
+    data: Vec<u8>,
+    retries: u32,
+    backoff: Duration,
+) -> Result<(), BridgeError> {
+    let mut delay = backoff;
+    let mut attempt = 0;
+    loop {
+        match publish_fn(topic, data.clone()) {
+            Ok(()) => return Ok(()),
+            Err(e) if e.is_transient() && attempt < retries => {
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+                delay = delay.saturating_mul(2);
+            }
+            Err(e) => return Err(e),
         }
     }
 }
 
+/// Everything the bridge knows about a single node
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeStatus {
+    pub node: NodeId,
+    /// Septal gate state (`Open` if no gate has been created)
+    pub gate_state: SeptalGateState,
+    /// When a message from this node was last handled
+    pub last_seen: Option<Timestamp>,
+    /// Latest known credit balance
+    pub known_balance: Option<Credits>,
+    /// Latest reported resource gradient
+    pub gradient: Option<ResourceGradient>,
+}
+
+/// A transfer that spends more than its sender is known to hold
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoubleSpendSuspect {
+    pub transfer: CreditTransfer,
+    /// Sender's known balance, net of transfers already seen from it
+    pub known_balance: Credits,
+    /// Sender's other transfers seen but not yet confirmed
+    pub outstanding: Credits,
+    pub detected_at: Timestamp,
+}
+
+/// How a transfer left the pending set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettlementOutcome {
+    Confirmed,
+    Refunded,
+    /// Timed out and burned under `TimeoutPolicy::Burn`
+    Burned,
+}
+
+/// A transfer that has confirmed or been refunded
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettledTransfer {
+    pub transfer: CreditTransfer,
+    pub outcome: SettlementOutcome,
+    pub settled_at: Timestamp,
+}
+
+/// Credit movements over a reporting period, from [`EnrBridge::transfer_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferReport {
+    /// Start of the period
+    pub since: Timestamp,
+    pub generated_at: Timestamp,
+    /// Transfers confirmed during the period, oldest first
+    pub confirmed: Vec<CreditTransfer>,
+    /// Transfers still awaiting confirmation, including quarantined ones,
+    /// whenever they were sent
+    pub pending: Vec<CreditTransfer>,
+    /// Outgoing transfers refunded during the period, oldest first
+    pub refunded: Vec<CreditTransfer>,
+    /// Outgoing transfers burned during the period, oldest first
+    pub burned: Vec<CreditTransfer>,
+    /// Net credits gained (positive) or spent per node from confirmed
+    /// transfers
+    pub net_positions: HashMap<NodeId, i128>,
+}
+
+/// The transfer an idempotency key was first used for
+struct KeyedTransfer {
+    transfer_id: TransferId,
+    to: NodeId,
+    amount: Credits,
+    created: Timestamp,
+}
+
+/// An election this node is coordinating
+struct ActiveElection {
+    announcement: ElectionAnnouncement,
+    round: ElectionRound,
+    /// One receipt per voter, weighted by the voter's trust score
+    receipts: Vec<VoteReceipt>,
+}
+
 /// The main ENR bridge coordinator
 ///
 /// Connects the ENR economic layer to the gossipsub network.
@@ -133,38 +388,166 @@ pub struct EnrBridge {
     topology: Arc<RwLock<TopologyManager>>,
     /// Aggregated gradients from network
     gradients: Arc<RwLock<HashMap<NodeId, ResourceGradient>>>,
+    /// Trust in each node's gradients [0, 1], weighting aggregation
+    trust_scores: Arc<RwLock<HashMap<NodeId, f64>>>,
+    /// When each node last gained trust from a gradient
+    trust_gained_at: Arc<RwLock<HashMap<NodeId, Timestamp>>>,
+    /// Election this node is coordinating, with its round state and votes
+    active_election: Arc<RwLock<Option<ActiveElection>>>,
     /// Local gradient state
     local_gradient: Arc<RwLock<ResourceGradient>>,
+    /// Local entropy conditions used to quote resource reservations
+    local_entropy: Arc<RwLock<EntropyAccount>>,
     /// Local credit balance
     local_balance: Arc<RwLock<Credits>>,
     /// Septal gates for nodes (circuit breaker state per node)
     septal_gates: Arc<RwLock<HashMap<NodeId, SeptalGate>>>,
+    /// When each remote node's messages were last handled
+    last_seen: Arc<RwLock<HashMap<NodeId, Timestamp>>>,
+    /// Estimated clock offset of each remote node
+    clock_skew: Arc<RwLock<ClockSkewEstimator>>,
     /// Publish function (connected to gossipsub)
     publish_fn: Option<PublishFn>,
-    /// Active broadcast handles (abort handles for cleanup)
-    broadcast_handles: Vec<tokio::task::AbortHandle>,
+    /// Running broadcast loops
+    broadcast_tasks: TaskTracker,
+    /// Running dispatch workers and maintenance loop
+    worker_tasks: TaskTracker,
+    /// Cancelled to make loops exit at their next safe point
+    shutdown: CancellationToken,
+    /// One queue per dispatch worker, once started
+    dispatch_shards: Vec<DispatchShard>,
+    /// Set while broadcast loops should skip their ticks
+    broadcasts_paused: Arc<AtomicBool>,
     /// Known node balances (for credit sync)
     known_balances: Arc<RwLock<HashMap<NodeId, Credits>>>,
+    /// When each known balance was last updated
+    balance_updated_at: Arc<RwLock<HashMap<NodeId, Timestamp>>>,
+    /// Latest state-sync version seen per node
+    balance_versions: Arc<RwLock<BalanceVersions>>,
     /// Pending credit transfers
     pending_transfers: Arc<RwLock<HashMap<TransferId, CreditTransfer>>>,
+    /// Every transfer seen, by ID, for post-partition settlement
+    transfer_log: Arc<RwLock<HashMap<TransferId, CreditTransfer>>>,
+    /// Millis before which transfers have been pruned from the log
+    ///
+    /// Older transfers are rejected, since the log can no longer catch
+    /// their replays.
+    transfer_log_horizon: Arc<AtomicU64>,
+    /// Transfer created for each caller-supplied idempotency key
+    idempotency_keys: Arc<RwLock<HashMap<String, KeyedTransfer>>>,
+    /// Pending transfers held because a counterparty is isolated
+    ///
+    /// Lock `pending_transfers` first when holding both.
+    quarantined_transfers: Arc<RwLock<HashMap<TransferId, CreditTransfer>>>,
+    /// Remote nodes' transfers seen but not yet confirmed
+    unconfirmed_remote: Arc<RwLock<HashMap<TransferId, CreditTransfer>>>,
+    /// Credits minted and burned locally, for conservation checks
+    conservation: Arc<RwLock<CreditConservation>>,
+    /// Recently confirmed and refunded transfers, oldest first
+    settlement_log: Arc<RwLock<VecDeque<SettledTransfer>>>,
+    /// Transfers flagged as potential double-spends, oldest first
+    double_spend_suspects: Arc<RwLock<VecDeque<DoubleSpendSuspect>>>,
+    /// Outgoing transfer batches with per-leg status
+    batches: Arc<RwLock<HashMap<BatchId, TransferBatch>>>,
+    /// Next local batch ID
+    next_batch_id: Arc<AtomicU64>,
+    /// Next outgoing transfer nonce, seeded from the clock at startup
+    next_transfer_nonce: Arc<AtomicU64>,
+    /// Credits set aside for resource operations, keyed to the payee
+    reservations: Arc<RwLock<ReservationManager>>,
+    /// Broadcasts dropped after exhausting retries or hitting a fatal error
+    dropped_broadcasts: Arc<AtomicU64>,
+    /// Sent/received/dropped counters per topic
+    topic_stats: TopicCounters,
+    /// Optional event-sourcing sink for every inbound/outbound message
+    event_sink: Option<Arc<dyn EventSink>>,
+    /// Key signing outbound gradients; its public half should be `local_id`
+    signing_key: Option<Arc<SigningKey>>,
+    /// Per-node entropy budgets for throttling high-entropy operations
+    entropy_budgets: Arc<RwLock<HashMap<NodeId, EntropyBudget>>>,
 }
 
 impl EnrBridge {
     /// Create a new ENR bridge
     pub fn new(local_id: NodeId, config: EnrBridgeConfig) -> Self {
+        let clock_skew = ClockSkewEstimator::new(
+            config.clock_skew_smoothing,
+            config.max_clock_skew.as_millis() as u64,
+        )
+        .with_max_step(config.clock_skew_max_step.as_millis() as u64);
         Self {
             config,
             local_id,
             topology: Arc::new(RwLock::new(TopologyManager::new())),
             gradients: Arc::new(RwLock::new(HashMap::new())),
+            trust_scores: Arc::new(RwLock::new(HashMap::new())),
+            trust_gained_at: Arc::new(RwLock::new(HashMap::new())),
+            active_election: Arc::new(RwLock::new(None)),
             local_gradient: Arc::new(RwLock::new(ResourceGradient::default())),
+            local_entropy: Arc::new(RwLock::new(EntropyAccount::zero())),
             local_balance: Arc::new(RwLock::new(Credits::new(0))),
             septal_gates: Arc::new(RwLock::new(HashMap::new())),
+            last_seen: Arc::new(RwLock::new(HashMap::new())),
+            clock_skew: Arc::new(RwLock::new(clock_skew)),
             publish_fn: None,
-            broadcast_handles: Vec::new(),
+            broadcast_tasks: TaskTracker::new(),
+            worker_tasks: TaskTracker::new(),
+            shutdown: CancellationToken::new(),
+            dispatch_shards: Vec::new(),
+            broadcasts_paused: Arc::new(AtomicBool::new(false)),
             known_balances: Arc::new(RwLock::new(HashMap::new())),
+            balance_updated_at: Arc::new(RwLock::new(HashMap::new())),
+            balance_versions: Arc::new(RwLock::new(HashMap::new())),
             pending_transfers: Arc::new(RwLock::new(HashMap::new())),
+            transfer_log: Arc::new(RwLock::new(HashMap::new())),
+            transfer_log_horizon: Arc::new(AtomicU64::new(0)),
+            idempotency_keys: Arc::new(RwLock::new(HashMap::new())),
+            quarantined_transfers: Arc::new(RwLock::new(HashMap::new())),
+            unconfirmed_remote: Arc::new(RwLock::new(HashMap::new())),
+            conservation: Arc::new(RwLock::new(CreditConservation::new(Credits::ZERO))),
+            settlement_log: Arc::new(RwLock::new(VecDeque::new())),
+            double_spend_suspects: Arc::new(RwLock::new(VecDeque::new())),
+            batches: Arc::new(RwLock::new(HashMap::new())),
+            next_batch_id: Arc::new(AtomicU64::new(1)),
+            next_transfer_nonce: Arc::new(AtomicU64::new(Timestamp::now().millis)),
+            reservations: Arc::new(RwLock::new(ReservationManager::new())),
+            dropped_broadcasts: Arc::new(AtomicU64::new(0)),
+            topic_stats: Arc::new(Mutex::new(HashMap::new())),
+            event_sink: None,
+            signing_key: None,
+            entropy_budgets: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Rebuild bridge state by replaying the inbound messages of a log
+    ///
+    /// Each message is handled as if received at its `recorded_at` time, so
+    /// age checks reproduce the original outcome. Handler errors are part of
+    /// the recorded history and are skipped. The replayed bridge has no
+    /// publisher; outbound side effects are not re-sent.
+    pub async fn replay(local_id: NodeId, config: EnrBridgeConfig, log: &[LogEntry]) -> Self {
+        let bridge = Self::new(local_id, config);
+        for entry in log {
+            if entry.direction == LogDirection::Inbound {
+                let _ = bridge
+                    .dispatch_message(entry.message.clone(), entry.recorded_at)
+                    .await;
+            }
         }
+        bridge
+    }
+
+    /// Sign outbound gradients with `key`
+    ///
+    /// Peers only learn our clock offset from signed gradients, so the
+    /// bridge's node ID should be `key`'s public half.
+    pub fn set_signing_key(&mut self, key: SigningKey) {
+        self.signing_key = Some(Arc::new(key));
+    }
+
+    /// Record every inbound and outbound message to `sink`
+    pub fn set_event_sink(&mut self, sink: Arc<dyn EventSink>) {
+        self.event_sink = Some(sink);
     }
 
     /// Connect the publish function from gossipsub
@@ -217,6 +600,21 @@ impl EnrBridge {
         self.local_id
     }
 
+    /// Number of broadcasts dropped by the broadcast loops
+    pub fn dropped_broadcasts(&self) -> u64 {
+        self.dropped_broadcasts.load(Ordering::Relaxed)
+    }
+
+    /// Snapshot of message counters and handling latency per topic
+    ///
+    /// Topics with no traffic yet are absent from the map.
+    pub fn topic_stats(&self) -> HashMap<TopicType, TopicStats> {
+        self.topic_stats
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
     /// Handle incoming message from gossipsub
     ///
     /// Route messages received from gossipsub to appropriate handlers.
@@ -229,34 +627,330 @@ impl EnrBridge {
     ///     bridge.handle_message(&message.topic, &message.data).await?;
     /// }
     /// ```
-    pub async fn handle_message(&self, _topic: &str, data: &[u8]) -> Result<(), BridgeError> {
-        let message = EnrMessage::from_bytes(data)?;
+    pub async fn handle_message(&self, topic: &str, data: &[u8]) -> Result<(), BridgeError> {
+        let message = self.decode_message(topic, data)?;
+        self.handle_decoded(topic, message).await
+    }
+
+    /// Decode a received message, counting failures against its topic
+    fn decode_message(&self, topic: &str, data: &[u8]) -> Result<EnrMessage, BridgeError> {
+        EnrMessage::from_bytes(data).inspect_err(|_e| {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(node = %self.local_id, topic, error = %_e, "failed to decode message");
+            record_topic(&self.topic_stats, topic, |s| s.dropped += 1);
+        })
+    }
+
+    /// Check a decoded message's topic, log it and dispatch it
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(node = %self.local_id, topic = topic, message_type = message.kind())
+        )
+    )]
+    async fn handle_decoded(&self, topic: &str, message: EnrMessage) -> Result<(), BridgeError> {
+        // A message is only valid on its own topic
+        if message.topic() != topic {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(expected = message.topic(), "message on wrong topic");
+            record_topic(&self.topic_stats, topic, |s| s.dropped += 1);
+            return Err(BridgeError::TopicMismatch {
+                expected: message.topic().to_string(),
+                received: topic.to_string(),
+            });
+        }
 
-        // Validate message age
         let now = Timestamp::now();
+        if let Some(sink) = &self.event_sink {
+            let mut entry = LogEntry::new(LogDirection::Inbound, topic, message.clone());
+            entry.recorded_at = now;
+            sink.record(entry);
+        }
+
+        let started = std::time::Instant::now();
+        let result = self.dispatch_message(message, now).await;
+        let elapsed = started.elapsed();
+        record_topic(&self.topic_stats, topic, |s| s.handling.record(elapsed));
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::warn!(error = %e, "failed to handle message");
+        }
+        result
+    }
+
+    /// Validate and route a decoded message as if received at `now`
+    async fn dispatch_message(
+        &self,
+        message: EnrMessage,
+        now: Timestamp,
+    ) -> Result<(), BridgeError> {
+        // Validate message age
         let max_age_ms = self.config.max_message_age.as_millis() as u64;
 
         match &message {
             EnrMessage::Gradient(msg) => {
-                if now.millis.saturating_sub(msg.timestamp.millis) > max_age_ms {
+                // Judge age by the sender's clock as we've come to know it;
+                // only accepted messages the sender signed refine the
+                // estimate, so nobody can skew it in another node's name
+                let offset = self.clock_skew.read().await.offset_ms(&msg.node_id);
+                if msg.timestamp.age_with_skew(now, offset) > max_age_ms {
+                    record_topic(&self.topic_stats, message.topic(), |s| s.dropped += 1);
                     return Err(BridgeError::MessageExpired);
                 }
+                if msg.verify_signature() {
+                    self.clock_skew
+                        .write()
+                        .await
+                        .observe(msg.node_id, msg.timestamp, now);
+                }
+                record_topic(&self.topic_stats, message.topic(), |s| s.received += 1);
+                self.mark_seen(&message, now).await;
                 self.handle_gradient_message(msg.clone()).await
             }
-            EnrMessage::Election(msg) => self.handle_election_message(msg.clone()).await,
-            EnrMessage::Credit(msg) => self.handle_credit_message(msg.clone()).await,
-            EnrMessage::Septal(msg) => self.handle_septal_message(msg.clone()).await,
+            EnrMessage::Election(msg) => {
+                record_topic(&self.topic_stats, message.topic(), |s| s.received += 1);
+                self.mark_seen(&message, now).await;
+                self.handle_election_message(msg.clone()).await
+            }
+            EnrMessage::Credit(msg) => {
+                record_topic(&self.topic_stats, message.topic(), |s| s.received += 1);
+                self.mark_seen(&message, now).await;
+                self.handle_credit_message(msg.clone()).await
+            }
+            EnrMessage::Septal(msg) => {
+                record_topic(&self.topic_stats, message.topic(), |s| s.received += 1);
+                self.mark_seen(&message, now).await;
+                self.handle_septal_message(msg.clone()).await
+            }
+        }
+    }
+
+    /// A second handle on this bridge's shared state
+    ///
+    /// Lets handlers run on worker tasks. The copy owns no background
+    /// tasks, so dropping it stops nothing.
+    fn share(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            local_id: self.local_id,
+            topology: self.topology.clone(),
+            gradients: self.gradients.clone(),
+            trust_scores: self.trust_scores.clone(),
+            trust_gained_at: self.trust_gained_at.clone(),
+            active_election: self.active_election.clone(),
+            local_gradient: self.local_gradient.clone(),
+            local_entropy: self.local_entropy.clone(),
+            local_balance: self.local_balance.clone(),
+            septal_gates: self.septal_gates.clone(),
+            last_seen: self.last_seen.clone(),
+            clock_skew: self.clock_skew.clone(),
+            publish_fn: self.publish_fn.clone(),
+            broadcast_tasks: TaskTracker::new(),
+            worker_tasks: TaskTracker::new(),
+            // A child, so stopping the copy can't cancel the owner's loops
+            shutdown: self.shutdown.child_token(),
+            dispatch_shards: self.dispatch_shards.clone(),
+            broadcasts_paused: self.broadcasts_paused.clone(),
+            known_balances: self.known_balances.clone(),
+            balance_updated_at: self.balance_updated_at.clone(),
+            balance_versions: self.balance_versions.clone(),
+            pending_transfers: self.pending_transfers.clone(),
+            transfer_log: self.transfer_log.clone(),
+            transfer_log_horizon: self.transfer_log_horizon.clone(),
+            idempotency_keys: self.idempotency_keys.clone(),
+            quarantined_transfers: self.quarantined_transfers.clone(),
+            unconfirmed_remote: self.unconfirmed_remote.clone(),
+            conservation: self.conservation.clone(),
+            settlement_log: self.settlement_log.clone(),
+            double_spend_suspects: self.double_spend_suspects.clone(),
+            batches: self.batches.clone(),
+            next_batch_id: self.next_batch_id.clone(),
+            next_transfer_nonce: self.next_transfer_nonce.clone(),
+            reservations: self.reservations.clone(),
+            dropped_broadcasts: self.dropped_broadcasts.clone(),
+            topic_stats: self.topic_stats.clone(),
+            event_sink: self.event_sink.clone(),
+            signing_key: self.signing_key.clone(),
+            entropy_budgets: self.entropy_budgets.clone(),
+        }
+    }
+
+    /// Start worker tasks that drain a bounded dispatch queue
+    ///
+    /// Afterwards [`Self::enqueue_message`] only decodes and buffers
+    /// received messages; `dispatch_workers` tasks handle them as
+    /// [`Self::handle_message`] would, so slow handlers no longer hold up the network event loop. Messages
+    /// are sharded by sender, so each sender's are handled one at a time and
+    /// in arrival order. Workers publish through the publisher connected at
+    /// the time of this call. Calling again while workers are running has
+    /// no effect.
+    pub fn start_dispatch_workers(&mut self) {
+        if !self.dispatch_shards.is_empty() {
+            return;
+        }
+
+        let workers = self.config.dispatch_workers.max(1);
+        let capacity = self.config.dispatch_capacity.div_ceil(workers).max(1);
+        self.dispatch_shards = (0..workers)
+            .map(|_| {
+                let (tx, rx) = mpsc::channel(capacity);
+                DispatchShard {
+                    tx,
+                    rx: Arc::new(tokio::sync::Mutex::new(rx)),
+                }
+            })
+            .collect();
+
+        for shard in self.dispatch_shards.clone() {
+            let bridge = self.share();
+            let rx = shard.rx;
+            let shutdown = self.shutdown.clone();
+            self.worker_tasks.spawn(async move {
+                loop {
+                    let next = tokio::select! {
+                        biased;
+                        _ = shutdown.cancelled() => break,
+                        next = async { rx.lock().await.recv().await } => next,
+                    };
+                    let Some((topic, message)) = next else {
+                        break;
+                    };
+                    // Failures are already counted (and traced) per topic
+                    let _ = bridge.handle_decoded(&topic, message).await;
+                }
+            });
+        }
+    }
+
+    /// Queue a received message for the dispatch workers
+    ///
+    /// The message is decoded once, here; one that doesn't decode is
+    /// counted and rejected as by [`Self::handle_message`]. When the
+    /// sender's queue is full, `dispatch_overflow` decides whether
+    /// the oldest message in it or this one is dropped; either way the drop
+    /// is counted against its topic. Without running workers the message is
+    /// handled inline, as by [`Self::handle_message`].
+    pub async fn enqueue_message(&self, topic: &str, data: Vec<u8>) -> Result<(), BridgeError> {
+        let message = self.decode_message(topic, &data)?;
+        let Some(DispatchShard { tx, rx }) = self.dispatch_shard(&message) else {
+            return self.handle_decoded(topic, message).await;
+        };
+
+        let mut message = (topic.to_string(), message);
+        loop {
+            match tx.try_send(message) {
+                Ok(()) => return Ok(()),
+                Err(mpsc::error::TrySendError::Closed((topic, message))) => {
+                    return self.handle_decoded(&topic, message).await;
+                }
+                Err(mpsc::error::TrySendError::Full(rejected)) => {
+                    match self.config.dispatch_overflow {
+                        QueueOverflow::Reject => {
+                            record_topic(&self.topic_stats, &rejected.0, |s| s.dropped += 1);
+                            return Err(BridgeError::QueueFull);
+                        }
+                        QueueOverflow::DropOldest => {
+                            if let Ok((evicted, _)) = rx.lock().await.try_recv() {
+                                record_topic(&self.topic_stats, &evicted, |s| s.dropped += 1);
+                            }
+                            message = rejected;
+                        }
+                    }
+                }
+            }
         }
     }
 
+    /// The queue for a message's sender, if workers are running
+    ///
+    /// Messages that name no sender share the first shard.
+    fn dispatch_shard(&self, message: &EnrMessage) -> Option<&DispatchShard> {
+        if self.dispatch_shards.is_empty() {
+            return None;
+        }
+        let index = message.sender().map_or(0, |sender| {
+            let mut prefix = [0u8; 8];
+            prefix.copy_from_slice(&sender.0[..8]);
+            (u64::from_le_bytes(prefix) % self.dispatch_shards.len() as u64) as usize
+        });
+        self.dispatch_shards.get(index)
+    }
+
+    /// Messages waiting in the dispatch queues
+    pub fn queued_messages(&self) -> usize {
+        self.dispatch_shards
+            .iter()
+            .map(|shard| shard.tx.max_capacity() - shard.tx.capacity())
+            .sum()
+    }
+
+    /// Record that a message's sender was heard from at `now`
+    async fn mark_seen(&self, message: &EnrMessage, now: Timestamp) {
+        if let Some(sender) = message.sender().filter(|id| *id != self.local_id) {
+            let mut last_seen = self.last_seen.write().await;
+            let seen = last_seen.entry(sender).or_insert(now);
+            if now.millis > seen.millis {
+                *seen = now;
+            }
+        }
+    }
+
+    /// Estimated milliseconds `node`'s clock runs ahead of ours
+    pub async fn clock_offset(&self, node: &NodeId) -> i64 {
+        self.clock_skew.read().await.offset_ms(node)
+    }
+
+    /// Every node the bridge knows about, with its derived status
+    ///
+    /// Cross-references gradients, known balances, septal gates, and
+    /// last-seen times. Sorted by node ID.
+    pub async fn node_directory(&self) -> Vec<NodeStatus> {
+        let gradients = self.gradients.read().await;
+        let balances = self.known_balances.read().await;
+        let gates = self.septal_gates.read().await;
+        let last_seen = self.last_seen.read().await;
+
+        let mut nodes: Vec<NodeId> = gradients
+            .keys()
+            .chain(balances.keys())
+            .chain(gates.keys())
+            .chain(last_seen.keys())
+            .copied()
+            .collect();
+        nodes.sort_by_key(|id| id.0);
+        nodes.dedup();
+
+        nodes
+            .into_iter()
+            .map(|node| NodeStatus {
+                node,
+                gate_state: gates
+                    .get(&node)
+                    .map(|g| g.state)
+                    .unwrap_or(SeptalGateState::Open),
+                last_seen: last_seen.get(&node).copied(),
+                known_balance: balances.get(&node).copied(),
+                gradient: gradients.get(&node).copied(),
+            })
+            .collect()
+    }
+
     /// Publish a message to the network
     pub fn publish(&self, message: EnrMessage) -> Result<(), BridgeError> {
         let publish_fn = self.publish_fn.as_ref().ok_or(BridgeError::NotConnected)?;
 
         let topic = message.topic();
-        let data = message.to_bytes()?;
+        let result = message.to_bytes().and_then(|data| publish_fn(topic, data));
+        if let Some(sink) = &self.event_sink {
+            sink.record(LogEntry::new(LogDirection::Outbound, topic, message));
+        }
 
-        publish_fn(topic, data)
+        record_topic(&self.topic_stats, topic, |s| match result {
+            Ok(()) => s.sent += 1,
+            Err(_) => s.dropped += 1,
+        });
+        result
     }
 
     // ========================================================================
@@ -273,12 +967,16 @@ impl EnrBridge {
         // Broadcast to network if connected
         if self.publish_fn.is_some() {
             let gradient_read = self.local_gradient.read().await;
-            let message = EnrMessage::Gradient(GradientMessage {
+            let mut gradient = GradientMessage {
                 node_id: self.local_id,
                 gradient: GradientPayload::from(&*gradient_read),
                 timestamp: Timestamp::now(),
                 signature: Signature::empty(),
-            });
+            };
+            if let Some(key) = &self.signing_key {
+                gradient.sign(key);
+            }
+            let message = EnrMessage::Gradient(gradient);
 
             self.publish(message)?;
         }
@@ -286,6 +984,33 @@ impl EnrBridge {
         Ok(())
     }
 
+    /// Mean resource availability across all known, non-isolated nodes
+    ///
+    /// Aggregates the gradients received from the network, each weighted
+    /// by its source's trust score. Returns `ResourceGradient::zero()` if
+    /// no nodes are known.
+    pub async fn network_capacity(&self) -> ResourceGradient {
+        let gradients = self.gradients.read().await;
+        let gates = self.septal_gates.read().await;
+        let trust = self.trust_scores.read().await;
+        let now = Timestamp::now();
+
+        let reports: Vec<LeafGradientReport> = gradients
+            .iter()
+            .filter(|(node, _)| !gates.get(node).is_some_and(|g| g.state.is_closed()))
+            .map(|(node, gradient)| LeafGradientReport {
+                node: *node,
+                gradient: *gradient,
+                weight: trust
+                    .get(node)
+                    .copied()
+                    .unwrap_or(self.config.initial_trust),
+                timestamp: now,
+            })
+            .collect();
+        aggregate_gradients(&reports)
+    }
+
     /// Start the gradient broadcast loop
     ///
     /// Spawns a tokio task that periodically broadcasts local gradient.
@@ -297,29 +1022,77 @@ impl EnrBridge {
         let local_gradient = self.local_gradient.clone();
         let local_id = self.local_id;
         let interval = self.config.gradient_interval;
+        let retries = self.config.publish_retries;
+        let backoff = self.config.publish_retry_backoff;
+        let dropped = self.dropped_broadcasts.clone();
+        let topic_stats = self.topic_stats.clone();
+        let event_sink = self.event_sink.clone();
+        let paused = self.broadcasts_paused.clone();
+        let shutdown = self.shutdown.clone();
         let publish_fn = self.publish_fn.clone().unwrap();
+        let signing_key = self.signing_key.clone();
 
-        let handle = tokio::spawn(async move {
+        let task = async move {
             let mut ticker = tokio::time::interval(interval);
             loop {
-                ticker.tick().await;
+                tokio::select! {
+                    biased;
+                    _ = shutdown.cancelled() => break,
+                    _ = ticker.tick() => {}
+                }
+                if paused.load(Ordering::Relaxed) {
+                    continue;
+                }
 
-                let gradient = local_gradient.read().await.clone();
-                let message = EnrMessage::Gradient(GradientMessage {
+                let gradient = *local_gradient.read().await;
+                let mut gradient = GradientMessage {
                     node_id: local_id,
                     gradient: GradientPayload::from(&gradient),
                     timestamp: Timestamp::now(),
                     signature: Signature::empty(),
-                });
+                };
+                if let Some(key) = &signing_key {
+                    gradient.sign(key);
+                }
+                let message = EnrMessage::Gradient(gradient);
+
+                if let Some(sink) = &event_sink {
+                    sink.record(LogEntry::new(
+                        LogDirection::Outbound,
+                        EnrTopics::GRADIENT,
+                        message.clone(),
+                    ));
+                }
 
-                if let Ok(data) = message.to_bytes() {
-                    let _ = publish_fn(EnrTopics::GRADIENT, data);
+                let sent = match message.to_bytes() {
+                    Ok(data) => {
+                        publish_with_retry(&publish_fn, EnrTopics::GRADIENT, data, retries, backoff)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+                if let Err(_e) = &sent {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_e, "dropped gradient broadcast");
+                    dropped.fetch_add(1, Ordering::Relaxed);
                 }
+                record_topic(&topic_stats, EnrTopics::GRADIENT, |s| match sent {
+                    Ok(()) => s.sent += 1,
+                    Err(_) => s.dropped += 1,
+                });
             }
-        });
-
-        self.broadcast_handles.push(handle.abort_handle());
-        Ok(handle)
+        };
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(
+            task,
+            tracing::info_span!(
+                "gradient_broadcast",
+                node = %local_id,
+                topic = EnrTopics::GRADIENT,
+                message_type = "gradient"
+            ),
+        );
+        Ok(self.broadcast_tasks.spawn(task))
     }
 
     /// Handle incoming gradient message
@@ -346,14 +1119,67 @@ impl EnrBridge {
             topology.update_gradient(&msg.node_id, msg.gradient.to_resource_gradient());
         }
 
+        // Trust tracks sustained, authenticated reporting rather than
+        // message volume: one gain per gradient interval, signed only
+        if msg.verify_signature() && self.take_trust_gain(&msg.node_id).await {
+            self.adjust_trust(&msg.node_id, self.config.trust_gain)
+                .await;
+        }
+
         Ok(())
     }
 
+    /// Claim a node's trust gain for the current gradient interval
+    ///
+    /// Returns false if it already gained trust within the interval.
+    async fn take_trust_gain(&self, node_id: &NodeId) -> bool {
+        let now = Timestamp::now();
+        let interval = self.config.gradient_interval.as_millis() as u64;
+        let mut gained_at = self.trust_gained_at.write().await;
+        if gained_at
+            .get(node_id)
+            .is_some_and(|last| now.millis.saturating_sub(last.millis) < interval)
+        {
+            return false;
+        }
+        gained_at.insert(*node_id, now);
+        true
+    }
+
+    /// Move a node's trust score by `delta`, clamped to [0, 1]
+    async fn adjust_trust(&self, node_id: &NodeId, delta: f64) {
+        let mut trust = self.trust_scores.write().await;
+        let score = trust.entry(*node_id).or_insert(self.config.initial_trust);
+        *score = (*score + delta).clamp(0.0, 1.0);
+    }
+
+    /// Trust in a node's gradients [0, 1]
+    ///
+    /// Starts at `initial_trust`, rises by `trust_gain` at most once per
+    /// gradient interval for signed gradients, and falls with each recorded
+    /// failure.
+    pub async fn trust_score(&self, node_id: &NodeId) -> f64 {
+        self.trust_scores
+            .read()
+            .await
+            .get(node_id)
+            .copied()
+            .unwrap_or(self.config.initial_trust)
+    }
+
     // ========================================================================
     // Election Handling
     // ========================================================================
 
     /// Trigger a new nexus election
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(node = %self.local_id, topic = EnrTopics::ELECTION, message_type = "election_announcement"),
+            err(Display)
+        )
+    )]
     pub async fn trigger_election(&self) -> Result<ElectionAnnouncement, BridgeError> {
         let election_id = {
             use std::collections::hash_map::DefaultHasher;
@@ -379,9 +1205,74 @@ impl EnrBridge {
         let message = EnrMessage::Election(ElectionMessage::Announcement(announcement.clone()));
         self.publish(message)?;
 
+        let round = ElectionRound::new(self.config.election, announcement.timestamp);
+        *self.active_election.write().await = Some(ActiveElection {
+            announcement: announcement.clone(),
+            round,
+            receipts: Vec::new(),
+        });
+
         Ok(announcement)
     }
 
+    /// Drive the election this node is coordinating
+    ///
+    /// When a round times out with votes collected, tallies them into an
+    /// [`ElectionResult`], publishes it and applies it locally. When a round
+    /// times out without votes, re-announces the election with the next
+    /// round number to re-solicit candidacies. Returns `None` if no election
+    /// is active; concluded or abandoned elections are cleared after being
+    /// reported once.
+    pub async fn poll_election(&self) -> Result<Option<RoundStatus>, BridgeError> {
+        let mut active = self.active_election.write().await;
+        let Some(election) = active.as_mut() else {
+            return Ok(None);
+        };
+
+        let now = Timestamp::now();
+        let timed_out = self
+            .config
+            .election
+            .phase_at(election.round.started(), now)
+            .is_none();
+        if timed_out && !election.receipts.is_empty() {
+            let receipts = std::mem::take(&mut election.receipts);
+            let result =
+                ElectionResult::from_receipts(election.announcement.election_id, receipts, now)
+                    .expect("receipts are non-empty");
+            *active = None;
+            drop(active);
+
+            self.publish(EnrMessage::Election(ElectionMessage::Result(
+                result.clone(),
+            )))?;
+            self.apply_election_result(&result).await;
+            return Ok(Some(RoundStatus::Concluded));
+        }
+
+        let status = election.round.poll(now);
+        match status {
+            RoundStatus::Advanced(number) => {
+                election.announcement.round = number;
+                election.announcement.timestamp = now;
+                let message = EnrMessage::Election(ElectionMessage::Announcement(
+                    election.announcement.clone(),
+                ));
+                self.publish(message)?;
+            }
+            RoundStatus::Concluded | RoundStatus::GaveUp => *active = None,
+            RoundStatus::InProgress(_) => {}
+        }
+        Ok(Some(status))
+    }
+
+    /// Current phase of an announced election, or `None` once it has timed out
+    pub fn election_phase(&self, announcement: &ElectionAnnouncement) -> Option<ElectionPhase> {
+        self.config
+            .election
+            .phase_at(announcement.timestamp, Timestamp::now())
+    }
+
     /// Handle incoming election message
     async fn handle_election_message(&self, msg: ElectionMessage) -> Result<(), BridgeError> {
         match msg {
@@ -393,23 +1284,168 @@ impl EnrBridge {
                 // TODO: Evaluate candidate and potentially vote
                 Ok(())
             }
-            ElectionMessage::Vote(_vote) => {
-                // TODO: Collect votes if we're coordinating
+            ElectionMessage::Vote(vote) => {
+                if !vote.verify_signature() {
+                    return Err(BridgeError::InvalidMessage(
+                        "election vote signature does not verify".to_string(),
+                    ));
+                }
+                let weight = self.trust_score(&vote.voter).await;
+                let mut active = self.active_election.write().await;
+                let Some(election) = active.as_mut() else {
+                    return Ok(());
+                };
+                if election.announcement.election_id != vote.election_id {
+                    return Ok(());
+                }
+
+                // A voter's latest vote replaces any earlier one
+                let receipt = vote.receipt(weight);
+                match election.receipts.iter_mut().find(|r| r.voter == vote.voter) {
+                    Some(existing) => *existing = receipt,
+                    None => election.receipts.push(receipt),
+                }
                 Ok(())
             }
             ElectionMessage::Result(result) => {
-                // Update topology with new nexus
-                let mut topology = self.topology.write().await;
-                if let Some(topo) = topology.get_topology(&result.winner) {
-                    let mut updated = topo.clone();
-                    updated.role.role_type = crate::nexus::NexusRoleType::Nexus;
-                    topology.set_topology(result.winner, updated);
+                if result.receipts.is_empty() {
+                    return Err(BridgeError::InvalidMessage(
+                        "election result has no vote receipts".to_string(),
+                    ));
+                }
+                if !result.verify() {
+                    return Err(BridgeError::InvalidMessage(
+                        "election result does not match its vote receipts".to_string(),
+                    ));
+                }
+
+                if let Some(election) = self.active_election.write().await.as_mut() {
+                    if election.announcement.election_id == result.election_id {
+                        election.round.conclude();
+                    }
                 }
+
+                self.apply_election_result(&result).await;
                 Ok(())
             }
         }
     }
 
+    /// Update the topology with an election's winner
+    async fn apply_election_result(&self, result: &ElectionResult) {
+        let mut topology = self.topology.write().await;
+
+        // Winner replaces its current nexus: move the leaves along
+        let previous = topology.get_role(&result.winner).parent;
+        if let Some(old) = previous {
+            if topology.get_role(&old).is_nexus() {
+                topology.handoff_nexus(old, result.winner);
+                return;
+            }
+        }
+
+        if let Some(topo) = topology.get_topology(&result.winner) {
+            let mut updated = topo.clone();
+            updated.role.role_type = crate::nexus::NexusRoleType::Nexus;
+            topology.set_topology(result.winner, updated);
+        }
+    }
+
+    // ========================================================================
+    // Routing
+    // ========================================================================
+
+    /// Choose a gossip path to `to` under the current topology
+    ///
+    /// Uses the local node's role from the topology and estimates each hop
+    /// as one direct hop plus the mean network entropy implied by the two
+    /// endpoints' latest gradients (nodes without a gradient count as fully
+    /// saturated). A nexus over budget routes via its parent, or else the
+    /// cheapest known poteau-mitan. Returns `None` for `to == self`, a leaf
+    /// with no nexus, or a nexus over budget with no super-hub to use.
+    pub async fn best_path(&self, to: NodeId, entropy_budget: f64) -> Option<GossipPath> {
+        if to == self.local_id {
+            return None;
+        }
+        let (role, poteau_mitans) = {
+            let topology = self.topology.read().await;
+            (
+                topology.get_role(&self.local_id),
+                topology.get_poteau_mitans(),
+            )
+        };
+
+        let network_entropy: HashMap<NodeId, f64> = {
+            let gradients = self.gradients.read().await;
+            let local = *self.local_gradient.read().await;
+            gradients
+                .iter()
+                .map(|(node, gradient)| (*node, gradient.estimated_entropy().network))
+                .chain(std::iter::once((
+                    self.local_id,
+                    local.estimated_entropy().network,
+                )))
+                .collect()
+        };
+        let saturated = ResourceGradient::zero().estimated_entropy().network;
+        let estimate = |from: NodeId, to: NodeId| {
+            let entropy_of = |node| network_entropy.get(&node).copied().unwrap_or(saturated);
+            estimate_direct_entropy(1, 0.0) + (entropy_of(from) + entropy_of(to)) / 2.0
+        };
+
+        let nearest_poteau_mitan = poteau_mitans
+            .into_iter()
+            .filter(|p| *p != self.local_id)
+            .min_by(|a, b| {
+                estimate(self.local_id, *a)
+                    .total_cmp(&estimate(self.local_id, *b))
+                    .then(a.0.cmp(&b.0))
+            });
+
+        if role.is_leaf() && role.parent.is_none() {
+            return None;
+        }
+        if role.is_nexus()
+            && role.parent.is_none()
+            && nearest_poteau_mitan.is_none()
+            && estimate(self.local_id, to) > entropy_budget
+        {
+            return None;
+        }
+
+        Some(determine_gossip_path(
+            self.local_id,
+            to,
+            &role,
+            entropy_budget,
+            estimate,
+            // Only consulted when a super-hub exists, per the checks above
+            || nearest_poteau_mitan.unwrap_or(to),
+        ))
+    }
+
+    /// Route an operation for `requester`, charging its entropy budget
+    ///
+    /// Finds a path as [`Self::best_path`] does, then spends `account`
+    /// from the requester's budget via [`Self::try_spend_entropy`]. Nothing
+    /// is charged when there's no path.
+    pub async fn route(
+        &self,
+        requester: NodeId,
+        to: NodeId,
+        account: &EntropyAccount,
+        entropy_budget: f64,
+    ) -> Result<GossipPath, BridgeError> {
+        let path = self
+            .best_path(to, entropy_budget)
+            .await
+            .ok_or(BridgeError::NoRoute(to))?;
+        if !self.try_spend_entropy(requester, account).await {
+            return Err(BridgeError::EntropyBudgetExhausted(requester));
+        }
+        Ok(path)
+    }
+
     // ========================================================================
     // Credit Transfer
     // ========================================================================
@@ -427,6 +1463,85 @@ impl EnrBridge {
 
     /// Transfer credits to another node
     pub async fn transfer(&self, to: NodeId, amount: Credits) -> Result<TransferId, TransferError> {
+        self.transfer_with_key(to, amount, None).await
+    }
+
+    /// Transfer credits, making retries under the same key safe
+    ///
+    /// The first call with a given idempotency key creates the transfer as
+    /// [`Self::transfer`] does. Later calls with that key reuse its
+    /// `TransferId` instead of creating a new one: a transfer still pending
+    /// is re-broadcast (recipients drop copies they've already seen), and a
+    /// settled one is left alone. Reusing a key for a different recipient or
+    /// amount fails with `KeyReused`. Keys expire with the transfer log; see
+    /// [`Self::prune_transfer_log`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(node = %self.local_id, topic = EnrTopics::CREDIT, message_type = "credit_transfer", to = %to, amount = amount.amount),
+            err(Display)
+        )
+    )]
+    pub async fn transfer_with_key(
+        &self,
+        to: NodeId,
+        amount: Credits,
+        idempotency_key: Option<&str>,
+    ) -> Result<TransferId, TransferError> {
+        let Some(key) = idempotency_key else {
+            let transfer = self.open_transfer(to, amount).await?;
+            return self.broadcast_transfer(transfer);
+        };
+
+        // Held until the key is recorded, so concurrent retries can't both
+        // open a transfer
+        let mut keys = self.idempotency_keys.write().await;
+        if let Some(keyed) = keys.get(key) {
+            if keyed.to != to || keyed.amount != amount {
+                return Err(TransferError::KeyReused);
+            }
+            let transfer_id = keyed.transfer_id;
+            drop(keys);
+            return self.resubmit_transfer(transfer_id).await;
+        }
+        let transfer = self.open_transfer(to, amount).await?;
+        keys.insert(
+            key.to_string(),
+            KeyedTransfer {
+                transfer_id: transfer.id,
+                to,
+                amount,
+                created: transfer.timestamp,
+            },
+        );
+        drop(keys);
+        self.broadcast_transfer(transfer)
+    }
+
+    /// Re-broadcast a keyed transfer if it's still pending
+    async fn resubmit_transfer(
+        &self,
+        transfer_id: TransferId,
+    ) -> Result<TransferId, TransferError> {
+        let pending = self
+            .pending_transfers
+            .read()
+            .await
+            .get(&transfer_id)
+            .cloned();
+        match pending {
+            Some(transfer) => self.broadcast_transfer(transfer),
+            None => Ok(transfer_id),
+        }
+    }
+
+    /// Validate a transfer, reserve its credits, and record it as pending
+    async fn open_transfer(
+        &self,
+        to: NodeId,
+        amount: Credits,
+    ) -> Result<CreditTransfer, TransferError> {
         // Validate transfer
         if amount.is_zero() {
             return Err(TransferError::ZeroAmount);
@@ -444,11 +1559,11 @@ impl EnrBridge {
         } // Drop read lock before acquiring write lock
 
         // Generate transfer ID
-        let nonce = Timestamp::now().millis;
+        let nonce = self.next_nonce();
         let transfer_id = TransferId::from_transfer(&self.local_id, &to, amount.amount, nonce);
 
         // Create transfer
-        let transfer = CreditTransfer {
+        let mut transfer = CreditTransfer {
             id: transfer_id,
             from: self.local_id,
             to,
@@ -458,6 +1573,9 @@ impl EnrBridge {
             memo: None,
             signature: Signature::empty(),
         };
+        if let Some(key) = &self.signing_key {
+            transfer.sign(key);
+        }
 
         // Reserve credits (deduct from local balance)
         {
@@ -470,8 +1588,17 @@ impl EnrBridge {
             let mut pending = self.pending_transfers.write().await;
             pending.insert(transfer_id, transfer.clone());
         }
+        {
+            let mut log = self.transfer_log.write().await;
+            log.insert(transfer_id, transfer.clone());
+        }
 
-        // Broadcast transfer
+        Ok(transfer)
+    }
+
+    /// Broadcast a pending transfer, returning its ID
+    fn broadcast_transfer(&self, transfer: CreditTransfer) -> Result<TransferId, TransferError> {
+        let transfer_id = transfer.id;
         let message = EnrMessage::Credit(CreditMessage::Transfer(transfer));
         self.publish(message)
             .map_err(|_| TransferError::Cancelled)?;
@@ -479,51 +1606,547 @@ impl EnrBridge {
         Ok(transfer_id)
     }
 
-    /// Handle incoming credit message
-    async fn handle_credit_message(&self, msg: CreditMessage) -> Result<(), BridgeError> {
-        match msg {
-            CreditMessage::Transfer(transfer) => {
-                // If we're the recipient, credit our balance
-                if transfer.to == self.local_id {
-                    let mut balance = self.local_balance.write().await;
-                    *balance = *balance + Credits::new(transfer.amount);
+    /// Transfer credits through an authoritative ledger, then broadcast
+    ///
+    /// The ledger debit and credit happen first and are checked for
+    /// conservation; only a committed transfer is broadcast, as a
+    /// notification rather than the source of truth. The local balance is
+    /// then debited by `amount`, leaving credits reserved by pending
+    /// transfers untouched. A failed broadcast doesn't undo the ledger
+    /// transfer; it is counted in `dropped_broadcasts`.
+    pub async fn transfer_via_ledger<L: CreditLedger>(
+        &self,
+        ledger: &mut L,
+        to: NodeId,
+        amount: Credits,
+    ) -> Result<TransferId, TransferError> {
+        if amount.is_zero() {
+            return Err(TransferError::ZeroAmount);
+        }
+        if to == self.local_id {
+            return Err(TransferError::SelfTransfer);
+        }
 
-                    // Send confirmation
-                    let confirmation = TransferConfirmation {
-                        transfer_id: transfer.id,
-                        confirmer: self.local_id,
-                        timestamp: Timestamp::now(),
-                        signature: Signature::empty(),
-                    };
-                    let message = EnrMessage::Credit(CreditMessage::Confirmation(confirmation));
-                    let _ = self.publish(message);
-                }
+        let supply_before = ledger.total_supply();
+        ledger.debit(&self.local_id, amount).map_err(|e| match e {
+            EnrError::InsufficientCredits { .. } => TransferError::InsufficientBalance,
+            e => TransferError::Ledger(e),
+        })?;
+        if let Err(e) = ledger.credit(&to, amount) {
+            // Restore the debit; it just succeeded, so this can't overflow
+            let _ = ledger.credit(&self.local_id, amount);
+            return Err(TransferError::Ledger(e));
+        }
+        let supply_after = ledger.total_supply();
+        if supply_after != supply_before {
+            let _ = ledger.debit(&to, amount);
+            let _ = ledger.credit(&self.local_id, amount);
+            return Err(TransferError::Ledger(EnrError::ConservationViolation {
+                expected: supply_before,
+                actual: supply_after,
+            }));
+        }
 
-                // Update known balances
-                {
-                    let mut balances = self.known_balances.write().await;
-                    // Deduct from sender
-                    if let Some(sender_balance) = balances.get_mut(&transfer.from) {
-                        *sender_balance =
-                            sender_balance.saturating_sub(Credits::new(transfer.amount));
-                    }
-                    // Add to recipient
-                    let recipient_balance = balances.entry(transfer.to).or_insert(Credits::zero());
-                    *recipient_balance = *recipient_balance + Credits::new(transfer.amount);
-                }
+        {
+            let mut balance = self.local_balance.write().await;
+            *balance = balance.saturating_sub(amount);
+        }
+
+        let nonce = self.next_nonce();
+        let mut transfer = CreditTransfer {
+            id: TransferId::from_transfer(&self.local_id, &to, amount.amount, nonce),
+            from: self.local_id,
+            to,
+            amount: amount.amount,
+            nonce,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        };
+        if let Some(key) = &self.signing_key {
+            transfer.sign(key);
+        }
+        let transfer_id = transfer.id;
+        self.transfer_log
+            .write()
+            .await
+            .insert(transfer_id, transfer.clone());
+        self.log_settlement(
+            vec![transfer.clone()],
+            SettlementOutcome::Confirmed,
+            transfer.timestamp,
+        )
+        .await;
+
+        let message = EnrMessage::Credit(CreditMessage::Transfer(transfer));
+        if self.publish(message).is_err() {
+            self.dropped_broadcasts.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(transfer_id)
+    }
+
+    /// Transfer credits to several nodes as one batch
+    ///
+    /// All legs are validated and the total reserved up front; each leg is
+    /// then broadcast as its own transfer and confirmed independently. Use
+    /// [`Self::batch_status`] to inspect legs and
+    /// [`Self::refund_expired_batches`] to refund legs that never confirm.
+    ///
+    /// With `net_batch_transfers`, legs are first netted per counterparty
+    /// with [`net_transfers`]. Every leg here is outgoing, so this merges
+    /// legs to the same recipient into one broadcast; use
+    /// [`Self::transfer_netted_batch`] to offset flows in both directions.
+    pub async fn transfer_batch(
+        &self,
+        legs: &[(NodeId, Credits)],
+    ) -> Result<BatchId, TransferError> {
+        let outgoing: Vec<(NodeId, NodeId, Credits)> = legs
+            .iter()
+            .map(|(to, amount)| (self.local_id, *to, *amount))
+            .collect();
+        if self.config.net_batch_transfers {
+            self.transfer_netted_batch(&outgoing).await
+        } else {
+            self.validate_batch(&outgoing)?;
+            self.issue_batch(legs).await
+        }
+    }
+
+    /// Net a batch of flows to and from this node, then transfer our side
+    ///
+    /// Legs are `(from, to, amount)` and must each involve the local node.
+    /// Opposing legs between us and a peer collapse into one in the
+    /// direction of the net flow, so A→B 50 and B→A 30 become A→B 20 and
+    /// both net positions are unchanged. Only the legs we end up sending are
+    /// broadcast and reserved; a pair netting towards us is left for the
+    /// peer, which nets the same flows, to send.
+    pub async fn transfer_netted_batch(
+        &self,
+        legs: &[(NodeId, NodeId, Credits)],
+    ) -> Result<BatchId, TransferError> {
+        self.validate_batch(legs)?;
+        let outgoing: Vec<(NodeId, Credits)> = net_transfers(legs)
+            .into_iter()
+            .filter(|(from, _, _)| *from == self.local_id)
+            .map(|(_, to, amount)| (to, amount))
+            .collect();
+        self.issue_batch(&outgoing).await
+    }
+
+    /// Take the next transfer nonce; never repeats within a bridge
+    fn next_nonce(&self) -> u64 {
+        self.next_transfer_nonce.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Reject zero, self and third-party legs
+    fn validate_batch(&self, legs: &[(NodeId, NodeId, Credits)]) -> Result<(), TransferError> {
+        for (from, to, amount) in legs {
+            if amount.is_zero() {
+                return Err(TransferError::ZeroAmount);
+            }
+            if from == to {
+                return Err(TransferError::SelfTransfer);
+            }
+            if *from != self.local_id && *to != self.local_id {
+                return Err(TransferError::NotParticipant);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reserve the total of outgoing legs and broadcast them as one batch
+    async fn issue_batch(&self, legs: &[(NodeId, Credits)]) -> Result<BatchId, TransferError> {
+        let mut total = Credits::zero();
+        for (_, amount) in legs {
+            total = total
+                .checked_add(*amount)
+                .ok_or(TransferError::InsufficientBalance)?;
+        }
+
+        // Check and reserve the total under one lock
+        {
+            let mut balance = self.local_balance.write().await;
+            if *balance < total {
+                return Err(TransferError::InsufficientBalance);
+            }
+            *balance = balance.saturating_sub(total);
+        }
+
+        // Distinct nonces keep IDs unique when legs repeat a recipient and amount
+        let now = Timestamp::now();
+        let transfers: Vec<CreditTransfer> = legs
+            .iter()
+            .map(|(to, amount)| {
+                let nonce = self.next_nonce();
+                let mut transfer = CreditTransfer {
+                    id: TransferId::from_transfer(&self.local_id, to, amount.amount, nonce),
+                    from: self.local_id,
+                    to: *to,
+                    amount: amount.amount,
+                    nonce,
+                    timestamp: now,
+                    memo: None,
+                    signature: Signature::empty(),
+                };
+                if let Some(key) = &self.signing_key {
+                    transfer.sign(key);
+                }
+                transfer
+            })
+            .collect();
+
+        {
+            let mut pending = self.pending_transfers.write().await;
+            let mut log = self.transfer_log.write().await;
+            for transfer in &transfers {
+                pending.insert(transfer.id, transfer.clone());
+                log.insert(transfer.id, transfer.clone());
+            }
+        }
+
+        let batch_id = BatchId(self.next_batch_id.fetch_add(1, Ordering::Relaxed));
+        self.batches.write().await.insert(
+            batch_id,
+            TransferBatch::new(batch_id, transfers.clone(), now),
+        );
+
+        // Legs that fail to broadcast stay pending and refund on expiry
+        for transfer in transfers {
+            let message = EnrMessage::Credit(CreditMessage::Transfer(transfer));
+            if self.publish(message).is_err() {
+                self.dropped_broadcasts.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(batch_id)
+    }
+
+    /// Drop settled batches whose legs have aged out of the transfer log
+    ///
+    /// Returns the number of batches dropped.
+    pub async fn prune_batches(&self, now: Timestamp) -> usize {
+        let horizon = now
+            .millis
+            .saturating_sub(self.config.transfer_log_retention.as_millis() as u64);
+        let mut batches = self.batches.write().await;
+        let before = batches.len();
+        batches.retain(|_, b| b.created.millis >= horizon || !b.status().is_settled());
+        before - batches.len()
+    }
+
+    /// Per-leg status of a batch, or `None` if the batch is unknown
+    /// or has been pruned
+    pub async fn batch_status(&self, batch_id: BatchId) -> Option<BatchStatus> {
+        self.batches.read().await.get(&batch_id).map(|b| b.status())
+    }
+
+    /// Fail batch legs still unconfirmed after `batch_timeout`, refunding
+    /// or burning them per `transfer_timeout_policy`
+    ///
+    /// Confirmed legs are left alone, as are legs already settled another
+    /// way (e.g. by [`Self::refund_quarantined`]). Burns are recorded in
+    /// [`Self::conservation`]. Returns the total refunded or burned.
+    pub async fn refund_expired_batches(&self, now: Timestamp) -> Credits {
+        let timeout = self.config.batch_timeout.as_millis() as u64;
+        let mut expired = Vec::new();
+        {
+            // Lock order: pending, quarantined, then batches. Only legs still
+            // held here are refunded, and they're removed in the same step
+            let mut pending = self.pending_transfers.write().await;
+            let mut quarantined = self.quarantined_transfers.write().await;
+            let mut batches = self.batches.write().await;
+            for batch in batches.values_mut() {
+                if now.millis.saturating_sub(batch.created.millis) >= timeout {
+                    expired.extend(batch.fail_pending());
+                }
+            }
+            expired
+                .retain(|t| pending.remove(&t.id).is_some() | quarantined.remove(&t.id).is_some());
+        }
+        if expired.is_empty() {
+            return Credits::zero();
+        }
+
+        let total = Credits::new(expired.iter().map(|t| t.amount).sum());
+        let outcome = match self.config.transfer_timeout_policy {
+            TimeoutPolicy::Refund => {
+                *self.local_balance.write().await += total;
+                SettlementOutcome::Refunded
+            }
+            TimeoutPolicy::Burn => {
+                self.conservation.write().await.record_burn(total);
+                SettlementOutcome::Burned
+            }
+        };
+        self.log_settlement(expired, outcome, now).await;
+        total
+    }
+
+    /// Replace the conservation counters, e.g. to set the genesis amount
+    pub async fn set_conservation(&self, conservation: CreditConservation) {
+        *self.conservation.write().await = conservation;
+    }
+
+    /// Credits minted and burned by this bridge
+    pub async fn conservation(&self) -> CreditConservation {
+        self.conservation.read().await.clone()
+    }
+
+    /// Append settled transfers to the bounded settlement log
+    async fn log_settlement(
+        &self,
+        transfers: Vec<CreditTransfer>,
+        outcome: SettlementOutcome,
+        settled_at: Timestamp,
+    ) {
+        let capacity = self.config.settlement_log_capacity;
+        let mut log = self.settlement_log.write().await;
+        for transfer in transfers {
+            log.push_back(SettledTransfer {
+                transfer,
+                outcome,
+                settled_at,
+            });
+        }
+        while log.len() > capacity {
+            log.pop_front();
+        }
+    }
+
+    /// Credit movements since `since`, for period-end reconciliation
+    ///
+    /// Confirmed and refunded transfers come from the settlement log, so
+    /// only the last `settlement_log_capacity` settlements are covered.
+    /// Pending transfers are listed whenever they were sent, since they're
+    /// still open at the close of the period.
+    pub async fn transfer_report(&self, since: Timestamp) -> TransferReport {
+        let mut confirmed = Vec::new();
+        let mut refunded = Vec::new();
+        let mut burned = Vec::new();
+        let mut net_positions: HashMap<NodeId, i128> = HashMap::new();
+        for settled in self.settlement_log.read().await.iter() {
+            if settled.settled_at < since {
+                continue;
+            }
+            let transfer = &settled.transfer;
+            match settled.outcome {
+                SettlementOutcome::Confirmed => {
+                    let amount = transfer.amount as i128;
+                    *net_positions.entry(transfer.from).or_default() -= amount;
+                    *net_positions.entry(transfer.to).or_default() += amount;
+                    confirmed.push(transfer.clone());
+                }
+                SettlementOutcome::Refunded => refunded.push(transfer.clone()),
+                SettlementOutcome::Burned => burned.push(transfer.clone()),
+            }
+        }
+
+        let mut pending: Vec<CreditTransfer> = self
+            .pending_transfers
+            .read()
+            .await
+            .values()
+            .chain(self.quarantined_transfers.read().await.values())
+            .cloned()
+            .collect();
+        pending.sort_by_key(|t| (t.timestamp, t.nonce));
+
+        TransferReport {
+            since,
+            generated_at: Timestamp::now(),
+            confirmed,
+            pending,
+            refunded,
+            burned,
+            net_positions,
+        }
+    }
+
+    /// Set the local entropy conditions used for resource quotes
+    pub async fn set_local_entropy(&self, entropy: EntropyAccount) {
+        *self.local_entropy.write().await = entropy;
+    }
+
+    /// Local entropy conditions used for resource quotes
+    pub async fn local_entropy(&self) -> EntropyAccount {
+        *self.local_entropy.read().await
+    }
+
+    /// Reserve credits for a resource operation paid to `to`
+    ///
+    /// Quotes `estimated_usage` of `resource` under the local entropy
+    /// conditions and sets aside the quoted total plus
+    /// `reservation_margin_bps`. The reserved credits leave the local
+    /// balance until the reservation is settled or expires.
+    pub async fn reserve_for_resource(
+        &self,
+        to: NodeId,
+        resource: ResourceType,
+        estimated_usage: u64,
+        pricer: &Pricer,
+    ) -> Result<ReservationId, TransferError> {
+        if to == self.local_id {
+            return Err(TransferError::SelfTransfer);
+        }
+
+        let entropy = self.local_entropy().await;
+        let quote = pricer.quote_resource(resource, estimated_usage, &entropy);
+        let amount = quote.total_price.saturating_add(
+            quote
+                .total_price
+                .basis_points(self.config.reservation_margin_bps),
+        );
+        if amount.is_zero() {
+            return Err(TransferError::ZeroAmount);
+        }
+
+        {
+            let mut balance = self.local_balance.write().await;
+            if *balance < amount {
+                return Err(TransferError::InsufficientBalance);
+            }
+            *balance = balance.saturating_sub(amount);
+        }
+
+        let ttl = crate::core::Duration::new(self.config.reservation_ttl.as_millis() as u64);
+        Ok(self
+            .reservations
+            .write()
+            .await
+            .reserve(AccountId::node_account(to), amount, ttl))
+    }
+
+    /// Settle a reservation by transferring `actual` to its payee
+    ///
+    /// The full reserved amount is returned to the local balance and
+    /// `actual` is then transferred, so any unused margin is refunded.
+    /// Fails without consuming the reservation if `actual` exceeds it.
+    /// Settling for zero releases the reservation and returns `ZeroAmount`.
+    pub async fn settle_reservation(
+        &self,
+        id: ReservationId,
+        actual: Credits,
+    ) -> Result<TransferId, TransferError> {
+        let (payee, reserved) = {
+            let mut reservations = self.reservations.write().await;
+            let reservation = reservations.get(id).ok_or(TransferError::Reservation(
+                EnrError::ReservationNotFound(id),
+            ))?;
+            if actual > reservation.amount {
+                return Err(TransferError::InsufficientBalance);
+            }
+            let payee = reservation.account.node;
+            let reserved = reservations
+                .consume(id)
+                .map_err(TransferError::Reservation)?;
+            (payee, reserved)
+        };
+
+        *self.local_balance.write().await += reserved;
+        self.transfer(payee, actual).await
+    }
+
+    /// Release unsettled reservations that expired before `now`
+    ///
+    /// Returns the total refunded to the local balance.
+    pub async fn release_expired_reservations(&self, now: Timestamp) -> Credits {
+        let released = self.reservations.write().await.release_expired(now);
+        let refund = Credits::new(released.iter().map(|r| r.amount.amount).sum());
+        *self.local_balance.write().await += refund;
+        refund
+    }
+
+    /// Handle incoming credit message
+    async fn handle_credit_message(&self, msg: CreditMessage) -> Result<(), BridgeError> {
+        match msg {
+            CreditMessage::Transfer(transfer) => {
+                // Ignore transfers already seen (e.g. via settlement)
+                if !self.accept_inbound_transfer(&transfer).await {
+                    return Ok(());
+                }
+
+                // If we're the recipient, confirm receipt
+                if transfer.to == self.local_id {
+                    let confirmation = TransferConfirmation {
+                        transfer_id: transfer.id,
+                        confirmer: self.local_id,
+                        timestamp: Timestamp::now(),
+                        signature: Signature::empty(),
+                    };
+                    let message = EnrMessage::Credit(CreditMessage::Confirmation(confirmation));
+                    let _ = self.publish(message);
+                    self.log_settlement(
+                        vec![transfer],
+                        SettlementOutcome::Confirmed,
+                        Timestamp::now(),
+                    )
+                    .await;
+                } else if transfer.from != self.local_id {
+                    self.unconfirmed_remote
+                        .write()
+                        .await
+                        .insert(transfer.id, transfer);
+                }
 
                 Ok(())
             }
             CreditMessage::Confirmation(confirmation) => {
+                // Quarantined transfers stay held until the counterparty recovers
+                if self
+                    .quarantined_transfers
+                    .read()
+                    .await
+                    .contains_key(&confirmation.transfer_id)
+                {
+                    return Ok(());
+                }
+
+                let remote = self
+                    .unconfirmed_remote
+                    .write()
+                    .await
+                    .remove(&confirmation.transfer_id);
+
                 // Remove from pending
-                let mut pending = self.pending_transfers.write().await;
-                pending.remove(&confirmation.transfer_id);
+                let removed = self
+                    .pending_transfers
+                    .write()
+                    .await
+                    .remove(&confirmation.transfer_id);
+                if removed.is_some() {
+                    for batch in self.batches.write().await.values_mut() {
+                        if batch.confirm(&confirmation.transfer_id) {
+                            break;
+                        }
+                    }
+                }
+                if let Some(transfer) = removed.or(remote) {
+                    self.log_settlement(
+                        vec![transfer],
+                        SettlementOutcome::Confirmed,
+                        Timestamp::now(),
+                    )
+                    .await;
+                }
                 Ok(())
             }
             CreditMessage::StateSync(sync) => {
+                // Ignore syncs older than the version we already hold. Lock
+                // order: versions, then balances (as in merge_known_balances)
+                let mut versions = self.balance_versions.write().await;
+                if versions
+                    .get(&sync.node_id)
+                    .is_some_and(|known| sync.version < *known)
+                {
+                    return Ok(());
+                }
+                versions.insert(sync.node_id, sync.version);
+
                 // Update known balance
                 let mut balances = self.known_balances.write().await;
                 balances.insert(sync.node_id, Credits::new(sync.balance));
+                self.touch_balances([sync.node_id]).await;
                 Ok(())
             }
             CreditMessage::BalanceQuery {
@@ -544,18 +2167,373 @@ impl EnrBridge {
             CreditMessage::BalanceResponse { node_id, balance } => {
                 let mut balances = self.known_balances.write().await;
                 balances.insert(node_id, Credits::new(balance));
+                self.touch_balances([node_id]).await;
+                Ok(())
+            }
+            CreditMessage::SettlementRequest(request) => {
+                if request.requester == self.local_id {
+                    return Ok(());
+                }
+
+                // Same acceptance as gossiped transfers; the log catches replays
+                let mut seen = HashSet::new();
+                for transfer in &request.transfers {
+                    seen.insert(transfer.id);
+                    self.accept_inbound_transfer(transfer).await;
+                }
+                let mut missing: Vec<CreditTransfer> = self
+                    .transfers_involving(&request.counterparty)
+                    .await
+                    .into_iter()
+                    .filter(|t| request.covers(t.timestamp) && !seen.contains(&t.id))
+                    .collect();
+                missing.sort_by_key(|t| (t.timestamp, t.id.0));
+
+                let canonical_balance = self.settled_balance(&request.counterparty).await.amount;
+                let balance_version = self
+                    .balance_versions
+                    .read()
+                    .await
+                    .get(&request.counterparty)
+                    .copied()
+                    .unwrap_or(0);
+                for chunk in chunk_transfers(&missing, SETTLEMENT_CHUNK_BYTES) {
+                    let response = SettlementResponse {
+                        responder: self.local_id,
+                        requester: request.requester,
+                        counterparty: request.counterparty,
+                        missing: chunk.to_vec(),
+                        canonical_balance,
+                        balance_version,
+                        timestamp: Timestamp::now(),
+                    };
+                    self.publish(EnrMessage::Credit(CreditMessage::SettlementResponse(
+                        response,
+                    )))?;
+                }
+                Ok(())
+            }
+            CreditMessage::SettlementResponse(response) => {
+                if response.requester != self.local_id {
+                    return Ok(());
+                }
+
+                for transfer in &response.missing {
+                    self.accept_inbound_transfer(transfer).await;
+                }
+                // Only the counterparty can vouch for its own balance; other
+                // peers' views are covered by the transfers they sent. Merge
+                // rather than letting the last reply win
+                if response.counterparty != self.local_id
+                    && response.responder == response.counterparty
+                {
+                    let balance = HashMap::from([(
+                        response.counterparty,
+                        Credits::new(response.canonical_balance),
+                    )]);
+                    let version =
+                        HashMap::from([(response.counterparty, response.balance_version)]);
+                    self.merge_known_balances(&balance, &version).await;
+                }
                 Ok(())
             }
         }
     }
 
+    /// Validate, record and apply a transfer received from a peer
+    ///
+    /// Shared by gossip and settlement. Rejects transfers whose signature
+    /// doesn't verify (unsigned ones pass, as no key may be configured),
+    /// replays, and double-spends when `reject_double_spends` is set.
+    /// Returns true if the transfer was applied.
+    async fn accept_inbound_transfer(&self, transfer: &CreditTransfer) -> bool {
+        if !transfer.signature.is_empty() && !transfer.verify_signature() {
+            return false;
+        }
+        if !self.record_transfer(transfer).await {
+            return false;
+        }
+        if self.check_double_spend(transfer).await && self.config.reject_double_spends {
+            // Unlogged, so settlement doesn't pass it on
+            self.transfer_log.write().await.remove(&transfer.id);
+            return false;
+        }
+        self.apply_transfer(transfer).await;
+        true
+    }
+
+    /// Add a transfer to the log, which doubles as the replay cache
+    ///
+    /// Returns false if it's already logged or predates the log horizon.
+    async fn record_transfer(&self, transfer: &CreditTransfer) -> bool {
+        if transfer.timestamp.millis < self.transfer_log_horizon.load(Ordering::Relaxed) {
+            return false;
+        }
+        let mut log = self.transfer_log.write().await;
+        if log.contains_key(&transfer.id) {
+            return false;
+        }
+        log.insert(transfer.id, transfer.clone());
+        true
+    }
+
+    /// Apply a transfer to balances
+    ///
+    /// Credits the local balance when we're the recipient and moves the
+    /// amount between known balances.
+    async fn apply_transfer(&self, transfer: &CreditTransfer) {
+        let amount = Credits::new(transfer.amount);
+        if transfer.to == self.local_id {
+            let mut balance = self.local_balance.write().await;
+            *balance += amount;
+        }
+
+        let mut balances = self.known_balances.write().await;
+        // Deduct from sender
+        if let Some(sender_balance) = balances.get_mut(&transfer.from) {
+            *sender_balance = sender_balance.saturating_sub(amount);
+        }
+        // Add to recipient
+        let recipient_balance = balances.entry(transfer.to).or_insert(Credits::zero());
+        *recipient_balance += amount;
+        let touched: Vec<NodeId> = [transfer.from, transfer.to]
+            .into_iter()
+            .filter(|node| balances.contains_key(node))
+            .collect();
+        self.touch_balances(touched).await;
+    }
+
+    /// Flag a remote transfer that overspends its sender's known balance
+    ///
+    /// A state sync can replace the known balance with one that predates
+    /// transfers still awaiting confirmation, so the check is against the
+    /// sender's last-known balance minus its outstanding outflow. Senders
+    /// with no known balance can't be checked. Returns true if the transfer
+    /// was flagged.
+    async fn check_double_spend(&self, transfer: &CreditTransfer) -> bool {
+        if transfer.from == self.local_id {
+            return false;
+        }
+        let Some(known_balance) = self
+            .known_balances
+            .read()
+            .await
+            .get(&transfer.from)
+            .copied()
+        else {
+            return false;
+        };
+        let outstanding = self.outstanding_outflow(&transfer.from).await;
+        if transfer.amount <= known_balance.saturating_sub(outstanding).amount {
+            return false;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            sender = %transfer.from,
+            amount = transfer.amount,
+            known_balance = known_balance.amount,
+            outstanding = outstanding.amount,
+            "potential double-spend"
+        );
+        let mut suspects = self.double_spend_suspects.write().await;
+        suspects.push_back(DoubleSpendSuspect {
+            transfer: transfer.clone(),
+            known_balance,
+            outstanding,
+            detected_at: Timestamp::now(),
+        });
+        while suspects.len() > self.config.double_spend_suspect_capacity {
+            suspects.pop_front();
+        }
+        true
+    }
+
+    /// Credits a remote node has committed in transfers not yet confirmed
+    pub async fn outstanding_outflow(&self, node_id: &NodeId) -> Credits {
+        let total = self
+            .unconfirmed_remote
+            .read()
+            .await
+            .values()
+            .filter(|t| t.from == *node_id)
+            .map(|t| t.amount)
+            .sum();
+        Credits::new(total)
+    }
+
+    /// Transfers flagged as potential double-spends, oldest first
+    pub async fn double_spend_suspects(&self) -> Vec<DoubleSpendSuspect> {
+        self.double_spend_suspects
+            .read()
+            .await
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Stop counting other nodes' transfers unconfirmed past
+    /// `remote_transfer_ttl` as outstanding
+    ///
+    /// Returns the number of transfers dropped.
+    pub async fn expire_unconfirmed_remote(&self, now: Timestamp) -> usize {
+        let ttl = self.config.remote_transfer_ttl.as_millis() as u64;
+        let mut remote = self.unconfirmed_remote.write().await;
+        let before = remote.len();
+        remote.retain(|_, t| now.millis.saturating_sub(t.timestamp.millis) < ttl);
+        before - remote.len()
+    }
+
+    /// Stamp known balances as updated now
+    async fn touch_balances(&self, nodes: impl IntoIterator<Item = NodeId>) {
+        let now = Timestamp::now();
+        let mut updated_at = self.balance_updated_at.write().await;
+        for node in nodes {
+            updated_at.insert(node, now);
+        }
+    }
+
+    /// A node's known balance and how long ago it was last updated
+    ///
+    /// Consumers can refuse to act on balances older than they tolerate.
+    pub async fn known_balance_with_age(&self, node: &NodeId) -> Option<(Credits, Duration)> {
+        let balance = *self.known_balances.read().await.get(node)?;
+        let updated = *self.balance_updated_at.read().await.get(node)?;
+        let age_ms = Timestamp::now().millis.saturating_sub(updated.millis);
+        Some((balance, Duration::from_millis(age_ms)))
+    }
+
+    /// Logged transfers sent or received by `node`
+    async fn transfers_involving(&self, node: &NodeId) -> Vec<CreditTransfer> {
+        let log = self.transfer_log.read().await;
+        log.values()
+            .filter(|t| t.from == *node || t.to == *node)
+            .cloned()
+            .collect()
+    }
+
+    /// Our view of a node's balance (local balance if it's us)
+    async fn settled_balance(&self, node: &NodeId) -> Credits {
+        if *node == self.local_id {
+            return *self.local_balance.read().await;
+        }
+        let balances = self.known_balances.read().await;
+        balances.get(node).copied().unwrap_or(Credits::zero())
+    }
+
+    /// Ask peers to reconcile transfers involving `counterparty`
+    ///
+    /// Call after a partition heals. Peers apply any transfers we saw that
+    /// they missed and reply with the ones we missed plus their canonical
+    /// balance for the counterparty. Logs too large for one message are
+    /// sent as several requests covering consecutive time ranges.
+    pub async fn request_settlement(&self, counterparty: NodeId) -> Result<(), BridgeError> {
+        let mut transfers = self.transfers_involving(&counterparty).await;
+        transfers.sort_by_key(|t| (t.timestamp, t.id.0));
+        let chunks = chunk_transfers(&transfers, SETTLEMENT_CHUNK_BYTES);
+        for (i, chunk) in chunks.iter().enumerate() {
+            let since = match i {
+                0 => Timestamp::new(0),
+                _ => chunk[0].timestamp,
+            };
+            let until = chunks.get(i + 1).map(|next| next[0].timestamp);
+            let request = SettlementRequest {
+                requester: self.local_id,
+                counterparty,
+                transfers: chunk.to_vec(),
+                since,
+                until,
+                timestamp: Timestamp::now(),
+            };
+            self.publish(EnrMessage::Credit(CreditMessage::SettlementRequest(
+                request,
+            )))?;
+        }
+        Ok(())
+    }
+
+    /// Drop logged transfers older than the retention window
+    ///
+    /// Transfers still pending, quarantined or awaiting confirmation are
+    /// kept. Transfers older than the window are rejected from then on, so
+    /// pruning can't reopen them to replay. Idempotency keys for pruned
+    /// transfers are dropped too. Returns the number of transfers dropped.
+    pub async fn prune_transfer_log(&self, now: Timestamp) -> usize {
+        let horizon = now
+            .millis
+            .saturating_sub(self.config.transfer_log_retention.as_millis() as u64);
+        let mut open: HashSet<TransferId> = HashSet::new();
+        open.extend(self.pending_transfers.read().await.keys());
+        open.extend(self.quarantined_transfers.read().await.keys());
+        open.extend(self.unconfirmed_remote.read().await.keys());
+
+        let pruned = {
+            let mut log = self.transfer_log.write().await;
+            let before = log.len();
+            log.retain(|id, t| t.timestamp.millis >= horizon || open.contains(id));
+            before - log.len()
+        };
+        self.transfer_log_horizon
+            .fetch_max(horizon, Ordering::Relaxed);
+
+        self.idempotency_keys
+            .write()
+            .await
+            .retain(|_, k| k.created.millis >= horizon || open.contains(&k.transfer_id));
+        pruned
+    }
+
+    /// Merge another bridge's known-balance view into ours
+    ///
+    /// Used when a standby bridge rejoins or partitions heal. Entries with
+    /// the higher sync version win; see [`merge_balances`].
+    pub async fn merge_known_balances(
+        &self,
+        other_balances: &HashMap<NodeId, Credits>,
+        other_versions: &BalanceVersions,
+    ) {
+        // Same lock order as the StateSync handler
+        let mut versions = self.balance_versions.write().await;
+        let mut balances = self.known_balances.write().await;
+        let merged = merge_balances(&balances, other_balances, (&versions, other_versions));
+        let changed: Vec<NodeId> = merged
+            .iter()
+            .filter(|(node, balance)| balances.get(node) != Some(balance))
+            .map(|(node, _)| *node)
+            .collect();
+        *balances = merged;
+        *versions = merge_versions(&versions, other_versions);
+        self.touch_balances(changed).await;
+    }
+
+    /// Charge an operation's entropy to a node's budget
+    ///
+    /// Returns false if the node has exhausted its budget; [`Self::route`]
+    /// refuses to route the operation until the budget refills.
+    pub async fn try_spend_entropy(&self, node: NodeId, account: &EntropyAccount) -> bool {
+        let mut budgets = self.entropy_budgets.write().await;
+        budgets
+            .entry(node)
+            .or_insert_with(|| {
+                EntropyBudget::new(
+                    self.config.entropy_budget_capacity,
+                    self.config.entropy_budget_leak_per_sec,
+                )
+            })
+            .try_spend(account)
+    }
+
     // ========================================================================
     // Septal Gate (Circuit Breaker)
     // ========================================================================
 
     /// Record a failure for a node
+    ///
+    /// The trip threshold scales with local network entropy per
+    /// `septal_config.entropy_threshold_scale`.
     pub async fn record_failure(&self, node_id: NodeId, reason: &str) {
-        {
+        let network_entropy = self.local_entropy().await.network;
+        let tripped = {
             let mut gates = self.septal_gates.write().await;
             let gate = gates
                 .entry(node_id)
@@ -563,10 +2541,17 @@ impl EnrBridge {
             gate.record_failure();
 
             // Check if should trip
-            if gate.should_trip() {
+            let trip = gate.should_trip_at(&self.config.septal_config, network_entropy);
+            if trip {
                 gate.trip();
             }
+            trip
+        };
+        if tripped {
+            self.quarantine_transfers(&node_id).await;
         }
+        self.adjust_trust(&node_id, -self.config.trust_penalty)
+            .await;
 
         // Broadcast failure report
         let report = FailureReport {
@@ -598,27 +2583,137 @@ impl EnrBridge {
             .unwrap_or(SeptalGateState::Open)
     }
 
-    /// Handle incoming septal message
-    async fn handle_septal_message(&self, msg: SeptalMessage) -> Result<(), BridgeError> {
-        match msg {
-            SeptalMessage::FailureReport(report) => {
-                let mut gates = self.septal_gates.write().await;
-                let gate = gates
-                    .entry(report.failed_node)
-                    .or_insert_with(|| SeptalGate::new(report.failed_node));
-                gate.record_failure();
+    /// Number of pending transfers currently held in quarantine
+    pub async fn quarantined_count(&self) -> usize {
+        self.quarantined_transfers.read().await.len()
+    }
 
-                if gate.should_trip() {
-                    gate.trip();
+    /// Move pending transfers to or from an isolated node into quarantine
+    ///
+    /// Quarantined transfers ignore confirmations until the node recovers.
+    async fn quarantine_transfers(&self, node_id: &NodeId) {
+        let mut pending = self.pending_transfers.write().await;
+        let affected: Vec<TransferId> = pending
+            .values()
+            .filter(|t| t.from == *node_id || t.to == *node_id)
+            .map(|t| t.id)
+            .collect();
+        if affected.is_empty() {
+            return;
+        }
+
+        let mut quarantined = self.quarantined_transfers.write().await;
+        for id in affected {
+            if let Some(transfer) = pending.remove(&id) {
+                quarantined.insert(id, transfer);
+            }
+        }
+    }
+
+    /// Return a recovered node's quarantined transfers to the pending set
+    async fn release_quarantined(&self, node_id: &NodeId) {
+        let mut pending = self.pending_transfers.write().await;
+        let mut quarantined = self.quarantined_transfers.write().await;
+        let released: Vec<TransferId> = quarantined
+            .values()
+            .filter(|t| t.from == *node_id || t.to == *node_id)
+            .map(|t| t.id)
+            .collect();
+        for id in released {
+            if let Some(transfer) = quarantined.remove(&id) {
+                pending.insert(id, transfer);
+            }
+        }
+    }
+
+    /// Drop a permanently isolated node's quarantined transfers
+    ///
+    /// Credits reserved by our own outgoing transfers are returned to the
+    /// local balance. Returns the total amount refunded.
+    pub async fn refund_quarantined(&self, node_id: &NodeId) -> Credits {
+        let mut quarantined = self.quarantined_transfers.write().await;
+        let mut refunded = Vec::new();
+        quarantined.retain(|_, t| {
+            if t.from != *node_id && t.to != *node_id {
+                return true;
+            }
+            if t.from == self.local_id {
+                refunded.push(t.clone());
+            }
+            false
+        });
+        drop(quarantined);
+
+        let refund = Credits::new(refunded.iter().map(|t| t.amount).sum());
+        if !refund.is_zero() {
+            let mut balance = self.local_balance.write().await;
+            *balance += refund;
+            drop(balance);
+            self.log_settlement(refunded, SettlementOutcome::Refunded, Timestamp::now())
+                .await;
+        }
+        refund
+    }
+
+    /// Mark nodes recovered locally and broadcast a `RecoveryNotice` for each
+    ///
+    /// Feed this the nodes returned by `WoroninManager::sweep_timed_out` or
+    /// `HealingManager::attempt_recovery_all` so peers resume routing to them.
+    pub async fn announce_recoveries(&self, nodes: &[NodeId]) -> Result<(), BridgeError> {
+        {
+            let mut gates = self.septal_gates.write().await;
+            for node in nodes {
+                if let Some(gate) = gates.get_mut(node) {
+                    gate.recover();
+                }
+            }
+        }
+        for node in nodes {
+            self.release_quarantined(node).await;
+        }
+
+        for node in nodes {
+            let notice = RecoveryNotice {
+                recovered_node: *node,
+                timestamp: Timestamp::now(),
+            };
+            self.publish(EnrMessage::Septal(SeptalMessage::Recovery(notice)))?;
+        }
+        Ok(())
+    }
+
+    /// Handle incoming septal message
+    async fn handle_septal_message(&self, msg: SeptalMessage) -> Result<(), BridgeError> {
+        match msg {
+            SeptalMessage::FailureReport(report) => {
+                let network_entropy = self.local_entropy().await.network;
+                let tripped = {
+                    let mut gates = self.septal_gates.write().await;
+                    let gate = gates
+                        .entry(report.failed_node)
+                        .or_insert_with(|| SeptalGate::new(report.failed_node));
+                    gate.record_failure();
+
+                    let trip = gate.should_trip_at(&self.config.septal_config, network_entropy);
+                    if trip {
+                        gate.trip();
+                    }
+                    trip
+                };
+                if tripped {
+                    self.quarantine_transfers(&report.failed_node).await;
                 }
                 Ok(())
             }
             SeptalMessage::Isolation(notice) => {
-                let mut gates = self.septal_gates.write().await;
-                let gate = gates
-                    .entry(notice.isolated_node)
-                    .or_insert_with(|| SeptalGate::new(notice.isolated_node));
-                gate.trip();
+                {
+                    let mut gates = self.septal_gates.write().await;
+                    let gate = gates
+                        .entry(notice.isolated_node)
+                        .or_insert_with(|| SeptalGate::new(notice.isolated_node));
+                    gate.trip();
+                }
+                self.quarantine_transfers(&notice.isolated_node).await;
                 Ok(())
             }
             SeptalMessage::HealingProbe(probe) => {
@@ -637,20 +2732,33 @@ impl EnrBridge {
             }
             SeptalMessage::HealingResponse(response) => {
                 // Mark successful probe - attempt half-open or recover
-                let mut gates = self.septal_gates.write().await;
-                if let Some(gate) = gates.get_mut(&response.responder) {
-                    gate.record_success();
-                    if gate.state.is_half_open() {
-                        gate.recover();
+                let recovered = {
+                    let mut gates = self.septal_gates.write().await;
+                    match gates.get_mut(&response.responder) {
+                        Some(gate) => {
+                            gate.record_success();
+                            let half_open = gate.state.is_half_open();
+                            if half_open {
+                                gate.recover();
+                            }
+                            half_open
+                        }
+                        None => false,
                     }
+                };
+                if recovered {
+                    self.release_quarantined(&response.responder).await;
                 }
                 Ok(())
             }
             SeptalMessage::Recovery(notice) => {
-                let mut gates = self.septal_gates.write().await;
-                if let Some(gate) = gates.get_mut(&notice.recovered_node) {
-                    gate.recover();
+                {
+                    let mut gates = self.septal_gates.write().await;
+                    if let Some(gate) = gates.get_mut(&notice.recovered_node) {
+                        gate.recover();
+                    }
                 }
+                self.release_quarantined(&notice.recovered_node).await;
                 Ok(())
             }
         }
@@ -660,11 +2768,96 @@ impl EnrBridge {
     // Lifecycle
     // ========================================================================
 
-    /// Stop all broadcast loops
-    pub fn stop(&mut self) {
-        for handle in self.broadcast_handles.drain(..) {
-            handle.abort();
+    /// Start the maintenance loop
+    ///
+    /// Spawns a tokio task that runs [`Self::run_maintenance`] every
+    /// `maintenance_interval`, so timed-out election rounds advance without
+    /// an outside caller. It publishes through the publisher connected at
+    /// the time of this call and is unaffected by
+    /// [`Self::pause_broadcasts`].
+    pub async fn start_maintenance(&mut self) -> Result<JoinHandle<()>, BridgeError> {
+        if self.publish_fn.is_none() {
+            return Err(BridgeError::NotConnected);
+        }
+
+        let bridge = self.share();
+        let shutdown = self.shutdown.clone();
+        let interval = self.config.maintenance_interval;
+        let task = async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = shutdown.cancelled() => break,
+                    _ = ticker.tick() => {}
+                }
+                bridge.run_maintenance().await;
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(
+            task,
+            tracing::info_span!("maintenance", node = %self.local_id),
+        );
+        Ok(self.worker_tasks.spawn(task))
+    }
+
+    /// One pass of periodic upkeep
+    ///
+    /// Drives the election this node is coordinating through
+    /// [`Self::poll_election`], settles expired batches, expires stale
+    /// remote transfers and prunes the transfer log and settled batches.
+    pub async fn run_maintenance(&self) {
+        if let Err(_e) = self.poll_election().await {
+            // The re-announcement is retried on the next pass
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %_e, "failed to advance election");
         }
+        let now = Timestamp::now();
+        self.refund_expired_batches(now).await;
+        self.expire_unconfirmed_remote(now).await;
+        self.prune_transfer_log(now).await;
+        self.prune_batches(now).await;
+    }
+
+    /// Suspend broadcast loops without aborting them
+    ///
+    /// Loops keep ticking but publish nothing until [`Self::resume_broadcasts`].
+    pub fn pause_broadcasts(&self) {
+        self.broadcasts_paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resume broadcast loops suspended by [`Self::pause_broadcasts`]
+    pub fn resume_broadcasts(&self) {
+        self.broadcasts_paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether a broadcast loop is running and not paused
+    pub fn is_broadcasting(&self) -> bool {
+        !self.broadcasts_paused.load(Ordering::Relaxed) && !self.broadcast_tasks.is_empty()
+    }
+
+    /// Signal all broadcast loops and dispatch workers to stop
+    ///
+    /// Loops exit at their next safe point, between ticks or between
+    /// messages, never while holding a lock. This doesn't wait for them; use
+    /// [`Self::shutdown`] for that. Loops can be started again afterwards.
+    pub fn stop(&mut self) {
+        std::mem::take(&mut self.shutdown).cancel();
+        std::mem::take(&mut self.broadcast_tasks).close();
+        std::mem::take(&mut self.worker_tasks).close();
+        self.dispatch_shards.clear();
+    }
+
+    /// Stop all loops and wait until every one has exited
+    ///
+    /// Messages still queued for the dispatch workers are left unhandled.
+    pub async fn shutdown(&mut self) {
+        let broadcasts = self.broadcast_tasks.clone();
+        let workers = self.worker_tasks.clone();
+        self.stop();
+        broadcasts.wait().await;
+        workers.wait().await;
     }
 }
 
@@ -683,12 +2876,43 @@ mod tests {
         NodeId::from_bytes([1u8; 32])
     }
 
+    /// A gradient reporting `cpu` signed by the key seeded with `seed`
+    fn signed_gradient(seed: u8, cpu: f64) -> GradientMessage {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let mut gradient = GradientMessage {
+            node_id: NodeId::from_bytes(key.verifying_key().to_bytes()),
+            gradient: GradientPayload::from(&ResourceGradient {
+                cpu_available: cpu,
+                ..ResourceGradient::zero()
+            }),
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        };
+        gradient.sign(&key);
+        gradient
+    }
+
+    /// A vote for `candidate` signed by the key seeded with `seed`
+    fn signed_vote(seed: u8, election_id: [u8; 32], candidate: NodeId) -> ElectionVote {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let mut vote = ElectionVote {
+            election_id,
+            voter: NodeId::from_bytes(key.verifying_key().to_bytes()),
+            candidate,
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        };
+        vote.sign(&key);
+        vote
+    }
+
     #[test]
     fn test_bridge_config_default() {
         let config = EnrBridgeConfig::default();
         assert_eq!(config.gradient_interval, Duration::from_secs(10));
         assert_eq!(config.election_interval, Duration::from_secs(3600));
         assert!(config.credit_sync_enabled);
+        assert_eq!(config.publish_retries, 3);
     }
 
     #[test]
@@ -890,6 +3114,228 @@ mod tests {
         assert!(gradients.get(&isolated_node).is_none());
     }
 
+    #[tokio::test]
+    async fn test_double_spend_detection() {
+        let local_id = test_node_id();
+        let spender = NodeId::from_bytes([2u8; 32]);
+        let other = NodeId::from_bytes([3u8; 32]);
+        let spend = |to, nonce| CreditTransfer {
+            id: TransferId::from_transfer(&spender, &to, 80, nonce),
+            from: spender,
+            to,
+            amount: 80,
+            nonce,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        };
+
+        for reject in [false, true] {
+            let config = EnrBridgeConfig {
+                reject_double_spends: reject,
+                ..Default::default()
+            };
+            let mut bridge = EnrBridge::new(local_id, config);
+            let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let sink = published.clone();
+            bridge.connect_publisher(Arc::new(move |_topic, data| {
+                sink.lock()
+                    .unwrap()
+                    .push(EnrMessage::from_bytes(&data).unwrap());
+                Ok(())
+            }));
+            bridge
+                .known_balances
+                .write()
+                .await
+                .insert(spender, Credits::new(100));
+
+            // First spend fits the known balance
+            bridge
+                .handle_credit_message(CreditMessage::Transfer(spend(other, 1)))
+                .await
+                .unwrap();
+            assert_eq!(bridge.outstanding_outflow(&spender).await, Credits::new(80));
+            assert!(bridge.double_spend_suspects().await.is_empty());
+
+            // Second spend of the same credits, to us
+            bridge
+                .handle_credit_message(CreditMessage::Transfer(spend(local_id, 2)))
+                .await
+                .unwrap();
+            let suspects = bridge.double_spend_suspects().await;
+            assert_eq!(suspects.len(), 1);
+            assert_eq!(suspects[0].known_balance, Credits::new(20));
+            assert_eq!(suspects[0].outstanding, Credits::new(80));
+
+            let confirmed = published
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|m| matches!(m, EnrMessage::Credit(CreditMessage::Confirmation(_))));
+            assert_eq!(confirmed, !reject);
+            let expected = if reject { 0 } else { 80 };
+            assert_eq!(bridge.balance().await, Credits::new(expected));
+
+            // Transfers to us are confirmed on receipt, so never outstanding
+            assert!(!bridge
+                .unconfirmed_remote
+                .read()
+                .await
+                .values()
+                .any(|t| t.to == local_id));
+
+            // Unconfirmed remote transfers stop counting once they go stale
+            let later = Timestamp::new(Timestamp::now().millis + 120_000);
+            assert_eq!(bridge.expire_unconfirmed_remote(later).await, 1);
+            assert!(bridge.outstanding_outflow(&spender).await.is_zero());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_double_spend_counts_outstanding_outflow() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_t, _d| Ok(())));
+        let spender = NodeId::from_bytes([2u8; 32]);
+        let spend = |to: NodeId, amount, nonce| CreditTransfer {
+            id: TransferId::from_transfer(&spender, &to, amount, nonce),
+            from: spender,
+            to,
+            amount,
+            nonce,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        };
+        let sync = |balance, version| {
+            CreditMessage::StateSync(CreditStateSync {
+                node_id: spender,
+                balance,
+                version,
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            })
+        };
+
+        bridge.handle_credit_message(sync(100, 1)).await.unwrap();
+        bridge
+            .handle_credit_message(CreditMessage::Transfer(spend(
+                NodeId::from_bytes([3u8; 32]),
+                60,
+                1,
+            )))
+            .await
+            .unwrap();
+        // A sync taken before that spend restores the full 100
+        bridge.handle_credit_message(sync(100, 2)).await.unwrap();
+        assert_eq!(bridge.outstanding_outflow(&spender).await, Credits::new(60));
+
+        // 50 fits the known 100 but not the 40 left after outstanding outflow
+        bridge
+            .handle_credit_message(CreditMessage::Transfer(spend(
+                NodeId::from_bytes([4u8; 32]),
+                50,
+                2,
+            )))
+            .await
+            .unwrap();
+        let suspects = bridge.double_spend_suspects().await;
+        assert_eq!(suspects.len(), 1);
+        assert_eq!(suspects[0].transfer.amount, 50);
+        assert_eq!(suspects[0].known_balance, Credits::new(100));
+    }
+
+    #[tokio::test]
+    async fn test_double_spend_suspects_are_capped() {
+        let config = EnrBridgeConfig {
+            double_spend_suspect_capacity: 2,
+            ..Default::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let spender = NodeId::from_bytes([2u8; 32]);
+        bridge
+            .known_balances
+            .write()
+            .await
+            .insert(spender, Credits::new(1));
+
+        for nonce in 1..=3 {
+            let to = NodeId::from_bytes([3u8; 32]);
+            bridge
+                .check_double_spend(&CreditTransfer {
+                    id: TransferId::from_transfer(&spender, &to, 10, nonce),
+                    from: spender,
+                    to,
+                    amount: 10,
+                    nonce,
+                    timestamp: Timestamp::now(),
+                    memo: None,
+                    signature: Signature::empty(),
+                })
+                .await;
+        }
+
+        let suspects = bridge.double_spend_suspects().await;
+        let nonces: Vec<u64> = suspects.iter().map(|s| s.transfer.nonce).collect();
+        assert_eq!(nonces, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_retry_with_idempotency_key() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let count = attempts.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, _data| {
+            // The first broadcast fails
+            match count.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(BridgeError::Network("no peers".to_string())),
+                _ => Ok(()),
+            }
+        }));
+        bridge.set_balance(Credits::new(1000)).await;
+        let recipient = NodeId::from_bytes([2u8; 32]);
+
+        let first = bridge
+            .transfer_with_key(recipient, Credits::new(100), Some("order-7"))
+            .await;
+        assert!(matches!(first, Err(TransferError::Cancelled)));
+        let id = *bridge.pending_transfers.read().await.keys().next().unwrap();
+
+        // The retry re-broadcasts the same transfer without charging again
+        let retried = bridge
+            .transfer_with_key(recipient, Credits::new(100), Some("order-7"))
+            .await;
+        assert_eq!(retried.unwrap(), id);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(bridge.balance().await, Credits::new(900));
+        assert_eq!(bridge.pending_transfers.read().await.len(), 1);
+
+        let reused = bridge
+            .transfer_with_key(recipient, Credits::new(50), Some("order-7"))
+            .await;
+        assert!(matches!(reused, Err(TransferError::KeyReused)));
+
+        // Once settled, a retry returns the ID without broadcasting
+        bridge.pending_transfers.write().await.remove(&id);
+        let settled = bridge
+            .transfer_with_key(recipient, Credits::new(100), Some("order-7"))
+            .await;
+        assert_eq!(settled.unwrap(), id);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // The key still pins recipient and amount once the log entry is gone
+        bridge.transfer_log.write().await.remove(&id);
+        let reused = bridge
+            .transfer_with_key(recipient, Credits::new(50), Some("order-7"))
+            .await;
+        assert!(matches!(reused, Err(TransferError::KeyReused)));
+
+        // Keys expire with the transfer log
+        let later = Timestamp::new(Timestamp::now().millis + 25 * 3600 * 1000);
+        bridge.prune_transfer_log(later).await;
+        assert!(bridge.idempotency_keys.read().await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_credit_transfer_as_recipient() {
         let local_id = test_node_id();
@@ -1007,338 +3453,1513 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_handle_credit_state_sync() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
-
-        let other_node = NodeId::from_bytes([2u8; 32]);
+    async fn test_node_directory() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let failed = NodeId::from_bytes([3u8; 32]);
 
-        let sync = CreditStateSync {
-            node_id: other_node,
-            balance: 5000,
-            version: 1,
+        let gradient = EnrMessage::Gradient(GradientMessage {
+            node_id: peer,
+            gradient: GradientPayload::from(&ResourceGradient::default()),
             timestamp: Timestamp::now(),
             signature: Signature::empty(),
-        };
-
+        });
         bridge
-            .handle_credit_message(CreditMessage::StateSync(sync))
+            .handle_message(EnrTopics::GRADIENT, &gradient.to_bytes().unwrap())
+            .await
+            .unwrap();
+        bridge
+            .handle_credit_message(CreditMessage::BalanceResponse {
+                node_id: peer,
+                balance: 250,
+            })
             .await
             .unwrap();
+        bridge.record_failure(failed, "timeout").await;
 
-        let balances = bridge.known_balances.read().await;
-        assert_eq!(balances.get(&other_node), Some(&Credits::new(5000)));
-    }
+        let directory = bridge.node_directory().await;
+        assert_eq!(directory.len(), 2);
 
-    #[tokio::test]
-    async fn test_handle_balance_query() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let mut bridge = EnrBridge::new(local_id, config);
+        let peer_status = directory.iter().find(|s| s.node == peer).unwrap();
+        assert!(peer_status.last_seen.is_some());
+        assert_eq!(peer_status.known_balance, Some(Credits::new(250)));
+        assert!(peer_status.gradient.is_some());
+        assert_eq!(peer_status.gate_state, SeptalGateState::Open);
 
-        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
-        let published_clone = published.clone();
-        let publish_fn: PublishFn = Arc::new(move |topic, data| {
-            let mut msgs = published_clone.lock().unwrap();
-            msgs.push((topic.to_string(), data));
-            Ok(())
-        });
-        bridge.connect_publisher(publish_fn);
-        bridge.set_balance(Credits::new(1234)).await;
+        let failed_status = directory.iter().find(|s| s.node == failed).unwrap();
+        assert_eq!(failed_status.last_seen, None);
+        assert_eq!(failed_status.gradient, None);
+    }
 
-        let requester = NodeId::from_bytes([2u8; 32]);
+    #[tokio::test]
+    async fn test_network_capacity_excludes_isolated() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        assert_eq!(bridge.network_capacity().await, ResourceGradient::zero());
 
-        // Query for our balance
+        let a = NodeId::from_bytes([2u8; 32]);
+        let b = NodeId::from_bytes([3u8; 32]);
+        let bad = NodeId::from_bytes([4u8; 32]);
+        {
+            let mut gradients = bridge.gradients.write().await;
+            gradients.insert(
+                a,
+                ResourceGradient {
+                    cpu_available: 0.2,
+                    ..ResourceGradient::zero()
+                },
+            );
+            gradients.insert(
+                b,
+                ResourceGradient {
+                    cpu_available: 0.6,
+                    ..ResourceGradient::zero()
+                },
+            );
+            gradients.insert(
+                bad,
+                ResourceGradient {
+                    cpu_available: 1.0,
+                    gpu_available: 1.0,
+                    ..ResourceGradient::zero()
+                },
+            );
+        }
         bridge
-            .handle_credit_message(CreditMessage::BalanceQuery {
-                requester,
-                target: local_id,
-            })
+            .handle_septal_message(SeptalMessage::Isolation(IsolationNotice {
+                isolated_node: bad,
+                reason: "test".to_string(),
+                timestamp: Timestamp::now(),
+            }))
             .await
             .unwrap();
 
-        // Should have published a response
-        let msgs = published.lock().unwrap();
-        assert!(!msgs.is_empty());
-        assert_eq!(msgs[0].0, EnrTopics::CREDIT);
+        let capacity = bridge.network_capacity().await;
+        assert!((capacity.cpu_available - 0.4).abs() < 1e-9);
+        assert_eq!(capacity.gpu_available, 0.0);
     }
 
     #[tokio::test]
-    async fn test_handle_balance_response() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
-
-        let other_node = NodeId::from_bytes([2u8; 32]);
+    async fn test_gradient_trust_weights_capacity() {
+        // Every gradient lands in a fresh interval
+        let config = EnrBridgeConfig {
+            gradient_interval: Duration::ZERO,
+            ..EnrBridgeConfig::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let veteran = signed_gradient(2, 1.0).node_id;
+        let newcomer = signed_gradient(3, 0.0).node_id;
 
+        assert_eq!(bridge.trust_score(&veteran).await, 0.1);
+        for _ in 0..20 {
+            bridge
+                .handle_gradient_message(signed_gradient(2, 1.0))
+                .await
+                .unwrap();
+        }
         bridge
-            .handle_credit_message(CreditMessage::BalanceResponse {
-                node_id: other_node,
-                balance: 9999,
-            })
+            .handle_gradient_message(signed_gradient(3, 0.0))
             .await
             .unwrap();
+        assert_eq!(bridge.trust_score(&veteran).await, 1.0);
+        assert!(bridge.trust_score(&newcomer).await < 0.2);
 
-        let balances = bridge.known_balances.read().await;
-        assert_eq!(balances.get(&other_node), Some(&Credits::new(9999)));
-    }
+        // The established peer dominates the aggregate
+        assert!(bridge.network_capacity().await.cpu_available > 0.8);
 
-    // ========================================================================
-    // Septal Message Handler Tests
-    // ========================================================================
+        bridge.record_failure(veteran, "timeout").await;
+        assert_eq!(bridge.trust_score(&veteran).await, 0.75);
+    }
 
     #[tokio::test]
-    async fn test_handle_failure_report() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
-
-        let failing_node = NodeId::from_bytes([2u8; 32]);
-        let reporter = NodeId::from_bytes([3u8; 32]);
-
-        // Report 5 failures to trip the gate
-        for _ in 0..5 {
-            let report = FailureReport {
-                reporter,
-                failed_node: failing_node,
-                failure_type: "timeout".to_string(),
-                timestamp: Timestamp::now(),
-                signature: Signature::empty(),
-            };
+    async fn test_trust_gain_limited_to_signed_gradients_per_interval() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let flooder = signed_gradient(2, 1.0).node_id;
 
+        // A burst within one interval earns a single gain
+        for _ in 0..20 {
             bridge
-                .handle_septal_message(SeptalMessage::FailureReport(report))
+                .handle_gradient_message(signed_gradient(2, 1.0))
                 .await
                 .unwrap();
         }
+        assert!((bridge.trust_score(&flooder).await - 0.15).abs() < 1e-9);
 
-        // Node should be isolated
-        assert!(bridge.is_isolated(&failing_node).await);
+        // Unsigned or forged gradients earn nothing
+        let forger = NodeId::from_bytes([4u8; 32]);
+        let mut forged = signed_gradient(3, 1.0);
+        forged.node_id = forger;
+        let unsigned = GradientMessage {
+            signature: Signature::empty(),
+            ..signed_gradient(5, 1.0)
+        };
+        let unsigned_node = unsigned.node_id;
+        for msg in [forged, unsigned] {
+            bridge.handle_gradient_message(msg).await.unwrap();
+        }
+        assert_eq!(bridge.trust_score(&forger).await, 0.1);
+        assert_eq!(bridge.trust_score(&unsigned_node).await, 0.1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_quarantine_flaps_race_sweeps() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1_000_000)).await;
+        let bridge = Arc::new(bridge);
+        let peer = NodeId::from_bytes([2u8; 32]);
+
+        let flapping = bridge.clone();
+        let flaps = tokio::spawn(async move {
+            for _ in 0..300 {
+                flapping.quarantine_transfers(&peer).await;
+                flapping.release_quarantined(&peer).await;
+            }
+        });
+        let sweeping = bridge.clone();
+        let sweeps = tokio::spawn(async move {
+            let later = Timestamp::new(Timestamp::now().millis + 3_600_000);
+            for i in 1..=300 {
+                sweeping
+                    .transfer_batch(&[(peer, Credits::new(i))])
+                    .await
+                    .unwrap();
+                sweeping.refund_expired_batches(later).await;
+                sweeping.transfer_report(Timestamp::new(0)).await;
+            }
+        });
+
+        let both = async { (flaps.await, sweeps.await) };
+        let (flaps, sweeps) = tokio::time::timeout(Duration::from_secs(10), both)
+            .await
+            .expect("quarantine and sweep deadlocked");
+        flaps.unwrap();
+        sweeps.unwrap();
     }
 
     #[tokio::test]
-    async fn test_handle_isolation_notice() {
+    async fn test_isolation_quarantines_pending_transfers() {
         let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
+        let mut bridge = EnrBridge::new(local_id, EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
 
-        let bad_node = NodeId::from_bytes([2u8; 32]);
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let transfer_id = bridge.transfer(peer, Credits::new(100)).await.unwrap();
 
-        let notice = IsolationNotice {
-            isolated_node: bad_node,
-            reason: "Byzantine behavior detected".to_string(),
+        let isolate = |node| {
+            SeptalMessage::Isolation(IsolationNotice {
+                isolated_node: node,
+                reason: "test".to_string(),
+                timestamp: Timestamp::now(),
+            })
+        };
+        bridge.handle_septal_message(isolate(peer)).await.unwrap();
+        assert_eq!(bridge.quarantined_count().await, 1);
+
+        // Confirmations are held while quarantined
+        let confirmation = TransferConfirmation {
+            transfer_id,
+            confirmer: peer,
             timestamp: Timestamp::now(),
+            signature: Signature::empty(),
         };
+        bridge
+            .handle_credit_message(CreditMessage::Confirmation(confirmation))
+            .await
+            .unwrap();
+        assert_eq!(bridge.quarantined_count().await, 1);
 
+        // Recovery releases back to pending
         bridge
-            .handle_septal_message(SeptalMessage::Isolation(notice))
+            .handle_septal_message(SeptalMessage::Recovery(RecoveryNotice {
+                recovered_node: peer,
+                timestamp: Timestamp::now(),
+            }))
             .await
             .unwrap();
+        assert_eq!(bridge.quarantined_count().await, 0);
+        assert!(bridge
+            .pending_transfers
+            .read()
+            .await
+            .contains_key(&transfer_id));
 
-        // Node should be immediately isolated
-        assert!(bridge.is_isolated(&bad_node).await);
-        assert_eq!(bridge.gate_state(&bad_node).await, SeptalGateState::Closed);
+        // Permanent isolation refunds the reserved credits
+        bridge.handle_septal_message(isolate(peer)).await.unwrap();
+        let refund = bridge.refund_quarantined(&peer).await;
+        assert_eq!(refund, Credits::new(100));
+        assert_eq!(bridge.balance().await, Credits::new(1000));
+        assert_eq!(bridge.quarantined_count().await, 0);
     }
 
     #[tokio::test]
-    async fn test_handle_healing_probe_as_target() {
+    async fn test_handle_credit_state_sync() {
         let local_id = test_node_id();
         let config = EnrBridgeConfig::default();
-        let mut bridge = EnrBridge::new(local_id, config);
+        let bridge = EnrBridge::new(local_id, config);
 
-        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
-        let published_clone = published.clone();
-        let publish_fn: PublishFn = Arc::new(move |topic, data| {
-            let mut msgs = published_clone.lock().unwrap();
-            msgs.push((topic.to_string(), data));
-            Ok(())
-        });
-        bridge.connect_publisher(publish_fn);
+        let other_node = NodeId::from_bytes([2u8; 32]);
 
-        let initiator = NodeId::from_bytes([2u8; 32]);
-        let probe = HealingProbe {
-            probe_id: [42u8; 32],
-            initiator,
-            target: local_id, // We are the target
+        let sync = CreditStateSync {
+            node_id: other_node,
+            balance: 5000,
+            version: 1,
             timestamp: Timestamp::now(),
+            signature: Signature::empty(),
         };
 
         bridge
-            .handle_septal_message(SeptalMessage::HealingProbe(probe))
+            .handle_credit_message(CreditMessage::StateSync(sync))
             .await
             .unwrap();
 
-        // Should have published a response
-        let msgs = published.lock().unwrap();
-        assert!(!msgs.is_empty());
-        assert_eq!(msgs[0].0, EnrTopics::SEPTAL);
-
-        // Verify the response contains our node ID
-        let response = EnrMessage::from_bytes(&msgs[0].1).unwrap();
-        if let EnrMessage::Septal(SeptalMessage::HealingResponse(resp)) = response {
-            assert_eq!(resp.responder, local_id);
-            assert_eq!(resp.probe_id, [42u8; 32]);
-        } else {
-            panic!("Expected HealingResponse");
-        }
+        let balances = bridge.known_balances.read().await;
+        assert_eq!(balances.get(&other_node), Some(&Credits::new(5000)));
     }
 
     #[tokio::test]
-    async fn test_handle_healing_response_recovery() {
+    async fn test_announce_recoveries() {
         let local_id = test_node_id();
         let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
+        let mut bridge = EnrBridge::new(local_id, config);
 
-        let recovering_node = NodeId::from_bytes([2u8; 32]);
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = published.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
 
-        // First, put the node in half-open state
-        {
-            let mut gates = bridge.septal_gates.write().await;
-            let mut gate = SeptalGate::new(recovering_node);
-            gate.state = SeptalGateState::HalfOpen;
-            gates.insert(recovering_node, gate);
+        let node = NodeId::from_bytes([3u8; 32]);
+        for _ in 0..5 {
+            bridge.record_failure(node, "timeout").await;
         }
+        assert!(bridge.is_isolated(&node).await);
+        published.lock().unwrap().clear();
+
+        bridge.announce_recoveries(&[node]).await.unwrap();
+        assert!(!bridge.is_isolated(&node).await);
+
+        let sent = published.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(matches!(
+            EnrMessage::from_bytes(&sent[0]).unwrap(),
+            EnrMessage::Septal(SeptalMessage::Recovery(RecoveryNotice { recovered_node, .. }))
+                if recovered_node == node
+        ));
+    }
 
-        let response = HealingResponse {
-            probe_id: [42u8; 32],
-            responder: recovering_node,
-            state: SeptalGateState::Open,
-            timestamp: Timestamp::now(),
+    #[tokio::test]
+    async fn test_try_spend_entropy() {
+        let config = EnrBridgeConfig {
+            entropy_budget_capacity: 10.0,
+            ..Default::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let busy = NodeId::from_bytes([2u8; 32]);
+        let quiet = NodeId::from_bytes([3u8; 32]);
+        let heavy = EntropyAccount {
+            network: 8.0,
+            compute: 8.0,
+            storage: 8.0,
+            temporal: 8.0,
         };
 
-        bridge
-            .handle_septal_message(SeptalMessage::HealingResponse(response))
-            .await
-            .unwrap();
+        assert!(bridge.try_spend_entropy(busy, &heavy).await);
+        assert!(!bridge.try_spend_entropy(busy, &heavy).await);
+        // Budgets are per node
+        assert!(bridge.try_spend_entropy(quiet, &heavy).await);
+    }
 
-        // Node should be recovered (Open state)
-        assert!(!bridge.is_isolated(&recovering_node).await);
+    #[tokio::test]
+    async fn test_election_phase_timeout() {
+        let local_id = test_node_id();
+        let mut bridge = EnrBridge::new(local_id, EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+
+        let mut announcement = bridge.trigger_election().await.unwrap();
         assert_eq!(
-            bridge.gate_state(&recovering_node).await,
-            SeptalGateState::Open
+            bridge.election_phase(&announcement),
+            Some(ElectionPhase::Announcement)
         );
+
+        let timeout = bridge.config.election.estimated_duration();
+        announcement.timestamp = Timestamp::new(Timestamp::now().millis - timeout.millis);
+        assert_eq!(bridge.election_phase(&announcement), None);
     }
 
     #[tokio::test]
-    async fn test_handle_recovery_notice() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
-
-        let recovered_node = NodeId::from_bytes([2u8; 32]);
+    async fn test_poll_election_rounds() {
+        let zero = crate::core::Duration::new(0);
+        let config = EnrBridgeConfig {
+            election: ElectionConfig {
+                announcement_phase: zero,
+                candidacy_phase: zero,
+                voting_phase: zero,
+                max_rounds: 2,
+            },
+            ..EnrBridgeConfig::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = published.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
 
-        // First isolate the node
-        for _ in 0..5 {
-            bridge.record_failure(recovered_node, "test").await;
-        }
-        assert!(bridge.is_isolated(&recovered_node).await);
+        assert_eq!(bridge.poll_election().await.unwrap(), None);
+        let announcement = bridge.trigger_election().await.unwrap();
 
-        // Send recovery notice
-        let notice = RecoveryNotice {
-            recovered_node,
-            timestamp: Timestamp::now(),
+        // Round 1 times out immediately: re-announced as round 2
+        assert_eq!(
+            bridge.poll_election().await.unwrap(),
+            Some(RoundStatus::Advanced(2))
+        );
+        let reannounced = EnrMessage::from_bytes(&published.lock().unwrap()[1]).unwrap();
+        let EnrMessage::Election(ElectionMessage::Announcement(reannounced)) = reannounced else {
+            panic!("expected announcement");
         };
+        assert_eq!(reannounced.election_id, announcement.election_id);
+        assert_eq!(reannounced.round, 2);
+
+        assert_eq!(
+            bridge.poll_election().await.unwrap(),
+            Some(RoundStatus::GaveUp)
+        );
+        assert_eq!(bridge.poll_election().await.unwrap(), None);
 
+        // A result for the active election concludes it
+        let announcement = bridge.trigger_election().await.unwrap();
+        let receipts = vec![signed_vote(3, announcement.election_id, test_node_id()).receipt(1.0)];
+        let result =
+            ElectionResult::from_receipts(announcement.election_id, receipts, Timestamp::now())
+                .unwrap();
         bridge
-            .handle_septal_message(SeptalMessage::Recovery(notice))
+            .handle_election_message(ElectionMessage::Result(result))
             .await
             .unwrap();
-
-        // Node should be recovered
-        assert!(!bridge.is_isolated(&recovered_node).await);
+        assert_eq!(
+            bridge.poll_election().await.unwrap(),
+            Some(RoundStatus::Concluded)
+        );
     }
 
-    // ========================================================================
-    // Election Message Handler Tests
-    // ========================================================================
-
     #[tokio::test]
-    async fn test_handle_election_result() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
+    async fn test_poll_election_finalizes_collected_votes() {
+        let zero = crate::core::Duration::new(0);
+        let config = EnrBridgeConfig {
+            election: ElectionConfig {
+                announcement_phase: zero,
+                candidacy_phase: zero,
+                voting_phase: zero,
+                max_rounds: 2,
+            },
+            ..EnrBridgeConfig::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = published.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
 
         let winner = NodeId::from_bytes([2u8; 32]);
-
-        // Add winner to topology first
-        {
-            let mut topology = bridge.topology.write().await;
-            topology.set_topology(
-                winner,
-                crate::nexus::NexusTopology {
-                    node: winner,
-                    role: crate::nexus::NexusRole::default(),
-                    aggregated_gradient: ResourceGradient::default(),
-                    leaf_count: 0,
-                    last_election: Timestamp::now(),
-                },
-            );
-        }
-
-        let result = ElectionResult {
-            election_id: [1u8; 32],
+        bridge.topology.write().await.set_topology(
             winner,
-            vote_count: 10,
-            timestamp: Timestamp::now(),
-        };
+            crate::nexus::NexusTopology {
+                node: winner,
+                role: crate::nexus::NexusRole::default(),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            },
+        );
 
+        let announcement = bridge.trigger_election().await.unwrap();
+        for (voter, candidate) in [(3u8, winner), (4, winner), (5, test_node_id())] {
+            bridge
+                .handle_election_message(ElectionMessage::Vote(signed_vote(
+                    voter,
+                    announcement.election_id,
+                    candidate,
+                )))
+                .await
+                .unwrap();
+        }
+        // Votes for another election are ignored
         bridge
-            .handle_election_message(ElectionMessage::Result(result))
+            .handle_election_message(ElectionMessage::Vote(signed_vote(
+                6,
+                [9u8; 32],
+                test_node_id(),
+            )))
             .await
             .unwrap();
 
-        // Winner should be promoted to Nexus role
+        // The round timed out with votes in hand: tally instead of re-announcing
+        assert_eq!(
+            bridge.poll_election().await.unwrap(),
+            Some(RoundStatus::Concluded)
+        );
+        assert_eq!(bridge.poll_election().await.unwrap(), None);
+
+        let message = EnrMessage::from_bytes(&published.lock().unwrap()[1]).unwrap();
+        let EnrMessage::Election(ElectionMessage::Result(result)) = message else {
+            panic!("expected result");
+        };
+        assert_eq!(result.election_id, announcement.election_id);
+        assert_eq!(result.winner, winner);
+        assert_eq!(result.vote_count, 3);
+        assert!(result.verify());
+
         let topology = bridge.topology.read().await;
-        let topo = topology.get_topology(&winner).unwrap();
-        assert_eq!(topo.role.role_type, crate::nexus::NexusRoleType::Nexus);
+        assert!(topology.get_role(&winner).is_nexus());
     }
 
     #[tokio::test]
-    async fn test_trigger_election() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let mut bridge = EnrBridge::new(local_id, config);
-
-        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
-        let published_clone = published.clone();
-        let publish_fn: PublishFn = Arc::new(move |topic, data| {
-            let mut msgs = published_clone.lock().unwrap();
-            msgs.push((topic.to_string(), data));
-            Ok(())
-        });
-        bridge.connect_publisher(publish_fn);
-
+    async fn test_reject_unsigned_election_vote() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_t, _d| Ok(())));
         let announcement = bridge.trigger_election().await.unwrap();
 
-        // Verify announcement details
-        assert_eq!(announcement.initiator, local_id);
-        assert_eq!(announcement.round, 1);
+        let mut forged = signed_vote(3, announcement.election_id, test_node_id());
+        forged.voter = NodeId::from_bytes([4u8; 32]);
+        let mut unsigned = signed_vote(5, announcement.election_id, test_node_id());
+        unsigned.signature = Signature::empty();
+        for vote in [forged, unsigned] {
+            let err = bridge
+                .handle_election_message(ElectionMessage::Vote(vote))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, BridgeError::InvalidMessage(_)));
+        }
 
-        // Verify published message
-        let msgs = published.lock().unwrap();
-        assert!(!msgs.is_empty());
-        assert_eq!(msgs[0].0, EnrTopics::ELECTION);
+        let active = bridge.active_election.read().await;
+        assert!(active.as_ref().unwrap().receipts.is_empty());
     }
 
-    // ========================================================================
-    // Message Roundtrip Tests
-    // ========================================================================
+    #[tokio::test(start_paused = true)]
+    async fn test_maintenance_drives_election_rounds() {
+        let zero = crate::core::Duration::new(0);
+        let config = EnrBridgeConfig {
+            election: ElectionConfig {
+                announcement_phase: zero,
+                candidacy_phase: zero,
+                voting_phase: zero,
+                max_rounds: 2,
+            },
+            maintenance_interval: Duration::from_millis(10),
+            ..EnrBridgeConfig::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        let publish_count = Arc::new(AtomicUsize::new(0));
+        let count = publish_count.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, _data| {
+            count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }));
+
+        bridge.trigger_election().await.unwrap();
+        bridge.start_maintenance().await.unwrap();
+        for _ in 0..3 {
+            tokio::time::advance(Duration::from_millis(10)).await;
+            tokio::task::yield_now().await;
+        }
+
+        // Re-announced as round 2, then abandoned, with no caller polling
+        assert_eq!(publish_count.load(Ordering::SeqCst), 2);
+        assert!(bridge.active_election.read().await.is_none());
+        bridge.shutdown().await;
+    }
 
     #[tokio::test]
-    async fn test_gradient_message_roundtrip() {
+    async fn test_event_log_replay() {
         let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let mut bridge = EnrBridge::new(local_id, config);
-
-        let received = Arc::new(std::sync::Mutex::new(Vec::<Vec<u8>>::new()));
-        let received_clone = received.clone();
-        let publish_fn: PublishFn = Arc::new(move |_topic, data| {
-            let mut msgs = received_clone.lock().unwrap();
-            msgs.push(data);
-            Ok(())
-        });
-        bridge.connect_publisher(publish_fn);
+        let mut bridge = EnrBridge::new(local_id, EnrBridgeConfig::default());
+        let log = Arc::new(MemoryEventLog::new());
+        bridge.set_event_sink(log.clone());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+
+        let sender = NodeId::from_bytes([2u8; 32]);
+        let transfer = EnrMessage::Credit(CreditMessage::Transfer(CreditTransfer {
+            id: TransferId::from_transfer(&sender, &local_id, 250, 1),
+            from: sender,
+            to: local_id,
+            amount: 250,
+            nonce: 1,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        }));
+        let gradient = EnrMessage::Gradient(GradientMessage {
+            node_id: sender,
+            gradient: GradientPayload::from(&ResourceGradient {
+                cpu_available: 0.4,
+                ..Default::default()
+            }),
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        });
+        for message in [&transfer, &gradient] {
+            bridge
+                .handle_message(message.topic(), &message.to_bytes().unwrap())
+                .await
+                .unwrap();
+        }
+
+        // Two inbound messages plus the outbound transfer confirmation
+        let entries = log.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|e| e.direction == LogDirection::Outbound)
+                .count(),
+            1
+        );
+
+        let replayed = EnrBridge::replay(local_id, EnrBridgeConfig::default(), &entries).await;
+        assert_eq!(replayed.balance().await, bridge.balance().await);
+        assert_eq!(replayed.balance().await, Credits::new(250));
+        let gradients = replayed.gradients.read().await;
+        assert!((gradients[&sender].cpu_available - 0.4).abs() < 0.001);
+    }
+
+    #[tokio::test]
+    async fn test_topic_stats() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let mut bridge = EnrBridge::new(local_id, config);
+        assert!(bridge.topic_stats().is_empty());
+
+        let publish_fn: PublishFn = Arc::new(|topic, _data| {
+            if topic == EnrTopics::SEPTAL {
+                Err(BridgeError::Network("unreachable".into()))
+            } else {
+                Ok(())
+            }
+        });
+        bridge.connect_publisher(publish_fn);
+
+        bridge.set_balance(Credits::new(1000)).await;
+        bridge
+            .transfer(NodeId::from_bytes([2u8; 32]), Credits::new(10))
+            .await
+            .unwrap();
+        bridge
+            .record_failure(NodeId::from_bytes([3u8; 32]), "timeout")
+            .await;
+
+        let sync = EnrMessage::Credit(CreditMessage::StateSync(CreditStateSync {
+            node_id: NodeId::from_bytes([4u8; 32]),
+            balance: 10,
+            version: 1,
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        }));
+        bridge
+            .handle_message(EnrTopics::CREDIT, &sync.to_bytes().unwrap())
+            .await
+            .unwrap();
+        assert!(bridge
+            .handle_message(EnrTopics::GRADIENT, b"garbage")
+            .await
+            .is_err());
+
+        let stats = bridge.topic_stats();
+        let credit = stats[&TopicType::Credit];
+        assert_eq!((credit.sent, credit.received, credit.dropped), (1, 1, 0));
+        assert_eq!(stats[&TopicType::Septal].dropped, 1);
+        assert_eq!(stats[&TopicType::Gradient].dropped, 1);
+        // Only decoded messages are timed
+        assert_eq!(credit.handling.count(), 1);
+        assert!(credit.handling.p99() >= credit.handling.min());
+        assert_eq!(stats[&TopicType::Gradient].handling.count(), 0);
+        assert!(!stats.contains_key(&TopicType::Election));
+    }
+
+    #[tokio::test]
+    async fn test_handle_stale_state_sync_ignored() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let other_node = NodeId::from_bytes([2u8; 32]);
+        for (version, balance) in [(5, 5000), (3, 3000)] {
+            let sync = CreditStateSync {
+                node_id: other_node,
+                balance,
+                version,
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            };
+            bridge
+                .handle_credit_message(CreditMessage::StateSync(sync))
+                .await
+                .unwrap();
+        }
+
+        let balances = bridge.known_balances.read().await;
+        assert_eq!(balances.get(&other_node), Some(&Credits::new(5000)));
+    }
+
+    #[tokio::test]
+    async fn test_known_balance_with_age() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let node = NodeId::from_bytes([2u8; 32]);
+        assert_eq!(bridge.known_balance_with_age(&node).await, None);
+
+        bridge
+            .handle_credit_message(CreditMessage::BalanceResponse {
+                node_id: node,
+                balance: 500,
+            })
+            .await
+            .unwrap();
+        let (balance, age) = bridge.known_balance_with_age(&node).await.unwrap();
+        assert_eq!(balance, Credits::new(500));
+        assert!(age < Duration::from_secs(5));
+
+        // Backdate the update to simulate a stale entry
+        bridge
+            .balance_updated_at
+            .write()
+            .await
+            .insert(node, Timestamp::new(Timestamp::now().millis - 3_600_000));
+        let (_, age) = bridge.known_balance_with_age(&node).await.unwrap();
+        assert!(age >= Duration::from_secs(3_600));
+    }
+
+    #[tokio::test]
+    async fn test_merge_known_balances() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let node_a = NodeId::from_bytes([2u8; 32]);
+        let node_b = NodeId::from_bytes([3u8; 32]);
+        let sync = CreditStateSync {
+            node_id: node_a,
+            balance: 100,
+            version: 2,
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        };
+        bridge
+            .handle_credit_message(CreditMessage::StateSync(sync))
+            .await
+            .unwrap();
+
+        let other_balances =
+            HashMap::from([(node_a, Credits::new(40)), (node_b, Credits::new(70))]);
+        let other_versions = HashMap::from([(node_a, 1), (node_b, 4)]);
+        bridge
+            .merge_known_balances(&other_balances, &other_versions)
+            .await;
+
+        let balances = bridge.known_balances.read().await;
+        assert_eq!(balances.get(&node_a), Some(&Credits::new(100)));
+        assert_eq!(balances.get(&node_b), Some(&Credits::new(70)));
+        let versions = bridge.balance_versions.read().await;
+        assert_eq!(versions.get(&node_b), Some(&4));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_merge_races_state_sync() {
+        let bridge = Arc::new(EnrBridge::new(test_node_id(), EnrBridgeConfig::default()));
+        let node = NodeId::from_bytes([2u8; 32]);
+
+        let syncing = bridge.clone();
+        let syncs = tokio::spawn(async move {
+            for version in 1..=200 {
+                let sync = CreditStateSync {
+                    node_id: node,
+                    balance: version,
+                    version,
+                    timestamp: Timestamp::now(),
+                    signature: Signature::empty(),
+                };
+                syncing
+                    .handle_credit_message(CreditMessage::StateSync(sync))
+                    .await
+                    .unwrap();
+            }
+        });
+        let merging = bridge.clone();
+        let merges = tokio::spawn(async move {
+            let other_balances = HashMap::from([(node, Credits::new(0))]);
+            let other_versions = HashMap::from([(node, 0)]);
+            for _ in 0..200 {
+                merging
+                    .merge_known_balances(&other_balances, &other_versions)
+                    .await;
+            }
+        });
+
+        let both = async { (syncs.await, merges.await) };
+        let (syncs, merges) = tokio::time::timeout(Duration::from_secs(10), both)
+            .await
+            .expect("sync and merge deadlocked");
+        syncs.unwrap();
+        merges.unwrap();
+        assert_eq!(
+            bridge.known_balances.read().await.get(&node),
+            Some(&Credits::new(200))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_balance_query() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
+        let published_clone = published.clone();
+        let publish_fn: PublishFn = Arc::new(move |topic, data| {
+            let mut msgs = published_clone.lock().unwrap();
+            msgs.push((topic.to_string(), data));
+            Ok(())
+        });
+        bridge.connect_publisher(publish_fn);
+        bridge.set_balance(Credits::new(1234)).await;
+
+        let requester = NodeId::from_bytes([2u8; 32]);
+
+        // Query for our balance
+        bridge
+            .handle_credit_message(CreditMessage::BalanceQuery {
+                requester,
+                target: local_id,
+            })
+            .await
+            .unwrap();
+
+        // Should have published a response
+        let msgs = published.lock().unwrap();
+        assert!(!msgs.is_empty());
+        assert_eq!(msgs[0].0, EnrTopics::CREDIT);
+    }
+
+    #[tokio::test]
+    async fn test_partition_settlement() {
+        fn capturing_bridge(id: NodeId) -> (EnrBridge, Arc<std::sync::Mutex<Vec<Vec<u8>>>>) {
+            let mut bridge = EnrBridge::new(id, EnrBridgeConfig::default());
+            let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let sink = published.clone();
+            bridge.connect_publisher(Arc::new(move |_topic, data| {
+                sink.lock().unwrap().push(data);
+                Ok(())
+            }));
+            (bridge, published)
+        }
+        fn transfer(from: u8, to: NodeId, amount: u64) -> CreditTransfer {
+            let key = SigningKey::from_bytes(&[from; 32]);
+            let from = NodeId::from_bytes(key.verifying_key().to_bytes());
+            let mut transfer = CreditTransfer {
+                id: TransferId::from_transfer(&from, &to, amount, 1),
+                from,
+                to,
+                amount,
+                nonce: 1,
+                timestamp: Timestamp::now(),
+                memo: None,
+                signature: Signature::empty(),
+            };
+            transfer.sign(&key);
+            transfer
+        }
+
+        let counterparty = NodeId::from_bytes([9u8; 32]);
+        let (a, a_sent) = capturing_bridge(NodeId::from_bytes([1u8; 32]));
+        let (b, b_sent) = capturing_bridge(NodeId::from_bytes([2u8; 32]));
+
+        // Each side of the partition saw a different transfer to the counterparty
+        a.handle_credit_message(CreditMessage::Transfer(transfer(3, counterparty, 100)))
+            .await
+            .unwrap();
+        b.handle_credit_message(CreditMessage::Transfer(transfer(4, counterparty, 50)))
+            .await
+            .unwrap();
+
+        a.request_settlement(counterparty).await.unwrap();
+        let request = EnrMessage::from_bytes(&a_sent.lock().unwrap()[0]).unwrap();
+        let EnrMessage::Credit(request) = request else {
+            panic!("expected credit message");
+        };
+        b.handle_credit_message(request).await.unwrap();
+
+        let response = EnrMessage::from_bytes(&b_sent.lock().unwrap()[0]).unwrap();
+        let EnrMessage::Credit(CreditMessage::SettlementResponse(response)) = response else {
+            panic!("expected settlement response");
+        };
+        assert_eq!(response.missing.len(), 1);
+        assert_eq!(response.canonical_balance, 150);
+        a.handle_credit_message(CreditMessage::SettlementResponse(response))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            a.known_balances.read().await.get(&counterparty).copied(),
+            Some(Credits::new(150))
+        );
+        assert_eq!(
+            b.known_balances.read().await.get(&counterparty).copied(),
+            Some(Credits::new(150))
+        );
+
+        // Re-delivering a settled transfer is a no-op
+        a.handle_credit_message(CreditMessage::Transfer(transfer(4, counterparty, 50)))
+            .await
+            .unwrap();
+        assert_eq!(
+            a.known_balances.read().await.get(&counterparty).copied(),
+            Some(Credits::new(150))
+        );
+
+        // Tampered transfers in a settlement are not applied
+        let mut forged = transfer(5, b.local_id, 500);
+        forged.amount = 5_000;
+        let mut resigned = transfer(6, b.local_id, 500);
+        resigned.signature = transfer(7, b.local_id, 500).signature;
+        b.handle_credit_message(CreditMessage::SettlementRequest(SettlementRequest {
+            requester: a.local_id,
+            counterparty: b.local_id,
+            transfers: vec![forged, resigned],
+            since: Timestamp::new(0),
+            until: None,
+            timestamp: Timestamp::now(),
+        }))
+        .await
+        .unwrap();
+        assert_eq!(b.balance().await, Credits::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_settlement_with_default_config() {
+        // No signing keys anywhere, so every transfer is unsigned
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = published.clone();
+        let mut a = EnrBridge::new(NodeId::from_bytes([1u8; 32]), EnrBridgeConfig::default());
+        a.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
+        a.set_balance(Credits::new(100)).await;
+        let mut b = EnrBridge::new(NodeId::from_bytes([2u8; 32]), EnrBridgeConfig::default());
+        b.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+
+        // b was partitioned away when a paid the counterparty
+        let counterparty = NodeId::from_bytes([9u8; 32]);
+        a.transfer(counterparty, Credits::new(40)).await.unwrap();
+        published.lock().unwrap().clear();
+
+        a.request_settlement(counterparty).await.unwrap();
+        let request = EnrMessage::from_bytes(&published.lock().unwrap()[0]).unwrap();
+        let EnrMessage::Credit(request) = request else {
+            panic!("expected credit message");
+        };
+        b.handle_credit_message(request).await.unwrap();
+
+        assert_eq!(b.transfer_log.read().await.len(), 1);
+        assert_eq!(
+            b.known_balances.read().await.get(&counterparty).copied(),
+            Some(Credits::new(40))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_large_settlement_is_chunked() {
+        let published = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = published.clone();
+        let mut a = EnrBridge::new(NodeId::from_bytes([1u8; 32]), EnrBridgeConfig::default());
+        a.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
+
+        let counterparty = NodeId::from_bytes([9u8; 32]);
+        let key = SigningKey::from_bytes(&[3u8; 32]);
+        let sender = NodeId::from_bytes(key.verifying_key().to_bytes());
+        let start = Timestamp::now().millis;
+        for nonce in 0..60u64 {
+            let mut transfer = CreditTransfer {
+                id: TransferId::from_transfer(&sender, &counterparty, 1, nonce),
+                from: sender,
+                to: counterparty,
+                amount: 1,
+                nonce,
+                timestamp: Timestamp::new(start + nonce),
+                memo: Some("x".repeat(2048)),
+                signature: Signature::empty(),
+            };
+            transfer.sign(&key);
+            a.accept_inbound_transfer(&transfer).await;
+        }
+
+        a.request_settlement(counterparty).await.unwrap();
+        let requests: Vec<SettlementRequest> = published
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|data| {
+                assert!(data.len() as u64 <= MAX_MESSAGE_SIZE);
+                match EnrMessage::from_bytes(data).unwrap() {
+                    EnrMessage::Credit(CreditMessage::SettlementRequest(r)) => r,
+                    other => panic!("unexpected {other:?}"),
+                }
+            })
+            .collect();
+        assert!(requests.len() > 1);
+        assert_eq!(
+            requests.iter().map(|r| r.transfers.len()).sum::<usize>(),
+            60
+        );
+        // Consecutive ranges with no gaps
+        assert_eq!(requests[0].since, Timestamp::new(0));
+        for pair in requests.windows(2) {
+            assert_eq!(pair[0].until, Some(pair[1].since));
+        }
+        assert_eq!(requests.last().unwrap().until, None);
+
+        // A peer that saw none of them applies every chunk
+        let responses = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = responses.clone();
+        let mut b = EnrBridge::new(NodeId::from_bytes([2u8; 32]), EnrBridgeConfig::default());
+        b.connect_publisher(Arc::new(move |_topic, data| {
+            sink.lock().unwrap().push(data);
+            Ok(())
+        }));
+        for request in &requests {
+            b.handle_credit_message(CreditMessage::SettlementRequest(request.clone()))
+                .await
+                .unwrap();
+        }
+        assert_eq!(b.transfer_log.read().await.len(), 60);
+
+        // Asked with an empty log, b's reply doesn't fit in one message
+        let empty = SettlementRequest {
+            requester: NodeId::from_bytes([5u8; 32]),
+            counterparty,
+            transfers: Vec::new(),
+            since: Timestamp::new(0),
+            until: None,
+            timestamp: Timestamp::now(),
+        };
+        responses.lock().unwrap().clear();
+        b.handle_credit_message(CreditMessage::SettlementRequest(empty))
+            .await
+            .unwrap();
+        let missing: usize = responses
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|data| {
+                assert!(data.len() as u64 <= MAX_MESSAGE_SIZE);
+                match EnrMessage::from_bytes(data).unwrap() {
+                    EnrMessage::Credit(CreditMessage::SettlementResponse(r)) => r.missing.len(),
+                    other => panic!("unexpected {other:?}"),
+                }
+            })
+            .sum();
+        assert!(responses.lock().unwrap().len() > 1);
+        assert_eq!(missing, 60);
+    }
+
+    #[tokio::test]
+    async fn test_settlement_responses_merge_by_version() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let counterparty = NodeId::from_bytes([9u8; 32]);
+        let response = |responder: u8, balance, version| SettlementResponse {
+            responder: NodeId::from_bytes([responder; 32]),
+            requester: bridge.local_id,
+            counterparty,
+            missing: Vec::new(),
+            canonical_balance: balance,
+            balance_version: version,
+            timestamp: Timestamp::now(),
+        };
+
+        // A stale reply arriving last doesn't overwrite a newer one
+        bridge
+            .handle_credit_message(CreditMessage::SettlementResponse(response(9, 300, 5)))
+            .await
+            .unwrap();
+        bridge
+            .handle_credit_message(CreditMessage::SettlementResponse(response(9, 100, 2)))
+            .await
+            .unwrap();
+        // Only the counterparty speaks for its balance, whatever the version
+        bridge
+            .handle_credit_message(CreditMessage::SettlementResponse(response(3, 1, 99)))
+            .await
+            .unwrap();
+        assert_eq!(
+            bridge
+                .known_balances
+                .read()
+                .await
+                .get(&counterparty)
+                .copied(),
+            Some(Credits::new(300))
+        );
+        assert_eq!(
+            bridge.balance_versions.read().await.get(&counterparty),
+            Some(&5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prune_transfer_log() {
+        let config = EnrBridgeConfig {
+            transfer_log_retention: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        bridge.connect_publisher(Arc::new(|_t, _d| Ok(())));
+        bridge.set_balance(Credits::new(100)).await;
+
+        let sender = NodeId::from_bytes([2u8; 32]);
+        let old = CreditTransfer {
+            id: TransferId::from_transfer(&sender, &bridge.local_id, 10, 1),
+            from: sender,
+            to: bridge.local_id,
+            amount: 10,
+            nonce: 1,
+            timestamp: Timestamp::new(1_000),
+            memo: None,
+            signature: Signature::empty(),
+        };
+        bridge.accept_inbound_transfer(&old).await;
+        // Still pending, so kept however old it is
+        let pending = bridge
+            .transfer(NodeId::from_bytes([3u8; 32]), Credits::new(5))
+            .await
+            .unwrap();
+        bridge
+            .transfer_log
+            .write()
+            .await
+            .get_mut(&pending)
+            .unwrap()
+            .timestamp = Timestamp::new(1_000);
+
+        let now = Timestamp::new(1_000 + 120_000);
+        assert_eq!(bridge.prune_transfer_log(now).await, 1);
+        assert!(bridge.transfer_log.read().await.contains_key(&pending));
+
+        // A replay of the pruned transfer is rejected rather than re-applied
+        let balance = bridge.balance().await;
+        assert!(!bridge.accept_inbound_transfer(&old).await);
+        assert_eq!(bridge.balance().await, balance);
+    }
+
+    #[tokio::test]
+    async fn test_handle_balance_response() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let other_node = NodeId::from_bytes([2u8; 32]);
+
+        bridge
+            .handle_credit_message(CreditMessage::BalanceResponse {
+                node_id: other_node,
+                balance: 9999,
+            })
+            .await
+            .unwrap();
+
+        let balances = bridge.known_balances.read().await;
+        assert_eq!(balances.get(&other_node), Some(&Credits::new(9999)));
+    }
+
+    // ========================================================================
+    // Septal Message Handler Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_handle_failure_report() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let failing_node = NodeId::from_bytes([2u8; 32]);
+        let reporter = NodeId::from_bytes([3u8; 32]);
+
+        // Report 5 failures to trip the gate
+        for _ in 0..5 {
+            let report = FailureReport {
+                reporter,
+                failed_node: failing_node,
+                failure_type: "timeout".to_string(),
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            };
+
+            bridge
+                .handle_septal_message(SeptalMessage::FailureReport(report))
+                .await
+                .unwrap();
+        }
+
+        // Node should be isolated
+        assert!(bridge.is_isolated(&failing_node).await);
+    }
+
+    #[tokio::test]
+    async fn test_handle_isolation_notice() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let bad_node = NodeId::from_bytes([2u8; 32]);
+
+        let notice = IsolationNotice {
+            isolated_node: bad_node,
+            reason: "Byzantine behavior detected".to_string(),
+            timestamp: Timestamp::now(),
+        };
+
+        bridge
+            .handle_septal_message(SeptalMessage::Isolation(notice))
+            .await
+            .unwrap();
+
+        // Node should be immediately isolated
+        assert!(bridge.is_isolated(&bad_node).await);
+        assert_eq!(bridge.gate_state(&bad_node).await, SeptalGateState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_handle_healing_probe_as_target() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
+        let published_clone = published.clone();
+        let publish_fn: PublishFn = Arc::new(move |topic, data| {
+            let mut msgs = published_clone.lock().unwrap();
+            msgs.push((topic.to_string(), data));
+            Ok(())
+        });
+        bridge.connect_publisher(publish_fn);
+
+        let initiator = NodeId::from_bytes([2u8; 32]);
+        let probe = HealingProbe {
+            probe_id: [42u8; 32],
+            initiator,
+            target: local_id, // We are the target
+            timestamp: Timestamp::now(),
+        };
+
+        bridge
+            .handle_septal_message(SeptalMessage::HealingProbe(probe))
+            .await
+            .unwrap();
+
+        // Should have published a response
+        let msgs = published.lock().unwrap();
+        assert!(!msgs.is_empty());
+        assert_eq!(msgs[0].0, EnrTopics::SEPTAL);
+
+        // Verify the response contains our node ID
+        let response = EnrMessage::from_bytes(&msgs[0].1).unwrap();
+        if let EnrMessage::Septal(SeptalMessage::HealingResponse(resp)) = response {
+            assert_eq!(resp.responder, local_id);
+            assert_eq!(resp.probe_id, [42u8; 32]);
+        } else {
+            panic!("Expected HealingResponse");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_healing_response_recovery() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let recovering_node = NodeId::from_bytes([2u8; 32]);
+
+        // First, put the node in half-open state
+        {
+            let mut gates = bridge.septal_gates.write().await;
+            let mut gate = SeptalGate::new(recovering_node);
+            gate.state = SeptalGateState::HalfOpen;
+            gates.insert(recovering_node, gate);
+        }
+
+        let response = HealingResponse {
+            probe_id: [42u8; 32],
+            responder: recovering_node,
+            state: SeptalGateState::Open,
+            timestamp: Timestamp::now(),
+        };
+
+        bridge
+            .handle_septal_message(SeptalMessage::HealingResponse(response))
+            .await
+            .unwrap();
+
+        // Node should be recovered (Open state)
+        assert!(!bridge.is_isolated(&recovering_node).await);
+        assert_eq!(
+            bridge.gate_state(&recovering_node).await,
+            SeptalGateState::Open
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_recovery_notice() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let recovered_node = NodeId::from_bytes([2u8; 32]);
+
+        // First isolate the node
+        for _ in 0..5 {
+            bridge.record_failure(recovered_node, "test").await;
+        }
+        assert!(bridge.is_isolated(&recovered_node).await);
+
+        // Send recovery notice
+        let notice = RecoveryNotice {
+            recovered_node,
+            timestamp: Timestamp::now(),
+        };
+
+        bridge
+            .handle_septal_message(SeptalMessage::Recovery(notice))
+            .await
+            .unwrap();
+
+        // Node should be recovered
+        assert!(!bridge.is_isolated(&recovered_node).await);
+    }
+
+    // ========================================================================
+    // Election Message Handler Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_handle_election_result() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let winner = NodeId::from_bytes([2u8; 32]);
+
+        // Add winner to topology first
+        {
+            let mut topology = bridge.topology.write().await;
+            topology.set_topology(
+                winner,
+                crate::nexus::NexusTopology {
+                    node: winner,
+                    role: crate::nexus::NexusRole::default(),
+                    aggregated_gradient: ResourceGradient::default(),
+                    leaf_count: 0,
+                    last_election: Timestamp::now(),
+                },
+            );
+        }
+
+        let receipts = (3u8..13)
+            .map(|voter| signed_vote(voter, [1u8; 32], winner).receipt(1.0))
+            .collect();
+        let result = ElectionResult::from_receipts([1u8; 32], receipts, Timestamp::now()).unwrap();
+
+        bridge
+            .handle_election_message(ElectionMessage::Result(result))
+            .await
+            .unwrap();
+
+        // Winner should be promoted to Nexus role
+        let topology = bridge.topology.read().await;
+        let topo = topology.get_topology(&winner).unwrap();
+        assert_eq!(topo.role.role_type, crate::nexus::NexusRoleType::Nexus);
+    }
+
+    #[tokio::test]
+    async fn test_election_result_hands_off_nexus() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let old = NodeId::from_bytes([1u8; 32]);
+        let winner = NodeId::from_bytes([2u8; 32]);
+        let leaf = NodeId::from_bytes([3u8; 32]);
+        {
+            let topo = |node, role| crate::nexus::NexusTopology {
+                node,
+                role,
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            };
+            let mut topology = bridge.topology.write().await;
+            topology.set_topology(
+                old,
+                topo(
+                    old,
+                    crate::nexus::NexusRole::nexus(None, vec![winner, leaf]),
+                ),
+            );
+            topology.set_topology(winner, topo(winner, crate::nexus::NexusRole::leaf(old)));
+            topology.set_topology(leaf, topo(leaf, crate::nexus::NexusRole::leaf(old)));
+        }
+
+        let receipts = vec![signed_vote(3, [1u8; 32], winner).receipt(1.0)];
+        let result = ElectionResult::from_receipts([1u8; 32], receipts, Timestamp::now()).unwrap();
+        let data = EnrMessage::Election(ElectionMessage::Result(result))
+            .to_bytes()
+            .unwrap();
+        bridge
+            .handle_message(EnrTopics::ELECTION, &data)
+            .await
+            .unwrap();
+
+        // The winner takes over its old nexus, which is demoted under it
+        let topology = bridge.topology.read().await;
+        let winner_role = topology.get_role(&winner);
+        assert!(winner_role.is_nexus());
+        assert!(winner_role.children.contains(&old));
+        assert!(winner_role.children.contains(&leaf));
+        assert_eq!(
+            topology.get_role(&old),
+            crate::nexus::NexusRole::leaf(winner)
+        );
+        assert_eq!(topology.get_role(&leaf).parent, Some(winner));
+        assert_eq!(topology.get_nexuses(), vec![winner]);
+    }
+
+    #[tokio::test]
+    async fn test_reject_forged_election_result() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let honest = NodeId::from_bytes([2u8; 32]);
+        let usurper = NodeId::from_bytes([3u8; 32]);
+        for node in [honest, usurper] {
+            bridge.topology.write().await.set_topology(
+                node,
+                crate::nexus::NexusTopology {
+                    node,
+                    role: crate::nexus::NexusRole::default(),
+                    aggregated_gradient: ResourceGradient::default(),
+                    leaf_count: 0,
+                    last_election: Timestamp::now(),
+                },
+            );
+        }
+
+        // Claims a winner the receipts don't support
+        let receipts = vec![signed_vote(4, [1u8; 32], honest).receipt(1.0)];
+        let mut forged =
+            ElectionResult::from_receipts([1u8; 32], receipts, Timestamp::now()).unwrap();
+        forged.winner = usurper;
+        let err = bridge
+            .handle_election_message(ElectionMessage::Result(forged))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BridgeError::InvalidMessage(_)));
+
+        // No receipts at all: nothing to verify the winner against
+        let unaudited = ElectionResult {
+            election_id: [1u8; 32],
+            winner: usurper,
+            vote_count: 10,
+            timestamp: Timestamp::now(),
+            receipts: Vec::new(),
+        };
+        let err = bridge
+            .handle_election_message(ElectionMessage::Result(unaudited))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BridgeError::InvalidMessage(_)));
+
+        // Internally consistent, but the voters never signed these votes
+        let fabricated = (4u8..10)
+            .map(|voter| VoteReceipt {
+                voter: NodeId::from_bytes([voter; 32]),
+                candidate: usurper,
+                weight: 1.0,
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            })
+            .collect();
+        let fabricated =
+            ElectionResult::from_receipts([1u8; 32], fabricated, Timestamp::now()).unwrap();
+        let err = bridge
+            .handle_election_message(ElectionMessage::Result(fabricated))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BridgeError::InvalidMessage(_)));
+
+        // Signed by a key other than the named voter's
+        let mut impersonated = signed_vote(4, [1u8; 32], usurper);
+        impersonated.voter = NodeId::from_bytes([5u8; 32]);
+        let impersonated = ElectionResult::from_receipts(
+            [1u8; 32],
+            vec![impersonated.receipt(1.0)],
+            Timestamp::now(),
+        )
+        .unwrap();
+        let err = bridge
+            .handle_election_message(ElectionMessage::Result(impersonated))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BridgeError::InvalidMessage(_)));
+
+        let topology = bridge.topology.read().await;
+        assert!(!topology.get_role(&usurper).is_nexus());
+        assert!(!topology.get_role(&honest).is_nexus());
+    }
+
+    #[tokio::test]
+    async fn test_trigger_election() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let published = Arc::new(std::sync::Mutex::new(Vec::<(String, Vec<u8>)>::new()));
+        let published_clone = published.clone();
+        let publish_fn: PublishFn = Arc::new(move |topic, data| {
+            let mut msgs = published_clone.lock().unwrap();
+            msgs.push((topic.to_string(), data));
+            Ok(())
+        });
+        bridge.connect_publisher(publish_fn);
+
+        let announcement = bridge.trigger_election().await.unwrap();
+
+        // Verify announcement details
+        assert_eq!(announcement.initiator, local_id);
+        assert_eq!(announcement.round, 1);
+
+        // Verify published message
+        let msgs = published.lock().unwrap();
+        assert!(!msgs.is_empty());
+        assert_eq!(msgs[0].0, EnrTopics::ELECTION);
+    }
+
+    // ========================================================================
+    // Message Roundtrip Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_gradient_message_roundtrip() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let received = Arc::new(std::sync::Mutex::new(Vec::<Vec<u8>>::new()));
+        let received_clone = received.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, data| {
+            let mut msgs = received_clone.lock().unwrap();
+            msgs.push(data);
+            Ok(())
+        });
+        bridge.connect_publisher(publish_fn);
 
         // Update gradient (publishes message)
         let gradient = ResourceGradient {
@@ -1346,151 +4967,1063 @@ mod tests {
             memory_available: 0.84,
             ..Default::default()
         };
-        bridge.update_gradient(gradient).await.unwrap();
+        bridge.update_gradient(gradient).await.unwrap();
+
+        // Get published data
+        let msgs = received.lock().unwrap();
+        assert!(!msgs.is_empty());
+
+        // Deserialize and handle as incoming message
+        let msg = EnrMessage::from_bytes(&msgs[0]).unwrap();
+        if let EnrMessage::Gradient(g) = msg {
+            assert_eq!(g.node_id, local_id);
+            assert!((g.gradient.cpu_available - 0.42).abs() < 0.001);
+        } else {
+            panic!("Expected GradientMessage");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_credit_transfer_roundtrip() {
+        let local_id = test_node_id();
+        let recipient_id = NodeId::from_bytes([2u8; 32]);
+        let config = EnrBridgeConfig::default();
+
+        // Create sender bridge
+        let mut sender = EnrBridge::new(local_id, config.clone());
+        sender.set_balance(Credits::new(1000)).await;
+
+        let received = Arc::new(std::sync::Mutex::new(Vec::<Vec<u8>>::new()));
+        let received_clone = received.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, data| {
+            let mut msgs = received_clone.lock().unwrap();
+            msgs.push(data);
+            Ok(())
+        });
+        sender.connect_publisher(publish_fn);
+
+        // Create recipient bridge
+        let mut recipient = EnrBridge::new(recipient_id, config);
+        let publish_fn2: PublishFn = Arc::new(|_topic, _data| Ok(()));
+        recipient.connect_publisher(publish_fn2);
+
+        // Sender initiates transfer
+        let _transfer_id = sender
+            .transfer(recipient_id, Credits::new(250))
+            .await
+            .unwrap();
+
+        // Verify sender balance deducted
+        assert_eq!(sender.balance().await, Credits::new(750));
+
+        // Simulate network delivery to recipient
+        let msgs = received.lock().unwrap();
+        assert!(!msgs.is_empty());
+
+        let msg = EnrMessage::from_bytes(&msgs[0]).unwrap();
+        if let EnrMessage::Credit(credit_msg) = msg {
+            recipient.handle_credit_message(credit_msg).await.unwrap();
+        } else {
+            panic!("Expected CreditMessage");
+        }
+
+        // Verify recipient balance credited
+        assert_eq!(recipient.balance().await, Credits::new(250));
+    }
+
+    // ========================================================================
+    // Message Expiration Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_expired_gradient_message_rejected() {
+        let local_id = test_node_id();
+        let mut config = EnrBridgeConfig::default();
+        config.max_message_age = Duration::from_secs(60);
+        let bridge = EnrBridge::new(local_id, config);
+
+        let sender = NodeId::from_bytes([2u8; 32]);
+
+        // Create a message with old timestamp (2 minutes ago)
+        let old_timestamp = Timestamp::new(Timestamp::now().millis - 120_000);
+
+        let gradient = GradientMessage {
+            node_id: sender,
+            gradient: GradientPayload::from(&ResourceGradient::default()),
+            timestamp: old_timestamp,
+            signature: Signature::empty(),
+        };
+
+        let msg = EnrMessage::Gradient(gradient);
+        let data = msg.to_bytes().unwrap();
+
+        // Should reject expired message
+        let result = bridge.handle_message(EnrTopics::GRADIENT, &data).await;
+        assert!(matches!(result, Err(BridgeError::MessageExpired)));
+    }
+
+    #[tokio::test]
+    async fn test_fresh_gradient_message_accepted() {
+        let local_id = test_node_id();
+        let mut config = EnrBridgeConfig::default();
+        config.max_message_age = Duration::from_secs(60);
+        let bridge = EnrBridge::new(local_id, config);
+
+        let sender = NodeId::from_bytes([2u8; 32]);
+
+        let gradient = GradientMessage {
+            node_id: sender,
+            gradient: GradientPayload {
+                cpu_available: 0.5,
+                ..Default::default()
+            },
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        };
+
+        let msg = EnrMessage::Gradient(gradient);
+        let data = msg.to_bytes().unwrap();
+
+        // Should accept fresh message
+        let result = bridge.handle_message(EnrTopics::GRADIENT, &data).await;
+        assert!(result.is_ok());
+
+        // Verify gradient was stored
+        let gradients = bridge.gradients.read().await;
+        assert!(gradients.contains_key(&sender));
+    }
+
+    // ========================================================================
+    // Edge Case Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_publish_without_connection() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig::default();
+        let bridge = EnrBridge::new(local_id, config);
+
+        let msg = EnrMessage::Gradient(GradientMessage {
+            node_id: local_id,
+            gradient: GradientPayload::from(&ResourceGradient::default()),
+            timestamp: Timestamp::now(),
+            signature: Signature::empty(),
+        });
+
+        let result = bridge.publish(msg);
+        assert!(matches!(result, Err(BridgeError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn test_publish_retry_on_transient_error() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let count = attempts.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, _data| {
+            // Fail twice with backpressure, then succeed
+            if count.fetch_add(1, Ordering::SeqCst) < 2 {
+                Err(BridgeError::Network("queue full".to_string()))
+            } else {
+                Ok(())
+            }
+        });
+
+        let result = publish_with_retry(
+            &publish_fn,
+            EnrTopics::GRADIENT,
+            vec![1, 2, 3],
+            3,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_publish_retry_gives_up() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let count = attempts.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, _data| {
+            count.fetch_add(1, Ordering::SeqCst);
+            Err(BridgeError::Network("queue full".to_string()))
+        });
+
+        let result =
+            publish_with_retry(&publish_fn, EnrTopics::GRADIENT, vec![], 2, Duration::ZERO).await;
+        assert!(matches!(result, Err(BridgeError::Network(_))));
+        // Initial attempt plus two retries
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_publish_no_retry_on_fatal_error() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let count = attempts.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, _data| {
+            count.fetch_add(1, Ordering::SeqCst);
+            Err(BridgeError::Serialization("bad payload".to_string()))
+        });
+
+        let result =
+            publish_with_retry(&publish_fn, EnrTopics::GRADIENT, vec![], 3, Duration::ZERO).await;
+        assert!(matches!(result, Err(BridgeError::Serialization(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_loop_counts_dropped() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig {
+            gradient_interval: Duration::from_millis(5),
+            publish_retries: 0,
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let publish_fn: PublishFn =
+            Arc::new(|_topic, _data| Err(BridgeError::Network("no peers".to_string())));
+        bridge.connect_publisher(publish_fn);
+
+        bridge.start_gradient_broadcast().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        bridge.stop();
+
+        assert!(bridge.dropped_broadcasts() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_loops() {
+        let config = EnrBridgeConfig {
+            gradient_interval: Duration::from_millis(5),
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+
+        let publish_count = Arc::new(AtomicUsize::new(0));
+        let count = publish_count.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, _data| {
+            count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }));
+        bridge.start_dispatch_workers();
+        let handle = bridge.start_gradient_broadcast().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        bridge.shutdown().await;
+        assert!(handle.is_finished());
+        assert!(!bridge.is_broadcasting());
+        let stopped_at = publish_count.load(Ordering::SeqCst);
+        assert!(stopped_at > 0);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(publish_count.load(Ordering::SeqCst), stopped_at);
+
+        // A stopped bridge can start broadcasting again
+        let handle = bridge.start_gradient_broadcast().await.unwrap();
+        assert!(bridge.is_broadcasting());
+        bridge.shutdown().await;
+        assert!(handle.is_finished());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pause_and_resume_broadcasts() {
+        let local_id = test_node_id();
+        let config = EnrBridgeConfig {
+            gradient_interval: Duration::from_millis(5),
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(local_id, config);
+
+        let publish_count = Arc::new(AtomicUsize::new(0));
+        let count = publish_count.clone();
+        let publish_fn: PublishFn = Arc::new(move |_topic, _data| {
+            count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+        bridge.connect_publisher(publish_fn);
+        assert!(!bridge.is_broadcasting());
+
+        // Advance the paused clock one interval and let the loop run
+        async fn tick() {
+            tokio::time::advance(Duration::from_millis(5)).await;
+            tokio::task::yield_now().await;
+        }
+
+        bridge.start_gradient_broadcast().await.unwrap();
+        assert!(bridge.is_broadcasting());
+        // The first tick fires immediately
+        tokio::task::yield_now().await;
+        assert_eq!(publish_count.load(Ordering::SeqCst), 1);
+        tick().await;
+        assert_eq!(publish_count.load(Ordering::SeqCst), 2);
+
+        bridge.pause_broadcasts();
+        assert!(!bridge.is_broadcasting());
+        for _ in 0..3 {
+            tick().await;
+        }
+        assert_eq!(publish_count.load(Ordering::SeqCst), 2);
+
+        bridge.resume_broadcasts();
+        assert!(bridge.is_broadcasting());
+        for _ in 0..2 {
+            tick().await;
+        }
+        assert_eq!(publish_count.load(Ordering::SeqCst), 4);
+
+        bridge.stop();
+        assert!(!bridge.is_broadcasting());
+    }
+
+    #[tokio::test]
+    async fn test_batch_partial_confirmation_refunds_unconfirmed() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
+
+        let recipients: Vec<NodeId> = (2..=4).map(|i| NodeId::from_bytes([i as u8; 32])).collect();
+        let legs: Vec<(NodeId, Credits)> = recipients
+            .iter()
+            .map(|node| (*node, Credits::new(100)))
+            .collect();
+        let batch_id = bridge.transfer_batch(&legs).await.unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(700));
+
+        let status = bridge.batch_status(batch_id).await.unwrap();
+        assert_eq!(status.pending.len(), 3);
+
+        // Two of three recipients confirm
+        for id in &status.pending[..2] {
+            bridge
+                .handle_credit_message(CreditMessage::Confirmation(TransferConfirmation {
+                    transfer_id: *id,
+                    confirmer: recipients[0],
+                    timestamp: Timestamp::now(),
+                    signature: Signature::empty(),
+                }))
+                .await
+                .unwrap();
+        }
+
+        // Not yet timed out
+        assert!(bridge
+            .refund_expired_batches(Timestamp::now())
+            .await
+            .is_zero());
+
+        let later = Timestamp::new(Timestamp::now().millis + 120_000);
+        assert_eq!(
+            bridge.refund_expired_batches(later).await,
+            Credits::new(100)
+        );
+        assert_eq!(bridge.balance().await, Credits::new(800));
+
+        let status = bridge.batch_status(batch_id).await.unwrap();
+        assert_eq!(status.confirmed.len(), 2);
+        assert_eq!(status.failed.len(), 1);
+        assert!(status.is_settled());
+        assert_eq!(bridge.pending_transfers.read().await.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_best_path_uses_topology() {
+        use crate::nexus::{GossipPathType, NexusRole, NexusTopology};
+
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let local = test_node_id();
+        let target = NodeId::from_bytes([2u8; 32]);
+        let hub = NodeId::from_bytes([3u8; 32]);
+        let poteau = NodeId::from_bytes([4u8; 32]);
+
+        // Unknown to the topology: a leaf with no nexus
+        assert!(bridge.best_path(target, 100.0).await.is_none());
+        assert!(bridge.best_path(local, 100.0).await.is_none());
+
+        let set_role = |role: NexusRole| NexusTopology {
+            node: local,
+            role,
+            aggregated_gradient: ResourceGradient::default(),
+            leaf_count: 0,
+            last_election: Timestamp::now(),
+        };
+        let topology = bridge.topology.clone();
+        topology
+            .write()
+            .await
+            .set_topology(local, set_role(NexusRole::leaf(hub)));
+        let path = bridge.best_path(target, 100.0).await.unwrap();
+        assert_eq!(path.path_type, GossipPathType::ViaHub);
+        assert_eq!(path.hops, vec![hub, target]);
+
+        // A nexus goes direct within budget
+        topology
+            .write()
+            .await
+            .set_topology(local, set_role(NexusRole::nexus(None, vec![])));
+        let idle = ResourceGradient {
+            cpu_available: 1.0,
+            memory_available: 1.0,
+            gpu_available: 1.0,
+            storage_available: 1.0,
+            bandwidth_available: 1.0,
+            credit_balance: 0.0,
+        };
+        bridge.update_gradient(idle).await.unwrap();
+        bridge.gradients.write().await.insert(target, idle);
+        let direct = bridge.best_path(target, 1.0).await.unwrap();
+        assert_eq!(direct.path_type, GossipPathType::Direct);
+        assert!((direct.estimated_entropy - 0.5).abs() < 1e-9);
+
+        // Over budget with no super-hub known
+        assert!(bridge.best_path(target, 0.1).await.is_none());
+
+        topology.write().await.set_topology(
+            poteau,
+            NexusTopology {
+                node: poteau,
+                role: NexusRole::poteau_mitan(vec![local]),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            },
+        );
+        let via = bridge.best_path(target, 0.1).await.unwrap();
+        assert_eq!(via.path_type, GossipPathType::ViaSuperHub);
+        assert_eq!(via.hops, vec![poteau, target]);
+    }
+
+    #[tokio::test]
+    async fn test_route_enforces_entropy_budget() {
+        use crate::nexus::{NexusRole, NexusTopology};
+
+        let config = EnrBridgeConfig {
+            entropy_budget_capacity: 10.0,
+            entropy_budget_leak_per_sec: 0.0,
+            ..Default::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let requester = NodeId::from_bytes([2u8; 32]);
+        let target = NodeId::from_bytes([3u8; 32]);
+        let heavy = EntropyAccount {
+            network: 8.0,
+            compute: 8.0,
+            storage: 8.0,
+            temporal: 8.0,
+        };
+
+        // No topology yet: no route, and the budget isn't touched
+        assert!(matches!(
+            bridge.route(requester, target, &heavy, 100.0).await,
+            Err(BridgeError::NoRoute(node)) if node == target
+        ));
+
+        bridge.topology.write().await.set_topology(
+            test_node_id(),
+            NexusTopology {
+                node: test_node_id(),
+                role: NexusRole::nexus(None, vec![]),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            },
+        );
+        assert!(bridge.route(requester, target, &heavy, 100.0).await.is_ok());
+        assert!(matches!(
+            bridge.route(requester, target, &heavy, 100.0).await,
+            Err(BridgeError::EntropyBudgetExhausted(node)) if node == requester
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_report() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
+        let start = Timestamp::now();
+
+        let a = NodeId::from_bytes([2u8; 32]);
+        let b = NodeId::from_bytes([3u8; 32]);
+        let batch_id = bridge
+            .transfer_batch(&[(a, Credits::new(100)), (b, Credits::new(50))])
+            .await
+            .unwrap();
+        let open = bridge.transfer(b, Credits::new(25)).await.unwrap();
+
+        let legs = bridge.batch_status(batch_id).await.unwrap().pending;
+        bridge
+            .handle_credit_message(CreditMessage::Confirmation(TransferConfirmation {
+                transfer_id: legs[0],
+                confirmer: a,
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            }))
+            .await
+            .unwrap();
+        let later = Timestamp::new(Timestamp::now().millis + 120_000);
+        bridge.refund_expired_batches(later).await;
+
+        let report = bridge.transfer_report(start).await;
+        assert_eq!(report.confirmed.len(), 1);
+        assert_eq!(report.confirmed[0].id, legs[0]);
+        assert_eq!(report.refunded.len(), 1);
+        assert_eq!(report.refunded[0].id, legs[1]);
+        assert_eq!(report.pending.len(), 1);
+        assert_eq!(report.pending[0].id, open);
+        assert_eq!(report.net_positions[&test_node_id()], -100);
+        assert_eq!(report.net_positions[&a], 100);
+        assert!(!report.net_positions.contains_key(&b));
+
+        // Only the refund happened after the confirmation
+        let report = bridge.transfer_report(later).await;
+        assert!(report.confirmed.is_empty());
+        assert_eq!(report.refunded.len(), 1);
+        assert_eq!(report.pending.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_burn_policy_preserves_conservation() {
+        let config = EnrBridgeConfig {
+            transfer_timeout_policy: TimeoutPolicy::Burn,
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
+        bridge
+            .set_conservation(CreditConservation::new(Credits::new(1000)))
+            .await;
+
+        let peer = NodeId::from_bytes([2u8; 32]);
+        bridge
+            .transfer_batch(&[(peer, Credits::new(100))])
+            .await
+            .unwrap();
+
+        let later = Timestamp::new(Timestamp::now().millis + 120_000);
+        assert_eq!(
+            bridge.refund_expired_batches(later).await,
+            Credits::new(100)
+        );
+        // Not refunded: the credits left circulation
+        assert_eq!(bridge.balance().await, Credits::new(900));
+        let conservation = bridge.conservation().await;
+        assert_eq!(conservation.total_burned, Credits::new(100));
+        assert!(conservation.check(bridge.balance().await));
+
+        let report = bridge.transfer_report(Timestamp::new(0)).await;
+        assert_eq!(report.burned.len(), 1);
+        assert!(report.refunded.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_expiry_skips_refunded_quarantined_legs() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
+
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let other = NodeId::from_bytes([3u8; 32]);
+        let batch_id = bridge
+            .transfer_batch(&[(peer, Credits::new(100)), (other, Credits::new(50))])
+            .await
+            .unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(850));
+
+        bridge
+            .handle_septal_message(SeptalMessage::Isolation(IsolationNotice {
+                isolated_node: peer,
+                reason: "test".to_string(),
+                timestamp: Timestamp::now(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(bridge.refund_quarantined(&peer).await, Credits::new(100));
+        assert_eq!(bridge.balance().await, Credits::new(950));
+
+        // Only the leg still pending is refunded on expiry
+        let later = Timestamp::new(Timestamp::now().millis + 120_000);
+        assert_eq!(bridge.refund_expired_batches(later).await, Credits::new(50));
+        assert_eq!(bridge.balance().await, Credits::new(1000));
+        assert!(bridge.batch_status(batch_id).await.unwrap().is_settled());
+
+        // Settled batches are dropped once their legs leave the log
+        let much_later = Timestamp::new(later.millis + 24 * 3600 * 1000);
+        assert_eq!(bridge.prune_batches(much_later).await, 1);
+        assert!(bridge.batch_status(batch_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_batch_publish_failure_still_returns_batch() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        bridge.connect_publisher(Arc::new(move |_topic, _data| {
+            if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(BridgeError::NotConnected)
+            } else {
+                Ok(())
+            }
+        }));
+        bridge.set_balance(Credits::new(1000)).await;
+
+        let legs: Vec<(NodeId, Credits)> = (2..=4)
+            .map(|i| (NodeId::from_bytes([i as u8; 32]), Credits::new(100)))
+            .collect();
+        let batch_id = bridge.transfer_batch(&legs).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(bridge.dropped_broadcasts(), 1);
+        let status = bridge.batch_status(batch_id).await.unwrap();
+        assert_eq!(status.pending.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_netted_batch_offsets_opposing_flows() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
+
+        let local = test_node_id();
+        let b = NodeId::from_bytes([2u8; 32]);
+        let c = NodeId::from_bytes([3u8; 32]);
+        let batch_id = bridge
+            .transfer_netted_batch(&[
+                (local, b, Credits::new(50)),
+                (b, local, Credits::new(30)),
+                (c, local, Credits::new(40)),
+                (local, c, Credits::new(10)),
+            ])
+            .await
+            .unwrap();
+
+        // A→B nets to 20; C owes us 30 net, so nothing goes to C
+        assert_eq!(bridge.balance().await, Credits::new(980));
+        let status = bridge.batch_status(batch_id).await.unwrap();
+        assert_eq!(status.pending.len(), 1);
+        let pending = bridge.pending_transfers.read().await;
+        let sent: Vec<(NodeId, u64)> = pending.values().map(|t| (t.to, t.amount)).collect();
+        assert_eq!(sent, vec![(b, 20)]);
+        drop(pending);
+
+        let stranger = NodeId::from_bytes([4u8; 32]);
+        let result = bridge
+            .transfer_netted_batch(&[(b, stranger, Credits::new(5))])
+            .await;
+        assert!(matches!(result, Err(TransferError::NotParticipant)));
+    }
+
+    #[tokio::test]
+    async fn test_batch_netting_merges_legs() {
+        let config = EnrBridgeConfig {
+            net_batch_transfers: true,
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
 
-        // Get published data
-        let msgs = received.lock().unwrap();
-        assert!(!msgs.is_empty());
+        let b = NodeId::from_bytes([2u8; 32]);
+        let c = NodeId::from_bytes([3u8; 32]);
+        let batch_id = bridge
+            .transfer_batch(&[
+                (b, Credits::new(50)),
+                (c, Credits::new(10)),
+                (b, Credits::new(30)),
+            ])
+            .await
+            .unwrap();
 
-        // Deserialize and handle as incoming message
-        let msg = EnrMessage::from_bytes(&msgs[0]).unwrap();
-        if let EnrMessage::Gradient(g) = msg {
-            assert_eq!(g.node_id, local_id);
-            assert!((g.gradient.cpu_available - 0.42).abs() < 0.001);
-        } else {
-            panic!("Expected GradientMessage");
-        }
+        assert_eq!(bridge.balance().await, Credits::new(910));
+        let status = bridge.batch_status(batch_id).await.unwrap();
+        assert_eq!(status.pending.len(), 2);
+        let pending = bridge.pending_transfers.read().await;
+        let to_b: Vec<u64> = pending
+            .values()
+            .filter(|t| t.to == b)
+            .map(|t| t.amount)
+            .collect();
+        assert_eq!(to_b, vec![80]);
     }
 
     #[tokio::test]
-    async fn test_credit_transfer_roundtrip() {
-        let local_id = test_node_id();
-        let recipient_id = NodeId::from_bytes([2u8; 32]);
-        let config = EnrBridgeConfig::default();
+    async fn test_back_to_back_batches_get_distinct_ids() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(1000)).await;
 
-        // Create sender bridge
-        let mut sender = EnrBridge::new(local_id, config.clone());
-        sender.set_balance(Credits::new(1000)).await;
+        // Identical batches issued within the same millisecond
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let c = NodeId::from_bytes([3u8; 32]);
+        let legs = [(peer, Credits::new(10)), (c, Credits::new(20))];
+        let first = bridge.transfer_batch(&legs).await.unwrap();
+        let second = bridge.transfer_batch(&legs).await.unwrap();
+
+        assert_eq!(bridge.balance().await, Credits::new(940));
+        assert_eq!(bridge.pending_transfers.read().await.len(), 4);
+        let first = bridge.batch_status(first).await.unwrap();
+        let second = bridge.batch_status(second).await.unwrap();
+        assert!(first.pending.iter().all(|id| !second.pending.contains(id)));
+    }
 
-        let received = Arc::new(std::sync::Mutex::new(Vec::<Vec<u8>>::new()));
-        let received_clone = received.clone();
-        let publish_fn: PublishFn = Arc::new(move |_topic, data| {
-            let mut msgs = received_clone.lock().unwrap();
-            msgs.push(data);
-            Ok(())
-        });
-        sender.connect_publisher(publish_fn);
+    #[tokio::test]
+    async fn test_batch_rejects_overdraft() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(150)).await;
 
-        // Create recipient bridge
-        let mut recipient = EnrBridge::new(recipient_id, config);
-        let publish_fn2: PublishFn = Arc::new(|_topic, _data| Ok(()));
-        recipient.connect_publisher(publish_fn2);
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let result = bridge
+            .transfer_batch(&[(peer, Credits::new(100)), (peer, Credits::new(100))])
+            .await;
+        assert!(matches!(result, Err(TransferError::InsufficientBalance)));
+        assert_eq!(bridge.balance().await, Credits::new(150));
+    }
 
-        // Sender initiates transfer
-        let _transfer_id = sender
-            .transfer(recipient_id, Credits::new(250))
+    #[tokio::test]
+    async fn test_reserve_for_resource() {
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(10_000)).await;
+        let pricer = Pricer::new();
+        let provider = NodeId::from_bytes([2u8; 32]);
+
+        // 10 GB of storage at 100/GB, calm network, plus 10% margin
+        let id = bridge
+            .reserve_for_resource(provider, ResourceType::Storage, 10, &pricer)
             .await
             .unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(8_900));
 
-        // Verify sender balance deducted
-        assert_eq!(sender.balance().await, Credits::new(750));
-
-        // Simulate network delivery to recipient
-        let msgs = received.lock().unwrap();
-        assert!(!msgs.is_empty());
+        // Settling below the reservation refunds the difference
+        bridge
+            .settle_reservation(id, Credits::new(900))
+            .await
+            .unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(9_100));
+        assert!(matches!(
+            bridge.settle_reservation(id, Credits::new(900)).await,
+            Err(TransferError::Reservation(_))
+        ));
 
-        let msg = EnrMessage::from_bytes(&msgs[0]).unwrap();
-        if let EnrMessage::Credit(credit_msg) = msg {
-            recipient.handle_credit_message(credit_msg).await.unwrap();
-        } else {
-            panic!("Expected CreditMessage");
-        }
+        // Entropy raises the quote
+        bridge
+            .set_local_entropy(EntropyAccount {
+                network: 10.0,
+                compute: 10.0,
+                storage: 10.0,
+                temporal: 10.0,
+            })
+            .await;
+        assert!(matches!(
+            bridge
+                .reserve_for_resource(provider, ResourceType::Storage, 20, &pricer)
+                .await,
+            Err(TransferError::InsufficientBalance)
+        ));
 
-        // Verify recipient balance credited
-        assert_eq!(recipient.balance().await, Credits::new(250));
+        bridge
+            .reserve_for_resource(provider, ResourceType::Storage, 1, &pricer)
+            .await
+            .unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(8_550));
+        let later = Timestamp::new(Timestamp::now().millis + 600_000);
+        assert_eq!(
+            bridge.release_expired_reservations(later).await,
+            Credits::new(550)
+        );
+        assert_eq!(bridge.balance().await, Credits::new(9_100));
     }
 
-    // ========================================================================
-    // Message Expiration Tests
-    // ========================================================================
+    #[tokio::test]
+    async fn test_transfer_via_ledger() {
+        use crate::core::MemoryLedger;
+
+        let published = Arc::new(AtomicU64::new(0));
+        let counter = published.clone();
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(move |_topic, _data| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }));
+
+        bridge.set_balance(Credits::new(500)).await;
+
+        let peer = NodeId::from_bytes([2u8; 32]);
+        let mut ledger = MemoryLedger::new();
+        ledger.credit(&test_node_id(), Credits::new(500)).unwrap();
+
+        bridge
+            .transfer_via_ledger(&mut ledger, peer, Credits::new(200))
+            .await
+            .unwrap();
+        assert_eq!(ledger.balance(&peer), Credits::new(200));
+        assert_eq!(ledger.total_supply(), Credits::new(500));
+        assert_eq!(bridge.balance().await, Credits::new(300));
+        assert_eq!(published.load(Ordering::SeqCst), 1);
+        // Settled by the ledger, so nothing awaits confirmation
+        assert!(bridge.pending_transfers.read().await.is_empty());
+
+        // Rejected by the ledger: nothing moves and nothing is broadcast
+        let result = bridge
+            .transfer_via_ledger(&mut ledger, peer, Credits::new(301))
+            .await;
+        assert!(matches!(result, Err(TransferError::InsufficientBalance)));
+        assert_eq!(ledger.balance(&test_node_id()), Credits::new(300));
+        assert_eq!(published.load(Ordering::SeqCst), 1);
+    }
 
     #[tokio::test]
-    async fn test_expired_gradient_message_rejected() {
-        let local_id = test_node_id();
-        let mut config = EnrBridgeConfig::default();
-        config.max_message_age = Duration::from_secs(60);
-        let bridge = EnrBridge::new(local_id, config);
+    async fn test_transfer_via_ledger_keeps_pending_reservations() {
+        use crate::core::MemoryLedger;
 
-        let sender = NodeId::from_bytes([2u8; 32]);
+        let mut bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.connect_publisher(Arc::new(|_topic, _data| Ok(())));
+        bridge.set_balance(Credits::new(500)).await;
 
-        // Create a message with old timestamp (2 minutes ago)
-        let old_timestamp = Timestamp::new(Timestamp::now().millis - 120_000);
+        // A gossip transfer reserves 100 the ledger doesn't know about
+        let peer = NodeId::from_bytes([2u8; 32]);
+        bridge.transfer(peer, Credits::new(100)).await.unwrap();
+        assert_eq!(bridge.balance().await, Credits::new(400));
 
-        let gradient = GradientMessage {
-            node_id: sender,
+        let mut ledger = MemoryLedger::new();
+        ledger.credit(&test_node_id(), Credits::new(500)).unwrap();
+        bridge
+            .transfer_via_ledger(&mut ledger, peer, Credits::new(200))
+            .await
+            .unwrap();
+
+        assert_eq!(ledger.balance(&test_node_id()), Credits::new(300));
+        assert_eq!(bridge.balance().await, Credits::new(200));
+        assert_eq!(bridge.pending_transfers.read().await.len(), 1);
+    }
+
+    fn gradient_bytes(node: u8) -> Vec<u8> {
+        EnrMessage::Gradient(GradientMessage {
+            node_id: NodeId::from_bytes([node; 32]),
             gradient: GradientPayload::from(&ResourceGradient::default()),
-            timestamp: old_timestamp,
+            timestamp: Timestamp::now(),
             signature: Signature::empty(),
+        })
+        .to_bytes()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_queue_drops_oldest() {
+        let config = EnrBridgeConfig {
+            dispatch_capacity: 2,
+            dispatch_workers: 1,
+            ..Default::default()
         };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        bridge.start_dispatch_workers();
 
-        let msg = EnrMessage::Gradient(gradient);
-        let data = msg.to_bytes().unwrap();
+        // Workers can't run until this task yields, so the third evicts the first
+        for node in 2..=4 {
+            bridge
+                .enqueue_message(EnrTopics::GRADIENT, gradient_bytes(node))
+                .await
+                .unwrap();
+        }
+        assert_eq!(bridge.queued_messages(), 2);
 
-        // Should reject expired message
-        let result = bridge.handle_message(EnrTopics::GRADIENT, &data).await;
-        assert!(matches!(result, Err(BridgeError::MessageExpired)));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(bridge.queued_messages(), 0);
+        let gradients = bridge.gradients.read().await;
+        assert!(!gradients.contains_key(&NodeId::from_bytes([2u8; 32])));
+        assert!(gradients.contains_key(&NodeId::from_bytes([3u8; 32])));
+        assert!(gradients.contains_key(&NodeId::from_bytes([4u8; 32])));
+
+        let stats = bridge.topic_stats()[&TopicType::Gradient];
+        assert_eq!(stats.received, 2);
+        assert_eq!(stats.dropped, 1);
     }
 
-    #[tokio::test]
-    async fn test_fresh_gradient_message_accepted() {
-        let local_id = test_node_id();
-        let mut config = EnrBridgeConfig::default();
-        config.max_message_age = Duration::from_secs(60);
-        let bridge = EnrBridge::new(local_id, config);
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_dispatch_keeps_sender_order() {
+        let config = EnrBridgeConfig {
+            dispatch_workers: 4,
+            ..Default::default()
+        };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
+        bridge.start_dispatch_workers();
 
         let sender = NodeId::from_bytes([2u8; 32]);
+        for cpu in 1..=50 {
+            let message = EnrMessage::Gradient(GradientMessage {
+                node_id: sender,
+                gradient: GradientPayload::from(&ResourceGradient {
+                    cpu_available: cpu as f64 / 100.0,
+                    ..Default::default()
+                }),
+                timestamp: Timestamp::now(),
+                signature: Signature::empty(),
+            });
+            bridge
+                .enqueue_message(EnrTopics::GRADIENT, message.to_bytes().unwrap())
+                .await
+                .unwrap();
+        }
 
-        let gradient = GradientMessage {
-            node_id: sender,
-            gradient: GradientPayload {
-                cpu_available: 0.5,
-                ..Default::default()
-            },
-            timestamp: Timestamp::now(),
-            signature: Signature::empty(),
+        while bridge.queued_messages() > 0 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        bridge.shutdown().await;
+        let gradients = bridge.gradients.read().await;
+        assert!((gradients[&sender].cpu_available - 0.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_queue_rejects_when_full() {
+        let config = EnrBridgeConfig {
+            dispatch_capacity: 1,
+            dispatch_overflow: QueueOverflow::Reject,
+            ..Default::default()
         };
+        let mut bridge = EnrBridge::new(test_node_id(), config);
 
-        let msg = EnrMessage::Gradient(gradient);
-        let data = msg.to_bytes().unwrap();
+        // Without workers, messages are handled inline
+        bridge
+            .enqueue_message(EnrTopics::GRADIENT, gradient_bytes(2))
+            .await
+            .unwrap();
+        assert_eq!(bridge.gradients.read().await.len(), 1);
 
-        // Should accept fresh message
-        let result = bridge.handle_message(EnrTopics::GRADIENT, &data).await;
-        assert!(result.is_ok());
+        // One sender's messages share a queue
+        bridge.start_dispatch_workers();
+        bridge
+            .enqueue_message(EnrTopics::GRADIENT, gradient_bytes(3))
+            .await
+            .unwrap();
+        assert!(matches!(
+            bridge
+                .enqueue_message(EnrTopics::GRADIENT, gradient_bytes(3))
+                .await,
+            Err(BridgeError::QueueFull)
+        ));
 
-        // Verify gradient was stored
-        let gradients = bridge.gradients.read().await;
-        assert!(gradients.contains_key(&sender));
+        bridge.stop();
+        assert_eq!(bridge.queued_messages(), 0);
     }
 
-    // ========================================================================
-    // Edge Case Tests
-    // ========================================================================
+    #[tokio::test]
+    async fn test_handle_message_rejects_topic_mismatch() {
+        let bridge = EnrBridge::new(test_node_id(), EnrBridgeConfig::default());
+        bridge.set_balance(Credits::new(0)).await;
+
+        // A credit transfer smuggled in on the gradient topic
+        let sender = NodeId::from_bytes([2u8; 32]);
+        let transfer = EnrMessage::Credit(CreditMessage::Transfer(CreditTransfer {
+            id: TransferId::from_transfer(&sender, &test_node_id(), 100, 1),
+            from: sender,
+            to: test_node_id(),
+            amount: 100,
+            nonce: 1,
+            timestamp: Timestamp::now(),
+            memo: None,
+            signature: Signature::empty(),
+        }));
+        let result = bridge
+            .handle_message(EnrTopics::GRADIENT, &transfer.to_bytes().unwrap())
+            .await;
+        assert!(matches!(
+            result,
+            Err(BridgeError::TopicMismatch { ref expected, ref received })
+                if expected == EnrTopics::CREDIT && received == EnrTopics::GRADIENT
+        ));
+        assert_eq!(bridge.balance().await, Credits::ZERO);
+        assert_eq!(bridge.topic_stats()[&TopicType::Gradient].dropped, 1);
+        assert!(!bridge.topic_stats().contains_key(&TopicType::Credit));
+    }
 
     #[tokio::test]
-    async fn test_publish_without_connection() {
-        let local_id = test_node_id();
-        let config = EnrBridgeConfig::default();
-        let bridge = EnrBridge::new(local_id, config);
+    async fn test_age_check_corrects_clock_skew() {
+        let config = EnrBridgeConfig {
+            max_clock_skew: Duration::from_secs(60),
+            clock_skew_smoothing: 1.0,
+            clock_skew_max_step: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let key = SigningKey::from_bytes(&[2u8; 32]);
+        let peer = NodeId::from_bytes(key.verifying_key().to_bytes());
+        let gradient = |behind_ms: u64| {
+            let mut msg = GradientMessage {
+                node_id: peer,
+                gradient: GradientPayload::from(&ResourceGradient::default()),
+                timestamp: Timestamp::new(Timestamp::now().millis - behind_ms),
+                signature: Signature::empty(),
+            };
+            msg.sign(&key);
+            EnrMessage::Gradient(msg).to_bytes().unwrap()
+        };
 
-        let msg = EnrMessage::Gradient(GradientMessage {
-            node_id: local_id,
+        // Peer's clock runs 40s behind; fresh messages still pass
+        bridge
+            .handle_message(EnrTopics::GRADIENT, &gradient(40_000))
+            .await
+            .unwrap();
+        let offset = bridge.clock_offset(&peer).await;
+        assert!((-40_100..=-39_900).contains(&offset));
+
+        // 90s behind our clock is only 50s old on the peer's
+        bridge
+            .handle_message(EnrTopics::GRADIENT, &gradient(90_000))
+            .await
+            .unwrap();
+        assert!(matches!(
+            bridge
+                .handle_message(EnrTopics::GRADIENT, &gradient(150_000))
+                .await,
+            Err(BridgeError::MessageExpired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_learned_only_from_signed_gradients() {
+        let config = EnrBridgeConfig {
+            max_clock_skew: Duration::from_secs(60),
+            clock_skew_smoothing: 1.0,
+            clock_skew_max_step: Duration::from_secs(5),
+            ..Default::default()
+        };
+        let bridge = EnrBridge::new(test_node_id(), config);
+        let key = SigningKey::from_bytes(&[2u8; 32]);
+        let peer = NodeId::from_bytes(key.verifying_key().to_bytes());
+        let gradient = |behind_ms: u64| GradientMessage {
+            node_id: peer,
             gradient: GradientPayload::from(&ResourceGradient::default()),
-            timestamp: Timestamp::now(),
+            timestamp: Timestamp::new(Timestamp::now().millis - behind_ms),
             signature: Signature::empty(),
-        });
+        };
 
-        let result = bridge.publish(msg);
-        assert!(matches!(result, Err(BridgeError::NotConnected)));
+        // Unsigned, and signed by someone else: accepted, but not learned from
+        let unsigned = gradient(40_000);
+        let mut forged = gradient(40_000);
+        forged.sign(&SigningKey::from_bytes(&[3u8; 32]));
+        for msg in [unsigned, forged] {
+            bridge
+                .handle_message(
+                    EnrTopics::GRADIENT,
+                    &EnrMessage::Gradient(msg).to_bytes().unwrap(),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(bridge.clock_offset(&peer).await, 0);
+
+        // Signed: learned, but at most 5s per message
+        let mut signed = gradient(40_000);
+        signed.sign(&key);
+        assert!(signed.verify_signature());
+        bridge
+            .handle_message(
+                EnrTopics::GRADIENT,
+                &EnrMessage::Gradient(signed).to_bytes().unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(bridge.clock_offset(&peer).await, -5_000);
     }
 
     #[tokio::test]
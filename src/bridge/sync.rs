@@ -0,0 +1,101 @@
+//! Credit State Sync
+//!
+//! Reconciliation of known-balance views between bridges.
+
+use std::collections::HashMap;
+
+use crate::core::{Credits, NodeId};
+
+/// Sync version per node, from `CreditStateSync.version`
+pub type BalanceVersions = HashMap<NodeId, u64>;
+
+/// Merge two known-balance views, keeping the higher-versioned entry per node
+///
+/// Nodes missing from a side's version map are treated as version 0. When
+/// versions are equal the larger balance wins, so the merge is commutative
+/// and two healing partitions converge on the same view regardless of order.
+pub fn merge_balances(
+    a: &HashMap<NodeId, Credits>,
+    b: &HashMap<NodeId, Credits>,
+    versions: (&BalanceVersions, &BalanceVersions),
+) -> HashMap<NodeId, Credits> {
+    let (a_versions, b_versions) = versions;
+    let mut merged = a.clone();
+
+    for (node, b_balance) in b {
+        match a.get(node) {
+            None => {
+                merged.insert(*node, *b_balance);
+            }
+            Some(a_balance) => {
+                let a_version = a_versions.get(node).copied().unwrap_or(0);
+                let b_version = b_versions.get(node).copied().unwrap_or(0);
+                let take_b =
+                    b_version > a_version || (b_version == a_version && b_balance > a_balance);
+                if take_b {
+                    merged.insert(*node, *b_balance);
+                }
+            }
+        }
+    }
+
+    merged
+}
+
+/// Merge two version maps, keeping the higher version per node
+pub fn merge_versions(a: &BalanceVersions, b: &BalanceVersions) -> BalanceVersions {
+    let mut merged = a.clone();
+    for (node, version) in b {
+        let entry = merged.entry(*node).or_insert(0);
+        *entry = (*entry).max(*version);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(n: u8) -> NodeId {
+        NodeId::from_bytes([n; 32])
+    }
+
+    #[test]
+    fn test_merge_prefers_higher_version() {
+        let a = HashMap::from([(node(1), Credits::new(100)), (node(2), Credits::new(50))]);
+        let b = HashMap::from([(node(1), Credits::new(80)), (node(2), Credits::new(70))]);
+        let a_versions = HashMap::from([(node(1), 3), (node(2), 1)]);
+        let b_versions = HashMap::from([(node(1), 2), (node(2), 5)]);
+
+        let merged = merge_balances(&a, &b, (&a_versions, &b_versions));
+        assert_eq!(merged.get(&node(1)), Some(&Credits::new(100)));
+        assert_eq!(merged.get(&node(2)), Some(&Credits::new(70)));
+    }
+
+    #[test]
+    fn test_merge_includes_disjoint_entries() {
+        let a = HashMap::from([(node(1), Credits::new(10))]);
+        let b = HashMap::from([(node(2), Credits::new(20))]);
+        let empty = BalanceVersions::new();
+
+        let merged = merge_balances(&a, &b, (&empty, &empty));
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.get(&node(2)), Some(&Credits::new(20)));
+    }
+
+    #[test]
+    fn test_merge_is_commutative() {
+        let a = HashMap::from([(node(1), Credits::new(10)), (node(2), Credits::new(30))]);
+        let b = HashMap::from([(node(1), Credits::new(40)), (node(2), Credits::new(20))]);
+        let a_versions = HashMap::from([(node(1), 1), (node(2), 2)]);
+        let b_versions = HashMap::from([(node(1), 1), (node(2), 2)]);
+
+        let ab = merge_balances(&a, &b, (&a_versions, &b_versions));
+        let ba = merge_balances(&b, &a, (&b_versions, &a_versions));
+        assert_eq!(ab, ba);
+        assert_eq!(
+            merge_versions(&a_versions, &b_versions),
+            merge_versions(&b_versions, &a_versions)
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Topic definitions for the ENR gossipsub protocol.
 
+use std::time::Duration;
+
 /// ENR topic constants for gossipsub subscription
 pub struct EnrTopics;
 
@@ -65,6 +67,100 @@ impl TopicType {
     }
 }
 
+/// Message counters for a single topic
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TopicStats {
+    /// Messages successfully published
+    pub sent: u64,
+    /// Messages received and decoded
+    pub received: u64,
+    /// Messages that failed to publish or were rejected on receipt
+    pub dropped: u64,
+    /// Time spent handling decoded messages received on this topic
+    pub handling: LatencyHistogram,
+}
+
+impl TopicStats {
+    /// Total messages seen on this topic in either direction
+    pub fn total(&self) -> u64 {
+        self.sent + self.received + self.dropped
+    }
+}
+
+/// Latency buckets; bucket `i` counts durations under 2^i microseconds,
+/// and the last also takes everything longer
+const LATENCY_BUCKETS: usize = 32;
+
+/// Histogram of durations in power-of-two microsecond buckets
+///
+/// Min, max, and mean are exact; percentiles are the upper bound of the
+/// bucket they fall in, capped at the maximum, so they may overstate by up
+/// to a factor of two.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    count: u64,
+    total_micros: u64,
+    min_micros: u64,
+    max_micros: u64,
+    buckets: [u64; LATENCY_BUCKETS],
+}
+
+impl LatencyHistogram {
+    pub fn record(&mut self, elapsed: Duration) {
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        let bucket = (u64::BITS - micros.leading_zeros()) as usize;
+        self.buckets[bucket.min(LATENCY_BUCKETS - 1)] += 1;
+        self.min_micros = if self.count == 0 {
+            micros
+        } else {
+            self.min_micros.min(micros)
+        };
+        self.max_micros = self.max_micros.max(micros);
+        self.total_micros = self.total_micros.saturating_add(micros);
+        self.count += 1;
+    }
+
+    /// Number of durations recorded
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_micros(self.min_micros))
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_micros(self.max_micros))
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0).then(|| Duration::from_micros(self.total_micros / self.count))
+    }
+
+    /// Duration at or below which `quantile` (in [0, 1]) of samples fall
+    pub fn percentile(&self, quantile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = ((quantile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (i, &n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                let upper = (1u64 << i).saturating_sub(1);
+                return Some(Duration::from_micros(
+                    upper.clamp(self.min_micros, self.max_micros),
+                ));
+            }
+        }
+        self.max()
+    }
+
+    pub fn p99(&self) -> Option<Duration> {
+        self.percentile(0.99)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +195,39 @@ mod tests {
         assert_eq!(EnrTopics::topic_type("/unknown"), None);
     }
 
+    #[test]
+    fn test_topic_stats_total() {
+        let stats = TopicStats {
+            sent: 3,
+            received: 5,
+            dropped: 1,
+            ..Default::default()
+        };
+        assert_eq!(stats.total(), 9);
+        assert_eq!(TopicStats::default().total(), 0);
+    }
+
+    #[test]
+    fn test_latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.p99(), None);
+
+        for _ in 0..98 {
+            histogram.record(Duration::from_micros(10));
+        }
+        histogram.record(Duration::from_micros(900));
+        histogram.record(Duration::from_millis(50));
+
+        assert_eq!(histogram.count(), 100);
+        assert_eq!(histogram.min(), Some(Duration::from_micros(10)));
+        assert_eq!(histogram.max(), Some(Duration::from_millis(50)));
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(518)));
+        // 10us lands in the [8, 16) bucket; the 99th sample in [512, 1024)
+        assert_eq!(histogram.percentile(0.5), Some(Duration::from_micros(15)));
+        assert_eq!(histogram.p99(), Some(Duration::from_micros(1023)));
+        assert_eq!(histogram.percentile(1.0), Some(Duration::from_millis(50)));
+    }
+
     #[test]
     fn test_topic_type_as_str() {
         assert_eq!(TopicType::Gradient.as_str(), "/enr/gradient/1.0");
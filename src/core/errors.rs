@@ -38,6 +38,12 @@ pub enum EnrError {
     #[error("Revival pool exhausted")]
     RevivalPoolExhausted,
 
+    #[error("Redistribution over budget: planned {planned}, available {available}")]
+    RedistributionOverBudget {
+        planned: Credits,
+        available: Credits,
+    },
+
     #[error("Credit conservation violated: expected {expected}, actual {actual}")]
     ConservationViolation { expected: Credits, actual: Credits },
 
@@ -8,6 +8,8 @@
 //! - SeptalSafety (line 520)
 
 use crate::core::{Credits, NodeId};
+use crate::entropy::{EntropyAccount, EntropyWeights};
+use crate::nexus::TopologyManager;
 
 /// Credit Conservation Invariant
 /// law: sum(all_credits) == GENESIS_AMOUNT + minted - burned
@@ -139,17 +141,231 @@ impl InvariantChecker {
 
         violations
     }
+
+    /// Run every invariant check and report each with pass/fail and detail
+    pub fn full_report(&self, ctx: &InvariantContext) -> InvariantReport {
+        let mut entries = Vec::new();
+
+        // Credit conservation
+        let conservation = self.check_all(ctx.actual_total);
+        let detail = format!(
+            "expected {}, actual {}",
+            self.conservation.expected_total(),
+            ctx.actual_total
+        );
+        entries.push(InvariantReportEntry::new(
+            "credit_conservation",
+            detail,
+            conservation,
+        ));
+
+        // Entropy non-negative and bounded, per component
+        let mut negative = Vec::new();
+        let mut unbounded = Vec::new();
+        for (_, account) in ctx.entropy_accounts {
+            for value in [
+                account.network,
+                account.compute,
+                account.storage,
+                account.temporal,
+            ] {
+                if !check_entropy_non_negative(value) {
+                    negative.push(InvariantViolation::EntropyNegative { value });
+                } else if !check_entropy_bounded(value, EntropyAccount::MAX_COMPONENT) {
+                    unbounded.push(InvariantViolation::EntropyUnbounded {
+                        value,
+                        max: EntropyAccount::MAX_COMPONENT,
+                    });
+                }
+            }
+        }
+        let accounts = ctx.entropy_accounts.len();
+        entries.push(InvariantReportEntry::new(
+            "entropy_non_negative",
+            format!("{} accounts checked", accounts),
+            negative,
+        ));
+        entries.push(InvariantReportEntry::new(
+            "entropy_bounded",
+            format!(
+                "{} accounts checked against max {}",
+                accounts,
+                EntropyAccount::MAX_COMPONENT
+            ),
+            unbounded,
+        ));
+
+        // Entropy weights
+        let weights = [
+            ctx.weights.network_weight,
+            ctx.weights.compute_weight,
+            ctx.weights.storage_weight,
+            ctx.weights.temporal_weight,
+        ];
+        let sum: f64 = weights.iter().sum();
+        let weight_violations = if check_weights_normalized(&weights, 0.001) {
+            Vec::new()
+        } else {
+            vec![InvariantViolation::WeightsNotNormalized { sum }]
+        };
+        entries.push(InvariantReportEntry::new(
+            "weights_normalized",
+            format!("sum {:.4}", sum),
+            weight_violations,
+        ));
+
+        // Nexus consistency: every leaf pointing at a nexus is in its view
+        let mut nexus_violations = Vec::new();
+        let mut nexus_count = 0;
+        if let Some(topology) = ctx.topology {
+            for nexus in topology.get_nexuses() {
+                nexus_count += 1;
+                let children = topology
+                    .get_topology(&nexus)
+                    .map(|t| t.role.children.clone())
+                    .unwrap_or_default();
+                for leaf in topology.get_leaves_of(&nexus) {
+                    if !children.contains(&leaf) {
+                        nexus_violations
+                            .push(InvariantViolation::NexusInconsistency { nexus, leaf });
+                    }
+                }
+            }
+        }
+        entries.push(InvariantReportEntry::new(
+            "nexus_consistency",
+            format!("{} nexuses checked", nexus_count),
+            nexus_violations,
+        ));
+
+        // Septal safety: no credit flow touches an isolated node
+        let septal_violations = ctx
+            .credit_flows
+            .iter()
+            .flat_map(|(from, to)| [from, to])
+            .filter(|node| self.septal_safety.is_isolated(node))
+            .map(|node| InvariantViolation::SeptalViolation {
+                isolated_node: *node,
+            })
+            .collect();
+        entries.push(InvariantReportEntry::new(
+            "septal_safety",
+            format!(
+                "{} flows checked, {} nodes isolated",
+                ctx.credit_flows.len(),
+                self.septal_safety.isolated_nodes.len()
+            ),
+            septal_violations,
+        ));
+
+        InvariantReport { entries }
+    }
 }
 
-/// Invariant violation types
+/// Observed network state supplied to [`InvariantChecker::full_report`]
+#[derive(Debug, Clone)]
+pub struct InvariantContext<'a> {
+    /// Actual total credits in circulation
+    pub actual_total: Credits,
+    /// Entropy accounts to check, keyed by node
+    pub entropy_accounts: &'a [(NodeId, EntropyAccount)],
+    /// Entropy weights in use
+    pub weights: EntropyWeights,
+    /// Current topology, if known
+    pub topology: Option<&'a TopologyManager>,
+    /// Recent credit flows as (from, to) pairs
+    pub credit_flows: &'a [(NodeId, NodeId)],
+}
+
+impl<'a> InvariantContext<'a> {
+    pub fn new(actual_total: Credits) -> Self {
+        Self {
+            actual_total,
+            entropy_accounts: &[],
+            weights: EntropyWeights::default(),
+            topology: None,
+            credit_flows: &[],
+        }
+    }
+}
+
+/// Result of a single invariant check
+#[derive(Debug, Clone)]
+pub struct InvariantReportEntry {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+    pub violations: Vec<InvariantViolation>,
+}
+
+impl InvariantReportEntry {
+    fn new(name: &'static str, detail: String, violations: Vec<InvariantViolation>) -> Self {
+        Self {
+            name,
+            passed: violations.is_empty(),
+            detail,
+            violations,
+        }
+    }
+}
+
+/// Structured report of every invariant check
 #[derive(Debug, Clone)]
+pub struct InvariantReport {
+    pub entries: Vec<InvariantReportEntry>,
+}
+
+impl InvariantReport {
+    /// True if every invariant passed
+    pub fn is_healthy(&self) -> bool {
+        self.entries.iter().all(|e| e.passed)
+    }
+
+    /// Entries that failed
+    pub fn failures(&self) -> Vec<&InvariantReportEntry> {
+        self.entries.iter().filter(|e| !e.passed).collect()
+    }
+
+    /// Look up an entry by invariant name
+    pub fn entry(&self, name: &str) -> Option<&InvariantReportEntry> {
+        self.entries.iter().find(|e| e.name == name)
+    }
+}
+
+/// Invariant violation types
+#[derive(Debug, Clone, PartialEq)]
 pub enum InvariantViolation {
-    CreditConservation { expected: Credits, actual: Credits },
-    EntropyNegative { value: f64 },
-    EntropyUnbounded { value: f64, max: f64 },
-    NexusInconsistency { nexus: NodeId, leaf: NodeId },
-    SeptalViolation { isolated_node: NodeId },
-    WeightsNotNormalized { sum: f64 },
+    CreditConservation {
+        expected: Credits,
+        actual: Credits,
+    },
+    EntropyNegative {
+        value: f64,
+    },
+    EntropyUnbounded {
+        value: f64,
+        max: f64,
+    },
+    NexusInconsistency {
+        nexus: NodeId,
+        leaf: NodeId,
+    },
+    LeafCountOutOfBounds {
+        nexus: NodeId,
+        count: u32,
+        min: u32,
+        max: u32,
+    },
+    /// A leaf with no parent, or a parent that isn't a known hub
+    OrphanedNode {
+        node: NodeId,
+    },
+    SeptalViolation {
+        isolated_node: NodeId,
+    },
+    WeightsNotNormalized {
+        sum: f64,
+    },
 }
 
 #[cfg(test)]
@@ -198,6 +414,75 @@ mod tests {
         assert!(safety.can_transact(&node));
     }
 
+    #[test]
+    fn test_full_report() {
+        use crate::core::Timestamp;
+        use crate::nexus::{NexusRole, NexusTopology, ResourceGradient};
+
+        let nexus = NodeId::from_bytes([1u8; 32]);
+        let leaf = NodeId::from_bytes([2u8; 32]);
+        let isolated = NodeId::from_bytes([3u8; 32]);
+
+        let mut checker = InvariantChecker::new(Credits::new(1000));
+        checker.septal_safety.isolate(isolated);
+
+        // Healthy context
+        let report = checker.full_report(&InvariantContext::new(Credits::new(1000)));
+        assert!(report.is_healthy());
+        assert_eq!(report.entries.len(), 6);
+
+        // Leaf claims a nexus parent that doesn't list it
+        let mut topology = TopologyManager::new();
+        topology.set_topology(
+            nexus,
+            NexusTopology {
+                node: nexus,
+                role: NexusRole::nexus(None, vec![]),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            },
+        );
+        topology.set_topology(
+            leaf,
+            NexusTopology {
+                node: leaf,
+                role: NexusRole::leaf(nexus),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            },
+        );
+        let accounts = [(
+            leaf,
+            EntropyAccount {
+                network: -1.0,
+                storage: 12.0,
+                ..Default::default()
+            },
+        )];
+        let flows = [(leaf, isolated)];
+        let ctx = InvariantContext {
+            actual_total: Credits::new(999),
+            entropy_accounts: &accounts,
+            weights: EntropyWeights {
+                network_weight: 0.5,
+                ..Default::default()
+            },
+            topology: Some(&topology),
+            credit_flows: &flows,
+        };
+
+        let report = checker.full_report(&ctx);
+        assert!(!report.is_healthy());
+        assert_eq!(report.failures().len(), 6);
+        assert!(matches!(
+            report.entry("nexus_consistency").unwrap().violations[0],
+            InvariantViolation::NexusInconsistency { .. }
+        ));
+        assert_eq!(report.entry("septal_safety").unwrap().violations.len(), 1);
+    }
+
     #[test]
     fn test_weights_normalized() {
         let good_weights = [0.3, 0.3, 0.2, 0.2];
@@ -0,0 +1,88 @@
+//! Credit Ledgers
+//!
+//! Abstraction over wherever node balances live, so credit movements can be
+//! written once and applied to any store.
+
+use std::collections::HashMap;
+
+use super::{Credits, EnrError, EnrResult, NodeId};
+
+/// A store of per-node credit balances
+pub trait CreditLedger {
+    /// Balance of a node (zero if unknown)
+    fn balance(&self, node: &NodeId) -> Credits;
+
+    /// Add credits to a node's balance
+    fn credit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()>;
+
+    /// Remove credits from a node's balance
+    ///
+    /// Fails with `InsufficientCredits` rather than going below zero.
+    fn debit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()>;
+
+    /// Sum of all balances
+    fn total_supply(&self) -> Credits;
+}
+
+/// In-memory ledger backed by a map
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryLedger {
+    pub balances: HashMap<NodeId, Credits>,
+}
+
+impl MemoryLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CreditLedger for MemoryLedger {
+    fn balance(&self, node: &NodeId) -> Credits {
+        self.balances.get(node).copied().unwrap_or(Credits::ZERO)
+    }
+
+    fn credit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+        let balance = self.balances.entry(*node).or_insert(Credits::ZERO);
+        *balance = balance
+            .checked_add(amount)
+            .ok_or_else(|| EnrError::TransferFailed("balance overflow".to_string()))?;
+        Ok(())
+    }
+
+    fn debit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+        let available = self.balance(node);
+        let remaining = available
+            .checked_sub(amount)
+            .ok_or(EnrError::InsufficientCredits {
+                required: amount,
+                available,
+            })?;
+        self.balances.insert(*node, remaining);
+        Ok(())
+    }
+
+    fn total_supply(&self) -> Credits {
+        Credits::new(self.balances.values().map(|c| c.amount).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_ledger() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let mut ledger = MemoryLedger::new();
+        assert!(ledger.balance(&node).is_zero());
+
+        ledger.credit(&node, Credits::new(100)).unwrap();
+        ledger.debit(&node, Credits::new(40)).unwrap();
+        assert_eq!(ledger.balance(&node), Credits::new(60));
+        assert!(matches!(
+            ledger.debit(&node, Credits::new(61)),
+            Err(EnrError::InsufficientCredits { .. })
+        ));
+        assert_eq!(ledger.total_supply(), Credits::new(60));
+    }
+}
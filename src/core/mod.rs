@@ -4,10 +4,16 @@
 
 pub mod errors;
 pub mod invariants;
+pub mod ledger;
+pub mod reputation;
+pub mod reservations;
 pub mod state;
 pub mod types;
 
 pub use errors::*;
 pub use invariants::*;
+pub use ledger::*;
+pub use reputation::*;
+pub use reservations::*;
 pub use state::*;
 pub use types::*;
@@ -0,0 +1,142 @@
+//! Node Reputation
+//!
+//! Derives a [0, 1] reputation from a node's recorded history, so the
+//! election, septal, and revival modules share one definition.
+
+use serde::{Deserialize, Serialize};
+
+/// Recorded behavior of a single node
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ReputationHistory {
+    /// Completed interactions (transfers confirmed, probes answered)
+    pub successes: u64,
+    /// Failed interactions (timeouts, defaults)
+    pub failures: u64,
+    /// Times the node's septal gate was tripped
+    pub isolations: u32,
+    /// Observed uptime fraction [0, 1]
+    pub uptime: f64,
+}
+
+impl ReputationHistory {
+    pub fn record_success(&mut self) {
+        self.successes = self.successes.saturating_add(1);
+    }
+
+    pub fn record_failure(&mut self) {
+        self.failures = self.failures.saturating_add(1);
+    }
+
+    pub fn record_isolation(&mut self) {
+        self.isolations = self.isolations.saturating_add(1);
+    }
+}
+
+/// Relative weight of each reputation factor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReputationWeights {
+    pub success_weight: f64,
+    pub isolation_weight: f64,
+    pub uptime_weight: f64,
+}
+
+impl Default for ReputationWeights {
+    fn default() -> Self {
+        Self {
+            success_weight: 0.5,
+            isolation_weight: 0.2,
+            uptime_weight: 0.3,
+        }
+    }
+}
+
+impl ReputationWeights {
+    /// Weights must be non-negative and sum to 1.0
+    pub fn is_valid(&self) -> bool {
+        let sum = self.success_weight + self.isolation_weight + self.uptime_weight;
+        self.success_weight >= 0.0
+            && self.isolation_weight >= 0.0
+            && self.uptime_weight >= 0.0
+            && (sum - 1.0).abs() < 0.001
+    }
+}
+
+/// Computes reputation from history
+#[derive(Debug, Clone, Default)]
+pub struct ReputationCalculator {
+    pub weights: ReputationWeights,
+}
+
+impl ReputationCalculator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_weights(weights: ReputationWeights) -> Self {
+        Self { weights }
+    }
+
+    /// Reputation in [0, 1]
+    ///
+    /// reputation = success_weight * (successes + 1) / (total + 2)
+    ///            + isolation_weight / (1 + isolations)
+    ///            + uptime_weight * uptime
+    ///
+    /// The success rate is Laplace-smoothed, so a node with no history
+    /// starts at a neutral 0.5 rather than 0 or 1.
+    pub fn calculate(&self, history: &ReputationHistory) -> f64 {
+        let total = history.successes as f64 + history.failures as f64;
+        let success_rate = (history.successes as f64 + 1.0) / (total + 2.0);
+        let isolation_score = 1.0 / (1.0 + history.isolations as f64);
+        let uptime = if history.uptime.is_nan() {
+            0.0
+        } else {
+            history.uptime.clamp(0.0, 1.0)
+        };
+
+        let score = self.weights.success_weight * success_rate
+            + self.weights.isolation_weight * isolation_score
+            + self.weights.uptime_weight * uptime;
+        score.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reputation_from_history() {
+        let calc = ReputationCalculator::new();
+
+        let perfect = ReputationHistory {
+            successes: 1_000_000,
+            failures: 0,
+            isolations: 0,
+            uptime: 1.0,
+        };
+        assert!(calc.calculate(&perfect) > 0.99);
+
+        // Unknown node: neutral success rate, no isolations, no uptime
+        let fresh = ReputationHistory::default();
+        assert!((calc.calculate(&fresh) - (0.5 * 0.5 + 0.2)).abs() < 1e-9);
+
+        let mut flaky = perfect;
+        for _ in 0..3 {
+            flaky.record_isolation();
+        }
+        flaky.failures = 1_000_000;
+        assert!(calc.calculate(&flaky) < calc.calculate(&perfect));
+        assert!(calc.calculate(&flaky) >= 0.0);
+    }
+
+    #[test]
+    fn test_reputation_weights_valid() {
+        assert!(ReputationWeights::default().is_valid());
+        assert!(!ReputationWeights {
+            success_weight: 0.9,
+            ..Default::default()
+        }
+        .is_valid());
+    }
+}
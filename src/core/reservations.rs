@@ -0,0 +1,123 @@
+//! Credit Reservations
+//!
+//! Tracks CreditReservation lifecycles from dol/core.dol line 144
+
+use std::collections::HashMap;
+
+use crate::core::{
+    AccountId, CreditReservation, Credits, Duration, EnrError, EnrResult, ReservationId, Timestamp,
+};
+
+/// Manager for outstanding credit reservations
+#[derive(Debug, Default)]
+pub struct ReservationManager {
+    reservations: HashMap<ReservationId, CreditReservation>,
+    next_id: u64,
+}
+
+impl ReservationManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a reservation and return its id
+    pub fn reserve(&mut self, account: AccountId, amount: Credits, ttl: Duration) -> ReservationId {
+        self.next_id += 1;
+        let id = ReservationId::new(self.next_id);
+        self.reservations
+            .insert(id, CreditReservation::new(id, account, amount, ttl));
+        id
+    }
+
+    pub fn get(&self, id: ReservationId) -> Option<&CreditReservation> {
+        self.reservations.get(&id)
+    }
+
+    /// Extend a reservation so it expires `additional` from now
+    pub fn renew(&mut self, id: ReservationId, additional: Duration) -> EnrResult<()> {
+        self.reservations
+            .get_mut(&id)
+            .ok_or(EnrError::ReservationNotFound(id))?
+            .renew(additional)
+    }
+
+    /// Consume a live reservation, returning the reserved amount
+    pub fn consume(&mut self, id: ReservationId) -> EnrResult<Credits> {
+        let reservation = self
+            .reservations
+            .get_mut(&id)
+            .ok_or(EnrError::ReservationNotFound(id))?;
+        if reservation.consumed {
+            return Err(EnrError::ReservationAlreadyConsumed(id));
+        }
+        if reservation.is_expired(Timestamp::now()) {
+            return Err(EnrError::ReservationExpired(id));
+        }
+        reservation.consume();
+        Ok(reservation.amount)
+    }
+
+    /// Remove unconsumed reservations that expired before `now`
+    pub fn release_expired(&mut self, now: Timestamp) -> Vec<CreditReservation> {
+        let expired: Vec<ReservationId> = self
+            .reservations
+            .values()
+            .filter(|r| !r.consumed && r.is_expired(now))
+            .map(|r| r.id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|id| self.reservations.remove(&id))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.reservations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reservations.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NodeId;
+
+    #[test]
+    fn test_reservation_manager_renew() {
+        let mut manager = ReservationManager::new();
+        let account = AccountId::node_account(NodeId::from_bytes([1u8; 32]));
+        let id = manager.reserve(account, Credits::new(100), Duration::seconds(10));
+
+        manager.renew(id, Duration::minutes(5)).unwrap();
+        let later = Timestamp::new(Timestamp::now().millis + 60_000);
+        assert!(!manager.get(id).unwrap().is_expired(later));
+
+        assert_eq!(manager.consume(id).unwrap(), Credits::new(100));
+        assert!(matches!(
+            manager.renew(id, Duration::minutes(5)),
+            Err(EnrError::ReservationAlreadyConsumed(_))
+        ));
+        assert!(matches!(
+            manager.renew(ReservationId::new(99), Duration::minutes(5)),
+            Err(EnrError::ReservationNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_reservation_manager_release_expired() {
+        let mut manager = ReservationManager::new();
+        let account = AccountId::node_account(NodeId::from_bytes([1u8; 32]));
+        let short = manager.reserve(account.clone(), Credits::new(10), Duration::seconds(1));
+        let long = manager.reserve(account, Credits::new(20), Duration::hours(1));
+
+        let later = Timestamp::new(Timestamp::now().millis + 10_000);
+        let released = manager.release_expired(later);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].id, short);
+        assert!(manager.get(long).is_some());
+        assert_eq!(manager.len(), 1);
+    }
+}
@@ -4,11 +4,13 @@
 //! From ENR-ARCHITECTURE.md Section 4
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Credit state machine states
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum CreditState {
     /// Credits available for use
+    #[default]
     Active,
     /// Credits reserved for pending operation
     Reserved,
@@ -21,6 +23,26 @@ pub enum CreditState {
 }
 
 impl CreditState {
+    /// Every credit state
+    pub fn all() -> &'static [CreditState] {
+        use CreditState::*;
+        &[Active, Reserved, Consumed, Released, InRevival]
+    }
+
+    /// States reachable from `start` via valid transitions (including `start`)
+    pub fn reachable_from(start: CreditState) -> HashSet<CreditState> {
+        let mut visited = HashSet::from([start]);
+        let mut frontier = vec![start];
+        while let Some(state) = frontier.pop() {
+            for next in Self::all() {
+                if state.can_transition_to(*next) && visited.insert(*next) {
+                    frontier.push(*next);
+                }
+            }
+        }
+        visited
+    }
+
     /// Valid state transitions
     pub fn can_transition_to(&self, next: CreditState) -> bool {
         use CreditState::*;
@@ -58,12 +80,6 @@ impl CreditState {
     }
 }
 
-impl Default for CreditState {
-    fn default() -> Self {
-        CreditState::Active
-    }
-}
-
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum StateError {
     #[error("Invalid state transition from {from:?} to {to:?}")]
@@ -119,6 +135,30 @@ mod tests {
         assert!(CreditState::InRevival.can_transition_to(CreditState::Active));
     }
 
+    #[test]
+    fn test_all_states_reachable() {
+        // Every state is reachable from Active
+        let from_active = CreditState::reachable_from(CreditState::Active);
+        assert_eq!(from_active.len(), CreditState::all().len());
+
+        // No state is a sink: credits always cycle back to Active
+        for state in CreditState::all() {
+            assert!(
+                CreditState::reachable_from(*state).contains(&CreditState::Active),
+                "{:?} cannot return to Active",
+                state
+            );
+        }
+
+        // Consumed credits only leave through the revival pool
+        let from_consumed = CreditState::reachable_from(CreditState::Consumed);
+        assert!(from_consumed.contains(&CreditState::InRevival));
+        assert!(CreditState::all()
+            .iter()
+            .filter(|s| CreditState::Consumed.can_transition_to(**s))
+            .eq([CreditState::InRevival].iter()));
+    }
+
     #[test]
     fn test_invalid_transitions() {
         assert!(!CreditState::Active.can_transition_to(CreditState::Consumed));
@@ -9,6 +9,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::{EnrError, EnrResult};
+
 /// NodeId - from dol/core.dol line 24
 /// Ed25519 public key based identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -92,6 +94,23 @@ impl Timestamp {
             .as_millis() as u64;
         Self { millis }
     }
+
+    /// Shift by a signed number of milliseconds, saturating at both ends
+    pub fn shifted(&self, offset_ms: i64) -> Self {
+        let millis = if offset_ms >= 0 {
+            self.millis.saturating_add(offset_ms as u64)
+        } else {
+            self.millis.saturating_sub(offset_ms.unsigned_abs())
+        };
+        Self { millis }
+    }
+
+    /// Milliseconds elapsed from this timestamp to `now`, after correcting
+    /// for the stamping clock running `offset_ms` ahead (zero if in the
+    /// future)
+    pub fn age_with_skew(&self, now: Timestamp, offset_ms: i64) -> u64 {
+        now.millis.saturating_sub(self.shifted(-offset_ms).millis)
+    }
 }
 
 /// Duration - from dol/core.dol line 67
@@ -179,6 +198,64 @@ impl Credits {
     pub fn is_zero(&self) -> bool {
         self.amount == 0
     }
+
+    /// Convert a float amount to credits, truncating toward zero
+    ///
+    /// Negative values and NaN become zero; values beyond `u64::MAX`
+    /// (including +infinity) saturate to `u64::MAX`.
+    pub fn from_f64_saturating(v: f64) -> Self {
+        let amount = if v.is_nan() || v <= 0.0 {
+            0
+        } else if v >= u64::MAX as f64 {
+            u64::MAX
+        } else {
+            v as u64
+        };
+        Self { amount }
+    }
+
+    /// Fraction of this amount in basis points (1 bps = 0.01%), rounded down
+    ///
+    /// Uses integer math: `amount * bps / 10_000`, widened to avoid overflow.
+    pub fn basis_points(&self, bps: u32) -> Self {
+        let scaled = self.amount as u128 * bps as u128 / 10_000;
+        Self {
+            amount: scaled.min(u64::MAX as u128) as u64,
+        }
+    }
+
+    /// Percentage of this amount (e.g. `2.0` for 2%), rounded down
+    ///
+    /// Percentages with a whole number of basis points go through
+    /// [`Credits::basis_points`]; finer fractions fall back to float math.
+    pub fn percent(&self, pct: f64) -> Self {
+        if !pct.is_finite() || pct <= 0.0 {
+            return Self::ZERO;
+        }
+        let bps = pct * 100.0;
+        if bps.fract() == 0.0 && bps <= u32::MAX as f64 {
+            return self.basis_points(bps as u32);
+        }
+        Self::from_f64_saturating((self.amount as f64 * pct / 100.0).floor())
+    }
+
+    /// Format with a fixed number of fractional digits, e.g. "1.000000 credits"
+    ///
+    /// Credits are whole units today, so the fractional digits are always
+    /// zero; callers that pick a precision now keep it once sub-unit
+    /// amounts exist.
+    pub fn display_fractional(&self, decimals: usize) -> String {
+        self.display_with_unit(decimals, CREDIT_UNIT_LABEL)
+    }
+
+    /// Format with a fixed precision and a custom unit label
+    pub fn display_with_unit(&self, decimals: usize, unit: &str) -> String {
+        if decimals == 0 {
+            format!("{} {}", self.amount, unit)
+        } else {
+            format!("{}.{} {}", self.amount, "0".repeat(decimals), unit)
+        }
+    }
 }
 
 impl std::ops::Add for Credits {
@@ -213,10 +290,13 @@ impl std::ops::SubAssign for Credits {
 
 impl std::fmt::Display for Credits {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} credits", self.amount)
+        write!(f, "{} {}", self.amount, CREDIT_UNIT_LABEL)
     }
 }
 
+/// Unit label used by `Credits` display formatting
+pub const CREDIT_UNIT_LABEL: &str = "credits";
+
 /// ReservationId - from dol/core.dol CreditReservation.id
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ReservationId(pub u64);
@@ -289,6 +369,26 @@ impl CreditReservation {
     pub fn consume(&mut self) {
         self.consumed = true;
     }
+
+    /// Extend the reservation so it expires `additional` from now
+    pub fn renew(&mut self, additional: Duration) -> EnrResult<()> {
+        self.renew_at(additional, Timestamp::now())
+    }
+
+    /// Extend the reservation so it expires `additional` after `now`
+    ///
+    /// Fails if the reservation is already consumed or expired.
+    pub fn renew_at(&mut self, additional: Duration, now: Timestamp) -> EnrResult<()> {
+        if self.consumed {
+            return Err(EnrError::ReservationAlreadyConsumed(self.id));
+        }
+        if self.is_expired(now) {
+            return Err(EnrError::ReservationExpired(self.id));
+        }
+        let elapsed = now.millis.saturating_sub(self.created_at.millis);
+        self.ttl = Duration::new(elapsed.saturating_add(additional.millis));
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -306,6 +406,50 @@ mod tests {
         assert_eq!(b.checked_sub(a), None);
     }
 
+    #[test]
+    fn test_credits_percent() {
+        let c = Credits::new(1000);
+        assert_eq!(c.percent(2.0), Credits::new(20));
+        assert_eq!(c.percent(15.0), Credits::new(150));
+        assert_eq!(c.percent(0.25), Credits::new(2));
+        assert_eq!(c.percent(0.0125), Credits::new(0));
+        assert_eq!(c.percent(-5.0), Credits::ZERO);
+        assert_eq!(c.percent(f64::NAN), Credits::ZERO);
+
+        assert_eq!(c.basis_points(200), Credits::new(20));
+        assert_eq!(c.basis_points(10_000), c);
+
+        // No overflow on large amounts
+        let max = Credits::new(u64::MAX);
+        assert_eq!(max.basis_points(5_000).amount, u64::MAX / 2);
+        assert_eq!(max.basis_points(20_000).amount, u64::MAX);
+    }
+
+    #[test]
+    fn test_credits_from_f64_saturating() {
+        assert_eq!(Credits::from_f64_saturating(42.9), Credits::new(42));
+        assert_eq!(Credits::from_f64_saturating(-3.0), Credits::ZERO);
+        assert_eq!(Credits::from_f64_saturating(f64::NAN), Credits::ZERO);
+        assert_eq!(
+            Credits::from_f64_saturating(f64::NEG_INFINITY),
+            Credits::ZERO
+        );
+        assert_eq!(Credits::from_f64_saturating(f64::INFINITY).amount, u64::MAX);
+        assert_eq!(Credits::from_f64_saturating(1e30).amount, u64::MAX);
+    }
+
+    #[test]
+    fn test_credits_display_fractional() {
+        let credits = Credits::new(1);
+        assert_eq!(credits.to_string(), "1 credits");
+        assert_eq!(credits.display_fractional(0), "1 credits");
+        assert_eq!(credits.display_fractional(6), "1.000000 credits");
+        assert_eq!(
+            Credits::new(u64::MAX).display_with_unit(2, "CR"),
+            format!("{}.00 CR", u64::MAX)
+        );
+    }
+
     #[test]
     fn test_node_id_hex() {
         let bytes = [1u8; 32];
@@ -346,4 +490,37 @@ mod tests {
         reservation.consume();
         assert!(reservation.consumed);
     }
+
+    #[test]
+    fn test_reservation_renew() {
+        let node = NodeId::from_bytes([0u8; 32]);
+        let mut reservation = CreditReservation::new(
+            ReservationId::new(1),
+            AccountId::node_account(node),
+            Credits::new(100),
+            Duration::seconds(10),
+        );
+        let created = reservation.created_at.millis;
+
+        // Renew 5s in: expiry moves to now + 30s
+        reservation
+            .renew_at(Duration::seconds(30), Timestamp::new(created + 5_000))
+            .unwrap();
+        assert!(!reservation.is_expired(Timestamp::new(created + 34_000)));
+        assert!(reservation.is_expired(Timestamp::new(created + 36_000)));
+
+        // Expired reservations can't be renewed
+        let late = Timestamp::new(created + 60_000);
+        assert!(matches!(
+            reservation.renew_at(Duration::seconds(30), late),
+            Err(EnrError::ReservationExpired(_))
+        ));
+
+        // Neither can consumed ones
+        reservation.consume();
+        assert!(matches!(
+            reservation.renew(Duration::seconds(30)),
+            Err(EnrError::ReservationAlreadyConsumed(_))
+        ));
+    }
 }
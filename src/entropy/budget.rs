@@ -0,0 +1,115 @@
+//! Entropy Budgets
+//!
+//! Per-node leaky-bucket rate limiting on spent entropy
+
+use super::calculator::weighted_entropy_sum;
+use super::types::*;
+use crate::core::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Leaky-bucket entropy budget for a single node
+///
+/// Each operation adds its weighted entropy to the bucket; the bucket
+/// drains at `leak_per_sec`. Operations that would overflow `capacity`
+/// are rejected until enough has drained.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntropyBudget {
+    /// Maximum entropy the bucket holds
+    pub capacity: f64,
+    /// Entropy drained per second
+    pub leak_per_sec: f64,
+    /// Entropy currently in the bucket
+    pub level: f64,
+    /// Last time the level was drained
+    pub last_update: Timestamp,
+    pub weights: EntropyWeights,
+}
+
+impl EntropyBudget {
+    pub fn new(capacity: f64, leak_per_sec: f64) -> Self {
+        Self {
+            capacity,
+            leak_per_sec,
+            level: 0.0,
+            last_update: Timestamp::now(),
+            weights: EntropyWeights::default(),
+        }
+    }
+
+    pub fn with_weights(mut self, weights: EntropyWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Drain the bucket up to `now`
+    fn leak(&mut self, now: Timestamp) {
+        let elapsed_secs = now.millis.saturating_sub(self.last_update.millis) as f64 / 1000.0;
+        self.level = (self.level - elapsed_secs * self.leak_per_sec).max(0.0);
+        if now.millis > self.last_update.millis {
+            self.last_update = now;
+        }
+    }
+
+    /// Spend the entropy of an operation if the budget allows it
+    pub fn try_spend(&mut self, account: &EntropyAccount) -> bool {
+        self.try_spend_at(account, Timestamp::now())
+    }
+
+    /// Spend the entropy of an operation at `now` if the budget allows it
+    pub fn try_spend_at(&mut self, account: &EntropyAccount, now: Timestamp) -> bool {
+        self.leak(now);
+        let cost = weighted_entropy_sum(account, &self.weights);
+        if !cost.is_finite() || cost < 0.0 || self.level + cost > self.capacity {
+            return false;
+        }
+        self.level += cost;
+        true
+    }
+
+    /// Entropy that can still be spent at `now`
+    pub fn remaining_at(&mut self, now: Timestamp) -> f64 {
+        self.leak(now);
+        self.capacity - self.level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(total: f64) -> EntropyAccount {
+        // Equal components so the default-weighted sum equals `total`
+        EntropyAccount {
+            network: total,
+            compute: total,
+            storage: total,
+            temporal: total,
+        }
+    }
+
+    #[test]
+    fn test_entropy_budget_throttles_and_refills() {
+        let start = Timestamp::new(1_000_000);
+        let mut budget = EntropyBudget::new(10.0, 1.0);
+        budget.last_update = start;
+
+        assert!(budget.try_spend_at(&account(6.0), start));
+        assert!(!budget.try_spend_at(&account(6.0), start));
+        assert!(budget.try_spend_at(&account(4.0), start));
+        assert!(budget.remaining_at(start).abs() < 1e-9);
+
+        // Five seconds later five units have drained
+        let later = Timestamp::new(start.millis + 5_000);
+        assert!((budget.remaining_at(later) - 5.0).abs() < 1e-9);
+        assert!(budget.try_spend_at(&account(5.0), later));
+        assert!(!budget.try_spend_at(&account(0.5), later));
+    }
+
+    #[test]
+    fn test_entropy_budget_rejects_invalid_cost() {
+        let mut budget = EntropyBudget::new(10.0, 1.0);
+        assert!(!budget.try_spend(&account(f64::NAN)));
+        assert!(!budget.try_spend(&account(-1.0)));
+        assert!(budget.try_spend(&EntropyAccount::zero()));
+    }
+}
@@ -3,8 +3,10 @@
 //! Implements entropy calculation from dol/entropy.dol lines 87-311
 
 use super::types::*;
+use crate::core::{EnrError, EnrResult};
+use serde::{Deserialize, Serialize};
 
-/// Entropy calculation constants from dol/entropy.dol lines 28-62
+// Entropy calculation constants from dol/entropy.dol lines 28-62
 
 // Network entropy factors (α coefficients)
 pub const HOP_ENTROPY_BASE: f64 = 0.1;
@@ -103,11 +105,42 @@ pub fn calculate_temporal_entropy(input: &TemporalEntropyInput) -> f64 {
 /// Formula: S_total = wₙ·Sₙ + wᶜ·Sᶜ + wˢ·Sˢ + wᵗ·Sᵗ
 ///
 /// From dol/entropy.dol lines 261-278
+///
+/// With `weights.max_contribution` set, the largest non-negative terms are
+/// lowered until none exceeds that fraction of the capped non-negative
+/// total, so one runaway dimension can't dominate. Negative terms (declines
+/// in an [`EntropyDelta`](super::EntropyDelta)) pass through uncapped. A
+/// fraction too small to satisfy evens the terms out at the smallest one.
 pub fn weighted_entropy_sum(account: &EntropyAccount, weights: &EntropyWeights) -> f64 {
-    account.network * weights.network_weight
-        + account.compute * weights.compute_weight
-        + account.storage * weights.storage_weight
-        + account.temporal * weights.temporal_weight
+    let terms = [
+        account.network * weights.network_weight,
+        account.compute * weights.compute_weight,
+        account.storage * weights.storage_weight,
+        account.temporal * weights.temporal_weight,
+    ];
+    match weights.max_contribution {
+        Some(fraction) if fraction > 0.0 && fraction < 1.0 => {
+            let declines: f64 = terms.iter().filter(|term| **term < 0.0).sum();
+            let mut rising: Vec<f64> = terms.into_iter().filter(|term| *term > 0.0).collect();
+            rising.sort_by(|a, b| b.total_cmp(a));
+
+            // With the `capped` largest terms at fraction × T and the rest
+            // untouched, T = rest / (1 - capped × fraction)
+            let mut rest: f64 = rising.iter().sum();
+            for (capped, term) in rising.iter().enumerate() {
+                let share = 1.0 - capped as f64 * fraction;
+                if share > 0.0 && *term <= fraction * rest / share {
+                    return rest / share + declines;
+                }
+                rest -= term;
+            }
+            rising
+                .last()
+                .map_or(0.0, |least| least * rising.len() as f64)
+                + declines
+        }
+        _ => terms.iter().sum(),
+    }
 }
 
 /// Calculate price multiplier based on total entropy.
@@ -136,10 +169,22 @@ pub fn entropy_price_multiplier(account: &EntropyAccount) -> f64 {
     multiplier.min(MAX_ENTROPY_MULTIPLIER)
 }
 
+/// Entropy weights tagged with the version they were installed as
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct VersionedWeights {
+    pub version: u64,
+    pub weights: EntropyWeights,
+}
+
 /// Standard entropy calculator implementation
+///
+/// Weights can be replaced at runtime with [`Self::swap_weights`] or
+/// [`Self::set_weights`]; each replacement bumps a version so quotes can
+/// record which weights produced them.
 #[derive(Debug, Clone, Default)]
 pub struct EntropyCalculator {
-    pub weights: EntropyWeights,
+    weights: EntropyWeights,
+    version: u64,
 }
 
 impl EntropyCalculator {
@@ -147,8 +192,54 @@ impl EntropyCalculator {
         Self::default()
     }
 
+    /// Use custom weights without validation; see [`Self::try_with_weights`]
     pub fn with_weights(weights: EntropyWeights) -> Self {
-        Self { weights }
+        Self {
+            weights,
+            version: 0,
+        }
+    }
+
+    /// Use custom weights, rejecting any that don't sum to 1.0
+    pub fn try_with_weights(weights: EntropyWeights) -> EnrResult<Self> {
+        if !weights.is_valid() {
+            return Err(EnrError::InvalidEntropyWeights);
+        }
+        Ok(Self::with_weights(weights))
+    }
+
+    /// Current weights and their version
+    pub fn versioned_weights(&self) -> VersionedWeights {
+        VersionedWeights {
+            version: self.version,
+            weights: self.weights,
+        }
+    }
+
+    /// Current weights
+    pub fn weights(&self) -> EntropyWeights {
+        self.weights
+    }
+
+    pub fn weights_version(&self) -> u64 {
+        self.version
+    }
+
+    /// Replace the weights, returning the new version
+    ///
+    /// Invalid weights are rejected and leave the current ones in place.
+    pub fn swap_weights(&mut self, weights: EntropyWeights) -> EnrResult<u64> {
+        if !weights.is_valid() {
+            return Err(EnrError::InvalidEntropyWeights);
+        }
+        Ok(self.set_weights(weights))
+    }
+
+    /// Replace the weights without validation, returning the new version
+    pub fn set_weights(&mut self, weights: EntropyWeights) -> u64 {
+        self.weights = weights;
+        self.version += 1;
+        self.version
     }
 
     /// Calculate entropy for all components
@@ -169,7 +260,12 @@ impl EntropyCalculator {
 
     /// Calculate weighted sum
     pub fn weighted_sum(&self, account: &EntropyAccount) -> f64 {
-        weighted_entropy_sum(account, &self.weights)
+        self.weighted_sum_versioned(account).0
+    }
+
+    /// Weighted sum with the version of the weights it used
+    pub fn weighted_sum_versioned(&self, account: &EntropyAccount) -> (f64, u64) {
+        (weighted_entropy_sum(account, &self.weights), self.version)
     }
 
     /// Calculate price multiplier
@@ -301,4 +397,120 @@ mod tests {
         assert_eq!(account.storage, 0.0);
         assert_eq!(account.temporal, 0.0);
     }
+
+    #[test]
+    fn test_contribution_cap_limits_saturated_dimension() {
+        let account = EntropyAccount {
+            network: 1.0,
+            compute: 1.0,
+            storage: 1.0,
+            temporal: 10.0,
+        };
+        // 0.3 + 0.3 + 0.2 + 2.0
+        let uncapped = EntropyWeights::default();
+        assert!((weighted_entropy_sum(&account, &uncapped) - 2.8).abs() < 1e-9);
+
+        // Temporal capped at half of the final total: 0.8 of 1.6
+        let capped = uncapped.with_max_contribution(0.5);
+        assert!(capped.is_valid());
+        assert!((weighted_entropy_sum(&account, &capped) - 1.6).abs() < 1e-9);
+
+        // At 30% the uncapped 0.8 is the other 70%: temporal is 0.3 × 8/7
+        let tight = uncapped.with_max_contribution(0.3);
+        assert!((weighted_entropy_sum(&account, &tight) - 8.0 / 7.0).abs() < 1e-9);
+
+        // At a quarter every term is lowered to the smallest, 0.2
+        let quarter = uncapped.with_max_contribution(0.25);
+        assert!((weighted_entropy_sum(&account, &quarter) - 0.8).abs() < 1e-9);
+
+        // Balanced accounts are unaffected
+        let calm = EntropyAccount {
+            network: 1.0,
+            compute: 1.0,
+            storage: 1.0,
+            temporal: 1.0,
+        };
+        assert_eq!(
+            weighted_entropy_sum(&calm, &capped),
+            weighted_entropy_sum(&calm, &uncapped)
+        );
+        assert!(!uncapped.with_max_contribution(0.0).is_valid());
+    }
+
+    #[test]
+    fn test_contribution_cap_ignores_declines() {
+        // A falling delta: the cap must not inflate the decline
+        let falling = EntropyAccount {
+            network: 1.0,
+            compute: 1.0,
+            storage: 1.0,
+            temporal: -10.0,
+        };
+        let uncapped = EntropyWeights::default();
+        let capped = uncapped.with_max_contribution(0.5);
+        assert!((weighted_entropy_sum(&falling, &uncapped) + 1.2).abs() < 1e-9);
+        assert!((weighted_entropy_sum(&falling, &capped) + 1.2).abs() < 1e-9);
+
+        // Rising terms are still capped among themselves: network's 3.0
+        // drops to 0.5 of 1.0, and the decline is added back unchanged
+        let mixed = EntropyAccount {
+            network: 10.0,
+            ..falling
+        };
+        assert!((weighted_entropy_sum(&mixed, &capped) + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_try_with_weights() {
+        assert!(EntropyCalculator::try_with_weights(EntropyWeights::default()).is_ok());
+
+        let skewed = EntropyWeights {
+            network_weight: 0.5,
+            ..EntropyWeights::default()
+        };
+        assert!(matches!(
+            EntropyCalculator::try_with_weights(skewed),
+            Err(EnrError::InvalidEntropyWeights)
+        ));
+    }
+
+    #[test]
+    fn test_swap_weights() {
+        let mut calc = EntropyCalculator::new();
+        let snapshot = calc.clone();
+        assert_eq!(calc.weights_version(), 0);
+
+        let network_heavy = EntropyWeights {
+            network_weight: 0.7,
+            compute_weight: 0.1,
+            storage_weight: 0.1,
+            temporal_weight: 0.1,
+            max_contribution: None,
+        };
+        assert_eq!(calc.swap_weights(network_heavy).unwrap(), 1);
+        assert_eq!(calc.weights(), network_heavy);
+
+        // Clones are independent
+        assert_eq!(snapshot.weights(), EntropyWeights::default());
+        assert_eq!(snapshot.weights_version(), 0);
+
+        let account = EntropyAccount {
+            network: 1.0,
+            ..EntropyAccount::zero()
+        };
+        let (sum, version) = calc.weighted_sum_versioned(&account);
+        assert!((sum - 0.7).abs() < 1e-9);
+        assert_eq!(version, 1);
+
+        let skewed = EntropyWeights {
+            network_weight: 0.9,
+            ..network_heavy
+        };
+        assert!(calc.swap_weights(skewed).is_err());
+        assert_eq!(calc.versioned_weights().version, 1);
+
+        // Unvalidated replacement still bumps the version
+        assert_eq!(calc.set_weights(skewed), 2);
+        assert_eq!(calc.versioned_weights().weights, skewed);
+    }
 }
@@ -0,0 +1,226 @@
+//! Multiplier Curves
+//!
+//! Configurable entropy → price multiplier curves
+
+use super::calculator::{weighted_entropy_sum, MAX_ENTROPY_MULTIPLIER};
+use super::types::*;
+use serde::{Deserialize, Serialize};
+
+/// Cap that scales with a demand signal instead of staying fixed
+///
+/// effective_cap = base_cap * (1 + demand_factor * demand)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DynamicCap {
+    pub base_cap: f64,
+    pub demand_factor: f64,
+}
+
+impl DynamicCap {
+    /// Cap for a demand signal (negative or NaN demand counts as zero)
+    pub fn at(&self, demand: f64) -> f64 {
+        let demand = if demand.is_nan() {
+            0.0
+        } else {
+            demand.max(0.0)
+        };
+        self.base_cap * (1.0 + self.demand_factor * demand)
+    }
+}
+
+/// Piecewise-linear curve mapping total entropy to a price multiplier
+///
+/// Between breakpoints the multiplier is interpolated linearly; past the
+/// last breakpoint the final segment's slope continues. The result is
+/// clamped to `cap`, or to the dynamic cap when one is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiplierCurve {
+    /// `(entropy, multiplier)` breakpoints, sorted by entropy
+    pub points: Vec<(f64, f64)>,
+    /// Static ceiling on the multiplier
+    pub cap: f64,
+    /// Demand-scaled ceiling, replacing `cap` when set
+    pub dynamic_cap: Option<DynamicCap>,
+}
+
+impl Default for MultiplierCurve {
+    /// The dol/entropy.dol curve used by `entropy_price_multiplier`
+    fn default() -> Self {
+        Self {
+            points: vec![(0.0, 1.0), (2.0, 1.1), (5.0, 1.4), (8.0, 2.0), (10.0, 5.0)],
+            cap: MAX_ENTROPY_MULTIPLIER,
+            dynamic_cap: None,
+        }
+    }
+}
+
+impl MultiplierCurve {
+    /// Curve through the given breakpoints with a static cap
+    pub fn new(mut points: Vec<(f64, f64)>, cap: f64) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            points,
+            cap,
+            dynamic_cap: None,
+        }
+    }
+
+    /// Let the cap float with demand; see [`DynamicCap`]
+    pub fn with_dynamic_cap(mut self, base_cap: f64, demand_factor: f64) -> Self {
+        self.dynamic_cap = Some(DynamicCap {
+            base_cap,
+            demand_factor,
+        });
+        self
+    }
+
+    /// Cap in effect for a demand signal
+    pub fn effective_cap(&self, demand: f64) -> f64 {
+        match self.dynamic_cap {
+            Some(dynamic) => dynamic.at(demand),
+            None => self.cap,
+        }
+    }
+
+    /// Multiplier for a total entropy, before any cap
+    fn uncapped(&self, total: f64) -> f64 {
+        match self.points.as_slice() {
+            [] => 1.0,
+            [(_, m)] => *m,
+            points => {
+                let segment = points
+                    .windows(2)
+                    .find(|w| total < w[1].0)
+                    .unwrap_or(&points[points.len() - 2..]);
+                let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+                if x1 == x0 {
+                    return y1;
+                }
+                y0 + (total.max(points[0].0) - x0) * (y1 - y0) / (x1 - x0)
+            }
+        }
+    }
+
+    /// Multiplier for a total entropy under the static cap (zero demand)
+    pub fn evaluate(&self, total: f64) -> f64 {
+        self.evaluate_with_demand(total, 0.0)
+    }
+
+    /// Multiplier for a total entropy, clamped to the cap for `demand`
+    pub fn evaluate_with_demand(&self, total: f64, demand: f64) -> f64 {
+        self.uncapped(total).min(self.effective_cap(demand))
+    }
+
+    /// Lowest total entropy at which [`Self::evaluate`] yields `multiplier`
+    ///
+    /// Inverts the first segment containing `multiplier`, or the
+    /// extrapolated final segment if it is rising. Returns `None` when
+    /// the multiplier is above the effective cap or never reached.
+    pub fn invert(&self, multiplier: f64) -> Option<f64> {
+        self.invert_with_demand(multiplier, 0.0)
+    }
+
+    /// Inverse of [`Self::evaluate_with_demand`]; see [`Self::invert`]
+    pub fn invert_with_demand(&self, multiplier: f64, demand: f64) -> Option<f64> {
+        if multiplier.is_nan() || multiplier > self.effective_cap(demand) {
+            return None;
+        }
+        match self.points.as_slice() {
+            [] => (multiplier == 1.0).then_some(0.0),
+            [(x, m)] => (multiplier == *m).then_some(*x),
+            points => {
+                let within = points.windows(2).find(|w| {
+                    let (lo, hi) = (w[0].1.min(w[1].1), w[0].1.max(w[1].1));
+                    (lo..=hi).contains(&multiplier)
+                });
+                let ((x0, y0), (x1, y1)) = match within {
+                    Some(w) => (w[0], w[1]),
+                    None => {
+                        let (a, b) = (points[points.len() - 2], points[points.len() - 1]);
+                        if b.1 <= a.1 || multiplier < b.1 {
+                            return None;
+                        }
+                        (a, b)
+                    }
+                };
+                if y1 == y0 {
+                    return Some(x0);
+                }
+                Some(x0 + (multiplier - y0) * (x1 - x0) / (y1 - y0))
+            }
+        }
+    }
+
+    /// Multiplier for an entropy account using default weights
+    pub fn evaluate_account(&self, account: &EntropyAccount) -> f64 {
+        self.evaluate(weighted_entropy_sum(account, &EntropyWeights::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entropy::entropy_price_multiplier;
+
+    #[test]
+    fn test_default_curve_matches_spec() {
+        let curve = MultiplierCurve::default();
+        for level in [0.0, 1.0, 3.0, 6.5, 9.0, 10.0] {
+            let account = EntropyAccount {
+                network: level,
+                compute: level,
+                storage: level,
+                temporal: level,
+            };
+            assert!(
+                (curve.evaluate_account(&account) - entropy_price_multiplier(&account)).abs()
+                    < 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_invert() {
+        let curve = MultiplierCurve::default();
+        for total in [0.0, 1.0, 3.5, 6.0, 9.0] {
+            let inverted = curve.invert(curve.evaluate(total)).unwrap();
+            assert!((inverted - total).abs() < 1e-9);
+        }
+        assert_eq!(curve.invert(0.5), None);
+        assert_eq!(curve.invert(5.5), None);
+
+        // Past the last breakpoint, up to the cap
+        let curve = MultiplierCurve::new(vec![(0.0, 1.0), (1.0, 2.0)], 4.0);
+        assert_eq!(curve.invert(3.0), Some(2.0));
+    }
+
+    #[test]
+    fn test_dynamic_cap() {
+        let curve = MultiplierCurve::new(vec![(0.0, 1.0), (10.0, 11.0)], 5.0);
+        assert_eq!(curve.evaluate(10.0), 5.0);
+
+        // base 5x, doubling at full demand
+        let curve = curve.with_dynamic_cap(5.0, 1.0);
+        assert_eq!(curve.evaluate_with_demand(10.0, 0.0), 5.0);
+        assert_eq!(curve.evaluate_with_demand(10.0, 1.0), 10.0);
+        assert_eq!(curve.evaluate_with_demand(4.0, 1.0), 5.0);
+        assert_eq!(curve.effective_cap(-1.0), 5.0);
+    }
+
+    #[test]
+    fn test_invert_respects_dynamic_cap() {
+        // The dynamic cap replaces the static 5x
+        let curve =
+            MultiplierCurve::new(vec![(0.0, 1.0), (10.0, 11.0)], 5.0).with_dynamic_cap(8.0, 1.0);
+        for total in [2.0, 6.0, 7.0] {
+            assert_eq!(curve.invert(curve.evaluate(total)), Some(total));
+        }
+        assert_eq!(curve.invert(9.0), None);
+
+        // Full demand doubles the cap to 16x
+        assert_eq!(
+            curve.invert_with_demand(curve.evaluate_with_demand(10.0, 1.0), 1.0),
+            Some(10.0)
+        );
+        assert_eq!(curve.invert_with_demand(16.5, 1.0), None);
+    }
+}
@@ -10,8 +10,16 @@
 //!
 //! Total entropy: S_total = wₙ·Sₙ + wᶜ·Sᶜ + wˢ·Sˢ + wᵗ·Sᵗ
 
+pub mod budget;
 pub mod calculator;
+pub mod curve;
+pub mod sampler;
+pub mod snapshot;
 pub mod types;
 
+pub use budget::*;
 pub use calculator::*;
+pub use curve::*;
+pub use sampler::*;
+pub use snapshot::*;
 pub use types::*;
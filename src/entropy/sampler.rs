@@ -0,0 +1,166 @@
+//! Network Entropy Sampling
+//!
+//! Sliding-window statistics that produce a `NetworkEntropyInput` from raw
+//! latency, loss, and saturation observations
+
+use super::types::*;
+use std::collections::VecDeque;
+
+/// Default number of samples kept per window
+pub const DEFAULT_SAMPLE_WINDOW: usize = 64;
+
+/// Tracks recent network observations over a fixed-size sliding window
+///
+/// Each series keeps its last `window` samples; older samples fall out as
+/// new ones arrive.
+#[derive(Debug, Clone)]
+pub struct NetworkEntropySampler {
+    window: usize,
+    hops: u32,
+    latencies: VecDeque<f64>,
+    /// `true` for a delivered probe, `false` for a lost one
+    deliveries: VecDeque<bool>,
+    saturation: VecDeque<f64>,
+}
+
+impl Default for NetworkEntropySampler {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLE_WINDOW)
+    }
+}
+
+impl NetworkEntropySampler {
+    /// Sampler keeping the last `window` samples (at least one)
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            hops: 0,
+            latencies: VecDeque::with_capacity(window),
+            deliveries: VecDeque::with_capacity(window),
+            saturation: VecDeque::with_capacity(window),
+        }
+    }
+
+    pub fn with_hops(mut self, hops: u32) -> Self {
+        self.hops = hops;
+        self
+    }
+
+    pub fn set_hops(&mut self, hops: u32) {
+        self.hops = hops;
+    }
+
+    fn push<T>(series: &mut VecDeque<T>, window: usize, value: T) {
+        if series.len() == window {
+            series.pop_front();
+        }
+        series.push_back(value);
+    }
+
+    /// Record a delivered probe and its round-trip latency
+    pub fn record_latency(&mut self, ms: f64) {
+        if ms.is_finite() && ms >= 0.0 {
+            Self::push(&mut self.latencies, self.window, ms);
+        }
+        Self::push(&mut self.deliveries, self.window, true);
+    }
+
+    /// Record a probe that was never answered
+    pub fn record_loss(&mut self) {
+        Self::push(&mut self.deliveries, self.window, false);
+    }
+
+    /// Record an observed bandwidth utilization [0, 1]
+    pub fn record_saturation(&mut self, utilization: f64) {
+        if !utilization.is_nan() {
+            Self::push(
+                &mut self.saturation,
+                self.window,
+                utilization.clamp(0.0, 1.0),
+            );
+        }
+    }
+
+    /// Number of latency samples in the window
+    pub fn sample_count(&self) -> usize {
+        self.latencies.len()
+    }
+
+    /// Population variance of windowed latencies (0 with fewer than two)
+    pub fn latency_variance_ms(&self) -> f64 {
+        let n = self.latencies.len();
+        if n < 2 {
+            return 0.0;
+        }
+        let mean = self.latencies.iter().sum::<f64>() / n as f64;
+        self.latencies
+            .iter()
+            .map(|l| (l - mean).powi(2))
+            .sum::<f64>()
+            / n as f64
+    }
+
+    /// Fraction of windowed probes that were lost
+    pub fn packet_loss_probability(&self) -> f64 {
+        if self.deliveries.is_empty() {
+            return 0.0;
+        }
+        let lost = self.deliveries.iter().filter(|d| !**d).count();
+        lost as f64 / self.deliveries.len() as f64
+    }
+
+    /// Mean windowed bandwidth utilization
+    pub fn bandwidth_saturation(&self) -> f64 {
+        if self.saturation.is_empty() {
+            return 0.0;
+        }
+        self.saturation.iter().sum::<f64>() / self.saturation.len() as f64
+    }
+
+    /// Current window as input to `calculate_network_entropy`
+    pub fn input(&self) -> NetworkEntropyInput {
+        NetworkEntropyInput {
+            hops: self.hops,
+            latency_variance_ms: self.latency_variance_ms(),
+            packet_loss_probability: self.packet_loss_probability(),
+            bandwidth_saturation: self.bandwidth_saturation(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_window_statistics() {
+        let mut sampler = NetworkEntropySampler::new(4).with_hops(3);
+        for ms in [10.0, 20.0, 10.0, 20.0] {
+            sampler.record_latency(ms);
+        }
+        sampler.record_saturation(0.5);
+        sampler.record_saturation(1.0);
+
+        let input = sampler.input();
+        assert_eq!(input.hops, 3);
+        assert_eq!(input.latency_variance_ms, 25.0);
+        assert_eq!(input.packet_loss_probability, 0.0);
+        assert_eq!(input.bandwidth_saturation, 0.75);
+        assert!(input.is_valid());
+
+        // One loss pushes the oldest delivery out of the window
+        sampler.record_loss();
+        assert_eq!(sampler.packet_loss_probability(), 0.25);
+    }
+
+    #[test]
+    fn test_sampler_slides() {
+        let mut sampler = NetworkEntropySampler::new(2);
+        sampler.record_latency(100.0);
+        sampler.record_latency(5.0);
+        sampler.record_latency(5.0);
+        assert_eq!(sampler.sample_count(), 2);
+        assert_eq!(sampler.latency_variance_ms(), 0.0);
+    }
+}
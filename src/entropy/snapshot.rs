@@ -0,0 +1,180 @@
+//! Entropy Snapshots
+//!
+//! Point-in-time entropy captures and the change between two of them
+
+use super::calculator::weighted_entropy_sum;
+use super::types::*;
+use crate::core::{Duration, Timestamp};
+use serde::{Deserialize, Serialize};
+
+/// An entropy account as observed at a point in time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropySnapshot {
+    pub account: EntropyAccount,
+    pub timestamp: Timestamp,
+}
+
+/// Change in entropy between two snapshots
+///
+/// Components of `change` and `rate_per_sec` are signed: positive means
+/// entropy rose.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropyDelta {
+    /// Per-component change, later minus earlier
+    pub change: EntropyAccount,
+    /// Per-component change per second (zero if no time elapsed)
+    pub rate_per_sec: EntropyAccount,
+    pub elapsed: Duration,
+}
+
+impl EntropySnapshot {
+    pub fn new(account: EntropyAccount, timestamp: Timestamp) -> Self {
+        Self { account, timestamp }
+    }
+
+    /// Snapshot of `account` taken now
+    pub fn now(account: EntropyAccount) -> Self {
+        Self::new(account, Timestamp::now())
+    }
+
+    /// Change from the earlier of the two snapshots to the later
+    pub fn diff(&self, other: &EntropySnapshot) -> EntropyDelta {
+        let (earlier, later) = if other.timestamp >= self.timestamp {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let elapsed = Duration::new(later.timestamp.millis - earlier.timestamp.millis);
+        let change = EntropyAccount {
+            network: later.account.network - earlier.account.network,
+            compute: later.account.compute - earlier.account.compute,
+            storage: later.account.storage - earlier.account.storage,
+            temporal: later.account.temporal - earlier.account.temporal,
+        };
+
+        let secs = elapsed.millis as f64 / 1000.0;
+        let rate_per_sec = if secs > 0.0 {
+            EntropyAccount {
+                network: change.network / secs,
+                compute: change.compute / secs,
+                storage: change.storage / secs,
+                temporal: change.temporal / secs,
+            }
+        } else {
+            EntropyAccount::zero()
+        };
+
+        EntropyDelta {
+            change,
+            rate_per_sec,
+            elapsed,
+        }
+    }
+}
+
+impl EntropyDelta {
+    /// Weighted change in total entropy
+    pub fn total_change(&self, weights: &EntropyWeights) -> f64 {
+        weighted_entropy_sum(&self.change, weights)
+    }
+
+    /// Weighted rate of change in total entropy, per second
+    pub fn total_rate_per_sec(&self, weights: &EntropyWeights) -> f64 {
+        weighted_entropy_sum(&self.rate_per_sec, weights)
+    }
+
+    /// Seconds until total entropy under `weights` reaches `ceiling` at the
+    /// current rate, starting from `current`
+    ///
+    /// Returns `None` if entropy isn't rising; `Some(0.0)` if already there.
+    pub fn time_to_ceiling(
+        &self,
+        current: &EntropyAccount,
+        ceiling: f64,
+        weights: &EntropyWeights,
+    ) -> Option<f64> {
+        let remaining = ceiling - weighted_entropy_sum(current, weights);
+        if remaining <= 0.0 {
+            return Some(0.0);
+        }
+        let rate = self.total_rate_per_sec(weights);
+        (rate > 0.0).then(|| remaining / rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_diff() {
+        let before = EntropySnapshot::new(
+            EntropyAccount {
+                network: 1.0,
+                compute: 2.0,
+                storage: 3.0,
+                temporal: 4.0,
+            },
+            Timestamp::new(10_000),
+        );
+        let after = EntropySnapshot::new(
+            EntropyAccount {
+                network: 3.0,
+                compute: 2.0,
+                storage: 2.0,
+                temporal: 4.0,
+            },
+            Timestamp::new(12_000),
+        );
+
+        let delta = before.diff(&after);
+        assert_eq!(delta, after.diff(&before));
+        assert_eq!(delta.elapsed, Duration::seconds(2));
+        assert_eq!(delta.change.network, 2.0);
+        assert_eq!(delta.change.storage, -1.0);
+        assert_eq!(delta.rate_per_sec.network, 1.0);
+        assert_eq!(delta.rate_per_sec.storage, -0.5);
+
+        // 0.3 * 2.0 - 0.2 * 1.0
+        let weights = EntropyWeights::default();
+        assert!((delta.total_change(&weights) - 0.4).abs() < 1e-9);
+        assert!((delta.total_rate_per_sec(&weights) - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_to_ceiling() {
+        let calm = EntropySnapshot::new(EntropyAccount::zero(), Timestamp::new(0));
+        let rising = EntropySnapshot::new(
+            EntropyAccount {
+                network: 1.0,
+                compute: 1.0,
+                storage: 1.0,
+                temporal: 1.0,
+            },
+            Timestamp::new(1_000),
+        );
+
+        let weights = EntropyWeights::default();
+        let delta = calm.diff(&rising);
+        assert_eq!(
+            delta.time_to_ceiling(&rising.account, 5.0, &weights),
+            Some(4.0)
+        );
+        assert_eq!(
+            delta.time_to_ceiling(&rising.account, 0.5, &weights),
+            Some(0.0)
+        );
+        assert_eq!(
+            calm.diff(&calm)
+                .time_to_ceiling(&calm.account, 5.0, &weights),
+            None
+        );
+
+        // Capping each dimension at a quarter slows the climb to 0.8/s from 0.8
+        let capped = weights.with_max_contribution(0.25);
+        let eta = delta
+            .time_to_ceiling(&rising.account, 5.0, &capped)
+            .unwrap();
+        assert!((eta - 5.25).abs() < 1e-9);
+    }
+}
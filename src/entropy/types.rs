@@ -57,6 +57,10 @@ pub struct EntropyWeights {
     pub compute_weight: f64,
     pub storage_weight: f64,
     pub temporal_weight: f64,
+    /// Largest fraction (0, 1] of the weighted total any one dimension may
+    /// contribute; `None` leaves contributions uncapped
+    #[serde(default)]
+    pub max_contribution: Option<f64>,
 }
 
 impl Default for EntropyWeights {
@@ -66,6 +70,7 @@ impl Default for EntropyWeights {
             compute_weight: 0.3,
             storage_weight: 0.2,
             temporal_weight: 0.2,
+            max_contribution: None,
         }
     }
 }
@@ -76,6 +81,15 @@ impl EntropyWeights {
         let sum =
             self.network_weight + self.compute_weight + self.storage_weight + self.temporal_weight;
         (sum - 1.0).abs() < 0.001
+            && self
+                .max_contribution
+                .is_none_or(|cap| cap > 0.0 && cap <= 1.0)
+    }
+
+    /// Cap each dimension's contribution at `fraction` of the total
+    pub fn with_max_contribution(mut self, fraction: f64) -> Self {
+        self.max_contribution = Some(fraction);
+        self
     }
 
     pub fn new(network: f64, compute: f64, storage: f64, temporal: f64) -> Option<Self> {
@@ -84,6 +98,7 @@ impl EntropyWeights {
             compute_weight: compute,
             storage_weight: storage,
             temporal_weight: temporal,
+            max_contribution: None,
         };
         if weights.is_valid() {
             Some(weights)
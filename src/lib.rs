@@ -11,7 +11,7 @@
 //!
 //! ## Modules
 //!
-//! - [`core`]: Credits, NodeId, state machine, invariants
+//! - [`core`]: Credits, NodeId, state machine, invariants, reputation, ledgers
 //! - [`entropy`]: Four entropy types and price multiplier
 //! - [`nexus`]: Topology, election, market making
 //! - [`revival`]: Decomposition and redistribution
@@ -52,9 +52,20 @@
 //! let multiplier = calculator.price_multiplier(&account);
 //!
 //! // Price is adjusted by entropy
-//! let adjusted_price = Credits::new((credits.amount as f64 * multiplier) as u64);
+//! let adjusted_price = Credits::from_f64_saturating(credits.amount as f64 * multiplier);
 //! ```
 
+// Existing test assertions predate these lints
+#![cfg_attr(
+    test,
+    allow(
+        clippy::manual_range_contains,
+        clippy::cloned_ref_to_slice_refs,
+        clippy::await_holding_lock,
+        clippy::field_reassign_with_default
+    )
+)]
+
 pub mod core;
 pub mod entropy;
 pub mod nexus;
@@ -1,8 +1,13 @@
 //! Gradient Aggregation
 //!
-//! Implements gradient aggregation from dol/nexus.dol lines 175-223
+//! Implements gradient aggregation from dol/nexus.dol lines 175-223, plus
+//! topology-weighted aggregation of entropy
 
+use super::topology::TopologyManager;
 use super::types::*;
+use crate::core::NodeId;
+use crate::entropy::{weighted_entropy_sum, EntropyAccount, EntropyWeights};
+use std::collections::HashMap;
 
 /// Aggregate gradients from leaf nodes using weighted average.
 ///
@@ -63,10 +68,145 @@ pub fn aggregate_gradients(reports: &[LeafGradientReport]) -> ResourceGradient {
     }
 }
 
+/// Learns per-leaf aggregation weights from prediction error
+///
+/// Each observation compares a leaf's reported gradient with the
+/// availability later measured for it. Weights follow a multiplicative
+/// (exponentiated-gradient) update, `w *= exp(-learning_rate * error)`,
+/// then are rescaled to average 1.0 so accurate leaves gain weight at the
+/// expense of inaccurate ones. Unknown leaves weigh 1.0.
+#[derive(Debug, Clone)]
+pub struct GradientWeightTuner {
+    weights: HashMap<NodeId, f64>,
+    pub learning_rate: f64,
+    /// Floor keeping every leaf in the aggregate
+    pub min_weight: f64,
+}
+
+impl Default for GradientWeightTuner {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl GradientWeightTuner {
+    pub fn new(learning_rate: f64) -> Self {
+        Self {
+            weights: HashMap::new(),
+            learning_rate,
+            min_weight: 0.05,
+        }
+    }
+
+    /// Mean absolute error across the five availability dimensions
+    fn prediction_error(predicted: &ResourceGradient, actual: &ResourceGradient) -> f64 {
+        ((predicted.cpu_available - actual.cpu_available).abs()
+            + (predicted.memory_available - actual.memory_available).abs()
+            + (predicted.gpu_available - actual.gpu_available).abs()
+            + (predicted.storage_available - actual.storage_available).abs()
+            + (predicted.bandwidth_available - actual.bandwidth_available).abs())
+            / 5.0
+    }
+
+    /// Record a (predicted, actual) pair for a leaf; returns its error
+    pub fn observe(
+        &mut self,
+        node: NodeId,
+        predicted: &ResourceGradient,
+        actual: &ResourceGradient,
+    ) -> f64 {
+        let error = Self::prediction_error(predicted, actual);
+        let weight = self.weights.entry(node).or_insert(1.0);
+        *weight *= (-self.learning_rate * error).exp();
+
+        let mean = self.weights.values().sum::<f64>() / self.weights.len() as f64;
+        if mean > 0.0 {
+            for w in self.weights.values_mut() {
+                *w = (*w / mean).max(self.min_weight);
+            }
+        }
+        error
+    }
+
+    /// Tuned weight for a leaf
+    pub fn weight(&self, node: &NodeId) -> f64 {
+        self.weights.get(node).copied().unwrap_or(1.0)
+    }
+
+    pub fn weights(&self) -> &HashMap<NodeId, f64> {
+        &self.weights
+    }
+
+    /// Scale each report's weight by its leaf's tuned weight
+    pub fn apply(&self, reports: &mut [LeafGradientReport]) {
+        for report in reports {
+            report.weight *= self.weight(&report.node);
+        }
+    }
+
+    /// Aggregate reports using tuned weights
+    pub fn aggregate(&self, reports: &[LeafGradientReport]) -> ResourceGradient {
+        let mut tuned = reports.to_vec();
+        self.apply(&mut tuned);
+        aggregate_gradients(&tuned)
+    }
+}
+
+/// How much traffic a node's entropy affects, by its role
+///
+/// A leaf (or unknown node) counts once; a nexus counts once plus once per
+/// leaf it serves; a poteau-mitan counts once plus the importance of each
+/// nexus under it.
+pub fn node_importance(topology: &TopologyManager, node: &NodeId) -> f64 {
+    let Some(topo) = topology.get_topology(node) else {
+        return 1.0;
+    };
+    match topo.role.role_type {
+        NexusRoleType::Leaf => 1.0,
+        NexusRoleType::Nexus => 1.0 + topo.leaf_count as f64,
+        NexusRoleType::PoteauMitan => {
+            1.0 + topo
+                .role
+                .children
+                .iter()
+                .filter_map(|child| topology.get_topology(child))
+                .filter(|child| child.role.is_nexus())
+                .map(|child| 1.0 + child.leaf_count as f64)
+                .sum::<f64>()
+        }
+    }
+}
+
+/// Network-wide entropy, weighting each node by [`node_importance`]
+///
+/// Formula: Σ(importance_i * S_total_i) / Σ(importance_i), where S_total
+/// is each account's weighted entropy sum. Returns 0 for no accounts.
+pub fn aggregate_entropy_weighted(
+    accounts: &[(NodeId, EntropyAccount)],
+    topology: &TopologyManager,
+    weights: &EntropyWeights,
+) -> f64 {
+    let (weighted, total_importance) =
+        accounts
+            .iter()
+            .fold((0.0, 0.0), |(weighted, total), (node, account)| {
+                let importance = node_importance(topology, node);
+                (
+                    weighted + importance * weighted_entropy_sum(account, weights),
+                    total + importance,
+                )
+            });
+    if total_importance == 0.0 {
+        return 0.0;
+    }
+    weighted / total_importance
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{NodeId, Timestamp};
+    use crate::core::Timestamp;
+    use crate::nexus::topology::test_topology;
 
     #[test]
     fn test_aggregate_empty() {
@@ -136,4 +276,85 @@ mod tests {
         assert!((result.memory_available - 0.75).abs() < 0.001);
         assert!((result.credit_balance - 75.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_weight_tuner_favors_accurate_leaves() {
+        let honest = NodeId::from_bytes([1u8; 32]);
+        let noisy = NodeId::from_bytes([2u8; 32]);
+        let actual = ResourceGradient {
+            cpu_available: 0.5,
+            memory_available: 0.5,
+            gpu_available: 0.5,
+            storage_available: 0.5,
+            bandwidth_available: 0.5,
+            credit_balance: 0.0,
+        };
+        let inflated = ResourceGradient {
+            cpu_available: 1.0,
+            memory_available: 1.0,
+            gpu_available: 1.0,
+            storage_available: 1.0,
+            bandwidth_available: 1.0,
+            credit_balance: 0.0,
+        };
+
+        let mut tuner = GradientWeightTuner::default();
+        for _ in 0..10 {
+            assert_eq!(tuner.observe(honest, &actual, &actual), 0.0);
+            assert!((tuner.observe(noisy, &inflated, &actual) - 0.5).abs() < 1e-9);
+        }
+        assert!(tuner.weight(&honest) > tuner.weight(&noisy));
+        assert!(tuner.weight(&noisy) >= tuner.min_weight);
+
+        // The tuned aggregate lands closer to the truth than equal weighting
+        let reports =
+            [(honest, actual), (noisy, inflated)].map(|(node, gradient)| LeafGradientReport {
+                node,
+                gradient,
+                weight: 1.0,
+                timestamp: Timestamp::now(),
+            });
+        let equal = aggregate_gradients(&reports).cpu_available;
+        let tuned = tuner.aggregate(&reports).cpu_available;
+        assert!((tuned - 0.5).abs() < (equal - 0.5).abs());
+    }
+
+    #[test]
+    fn test_aggregate_entropy_weighted() {
+        let mut topology = TopologyManager::new();
+        let poteau = NodeId::from_bytes([9u8; 32]);
+        let nexus = NodeId::from_bytes([1u8; 32]);
+        let leaf = NodeId::from_bytes([2u8; 32]);
+
+        topology.set_topology(
+            poteau,
+            test_topology(poteau, NexusRole::poteau_mitan(vec![nexus])),
+        );
+        topology.set_topology(
+            nexus,
+            NexusTopology {
+                leaf_count: 3,
+                ..test_topology(nexus, NexusRole::nexus(Some(poteau), vec![leaf]))
+            },
+        );
+        topology.set_topology(leaf, test_topology(leaf, NexusRole::leaf(nexus)));
+
+        assert_eq!(node_importance(&topology, &leaf), 1.0);
+        assert_eq!(node_importance(&topology, &nexus), 4.0);
+        assert_eq!(node_importance(&topology, &poteau), 5.0);
+
+        let level = |e: f64| EntropyAccount {
+            network: e,
+            compute: e,
+            storage: e,
+            temporal: e,
+        };
+        let weights = EntropyWeights::default();
+        let accounts = [(nexus, level(6.0)), (leaf, level(1.0))];
+
+        // (4 * 6 + 1 * 1) / 5, versus a flat mean of 3.5
+        let aggregated = aggregate_entropy_weighted(&accounts, &topology, &weights);
+        assert!((aggregated - 5.0).abs() < 1e-9);
+        assert_eq!(aggregate_entropy_weighted(&[], &topology, &weights), 0.0);
+    }
 }
@@ -3,7 +3,7 @@
 //! Implements nexus election from dol/nexus.dol lines 275-338
 
 use super::types::*;
-use crate::core::NodeId;
+use crate::core::{Duration, EnrError, NodeId, Timestamp};
 
 /// Nexus eligibility thresholds - from dol/nexus.dol lines 30-32
 pub const MIN_NEXUS_UPTIME: f64 = 0.95;
@@ -14,6 +14,13 @@ pub const MIN_NEXUS_REPUTATION: f64 = 0.7;
 pub const MIN_LEAVES_PER_NEXUS: u32 = 5;
 pub const MAX_LEAVES_PER_NEXUS: u32 = 50;
 
+/// Hard ceiling on leaves for even the strongest nexus
+pub const ABSOLUTE_MAX_LEAVES_PER_NEXUS: u32 = 200;
+
+/// Bandwidth budgeted per leaf (bits/s): a nexus at `MIN_NEXUS_BANDWIDTH`
+/// carries `MAX_LEAVES_PER_NEXUS` leaves
+pub const BANDWIDTH_PER_LEAF: u64 = MIN_NEXUS_BANDWIDTH / MAX_LEAVES_PER_NEXUS as u64;
+
 /// Election weights - from dol/nexus.dol lines 39-42
 pub const UPTIME_WEIGHT: f64 = 0.3;
 pub const BANDWIDTH_WEIGHT: f64 = 0.3;
@@ -39,12 +46,234 @@ pub fn normalize_connectivity(leaf_count: u32) -> f64 {
     1.0 - (distance as f64 / max_distance)
 }
 
+/// Leaves a nexus can carry given its bandwidth and current availability
+///
+/// One leaf per `BANDWIDTH_PER_LEAF` of `bandwidth`, scaled by the mean
+/// CPU, memory, and bandwidth availability in `gradient`, then clamped to
+/// [`MIN_LEAVES_PER_NEXUS`, `ABSOLUTE_MAX_LEAVES_PER_NEXUS`].
+pub fn optimal_leaf_capacity(gradient: &ResourceGradient, bandwidth: u64) -> u32 {
+    let headroom =
+        ((gradient.cpu_available + gradient.memory_available + gradient.bandwidth_available) / 3.0)
+            .clamp(0.0, 1.0);
+    let by_bandwidth = bandwidth as f64 / BANDWIDTH_PER_LEAF as f64;
+    let capacity = (by_bandwidth * headroom).floor();
+    if capacity.is_nan() {
+        return MIN_LEAVES_PER_NEXUS;
+    }
+    (capacity.min(ABSOLUTE_MAX_LEAVES_PER_NEXUS as f64) as u32)
+        .clamp(MIN_LEAVES_PER_NEXUS, ABSOLUTE_MAX_LEAVES_PER_NEXUS)
+}
+
+/// How connectivity contributes to the election score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectivityMode {
+    /// Prefer nodes near the optimal leaf count (fills existing nexuses)
+    #[default]
+    Midpoint,
+    /// Prefer nodes with room to grow (spreads load to fresh nexuses)
+    Headroom,
+}
+
+/// Normalize connectivity to [0, 1] for scoring under the given mode.
+pub fn normalize_connectivity_with_mode(leaf_count: u32, mode: ConnectivityMode) -> f64 {
+    normalize_connectivity_for_capacity(leaf_count, MAX_LEAVES_PER_NEXUS, mode)
+}
+
+/// Normalize connectivity for a node that can carry `capacity` leaves
+///
+/// As [`normalize_connectivity_with_mode`], with `capacity` in place of
+/// `MAX_LEAVES_PER_NEXUS`.
+pub fn normalize_connectivity_for_capacity(
+    leaf_count: u32,
+    capacity: u32,
+    mode: ConnectivityMode,
+) -> f64 {
+    let capacity = capacity.max(1);
+    match mode {
+        ConnectivityMode::Midpoint => {
+            let optimal = (MIN_LEAVES_PER_NEXUS + capacity) / 2;
+            let distance = (leaf_count as i64 - optimal as i64).unsigned_abs();
+            1.0 - (distance as f64 / capacity as f64)
+        }
+        ConnectivityMode::Headroom => 1.0 - (leaf_count.min(capacity) as f64 / capacity as f64),
+    }
+}
+
 /// Calculate election score for a candidate
 pub fn calculate_election_score(candidate: &NexusCandidate) -> f64 {
+    calculate_election_score_with_mode(candidate, ConnectivityMode::Midpoint)
+}
+
+/// Calculate election score for a candidate under a connectivity mode
+pub fn calculate_election_score_with_mode(
+    candidate: &NexusCandidate,
+    mode: ConnectivityMode,
+) -> f64 {
+    calculate_election_score_for_capacity(candidate, MAX_LEAVES_PER_NEXUS, mode)
+}
+
+/// Calculate election score for a candidate that can carry `capacity` leaves
+pub fn calculate_election_score_for_capacity(
+    candidate: &NexusCandidate,
+    capacity: u32,
+    mode: ConnectivityMode,
+) -> f64 {
     candidate.uptime * UPTIME_WEIGHT
         + normalize_bandwidth(candidate.bandwidth) * BANDWIDTH_WEIGHT
         + candidate.reputation * REPUTATION_WEIGHT
-        + normalize_connectivity(candidate.current_leaf_count) * CONNECTIVITY_WEIGHT
+        + normalize_connectivity_for_capacity(candidate.current_leaf_count, capacity, mode)
+            * CONNECTIVITY_WEIGHT
+}
+
+/// Distributed election phases, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElectionPhase {
+    Announcement,
+    Candidacy,
+    Voting,
+}
+
+/// Default number of rounds before an election is abandoned
+pub const DEFAULT_MAX_ELECTION_ROUNDS: u32 = 3;
+
+/// Phase timeouts for a distributed election
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElectionConfig {
+    pub announcement_phase: Duration,
+    pub candidacy_phase: Duration,
+    pub voting_phase: Duration,
+    /// Rounds to attempt before giving up on an inconclusive election
+    pub max_rounds: u32,
+}
+
+impl Default for ElectionConfig {
+    fn default() -> Self {
+        Self {
+            announcement_phase: Duration::seconds(5),
+            candidacy_phase: Duration::seconds(15),
+            voting_phase: Duration::seconds(30),
+            max_rounds: DEFAULT_MAX_ELECTION_ROUNDS,
+        }
+    }
+}
+
+impl ElectionConfig {
+    /// Upper bound on how long an election takes
+    pub fn estimated_duration(&self) -> Duration {
+        Duration::new(
+            self.announcement_phase
+                .millis
+                .saturating_add(self.candidacy_phase.millis)
+                .saturating_add(self.voting_phase.millis),
+        )
+    }
+
+    /// Phase an election started at `started` is in at `now`
+    ///
+    /// Returns `None` once every phase has timed out.
+    pub fn phase_at(&self, started: Timestamp, now: Timestamp) -> Option<ElectionPhase> {
+        let elapsed = now.millis.saturating_sub(started.millis);
+        let mut deadline = 0u64;
+        for (phase, length) in [
+            (ElectionPhase::Announcement, self.announcement_phase),
+            (ElectionPhase::Candidacy, self.candidacy_phase),
+            (ElectionPhase::Voting, self.voting_phase),
+        ] {
+            deadline = deadline.saturating_add(length.millis);
+            if elapsed < deadline {
+                return Some(phase);
+            }
+        }
+        None
+    }
+}
+
+/// Result of polling an [`ElectionRound`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundStatus {
+    /// The current round is still running in this phase
+    InProgress(ElectionPhase),
+    /// The previous round timed out; candidacies should be re-solicited
+    /// for the new round number
+    Advanced(u32),
+    /// A result was accepted for the current round
+    Concluded,
+    /// Every round timed out without a result
+    GaveUp,
+}
+
+/// Multi-round election state machine
+///
+/// Each round runs through the phases of an [`ElectionConfig`]. A round
+/// that times out without a result advances to the next round, up to
+/// `max_rounds`, after which the election is abandoned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElectionRound {
+    config: ElectionConfig,
+    round: u32,
+    started: Timestamp,
+    concluded: bool,
+}
+
+impl ElectionRound {
+    /// Start round 1 at `started`
+    pub fn new(config: ElectionConfig, started: Timestamp) -> Self {
+        Self {
+            config,
+            round: 1,
+            started,
+            concluded: false,
+        }
+    }
+
+    /// Current round number (1-based)
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// When the current round started
+    pub fn started(&self) -> Timestamp {
+        self.started
+    }
+
+    /// Mark the election concluded (a result was accepted)
+    pub fn conclude(&mut self) {
+        self.concluded = true;
+    }
+
+    /// Advance the state machine to `now`
+    pub fn poll(&mut self, now: Timestamp) -> RoundStatus {
+        if self.concluded {
+            return RoundStatus::Concluded;
+        }
+        if let Some(phase) = self.config.phase_at(self.started, now) {
+            return RoundStatus::InProgress(phase);
+        }
+        if self.round >= self.config.max_rounds {
+            return RoundStatus::GaveUp;
+        }
+
+        self.round += 1;
+        self.started = now;
+        RoundStatus::Advanced(self.round)
+    }
+}
+
+/// Why an election produced no nexus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ElectionError {
+    #[error("region has no nodes")]
+    EmptyRegion,
+    #[error("no reachable candidates in region")]
+    NoEligibleCandidates,
+    #[error("all candidates below the fallback reputation floor")]
+    AllBelowReputationFloor,
+}
+
+impl From<ElectionError> for EnrError {
+    fn from(err: ElectionError) -> Self {
+        EnrError::NexusElectionFailed(err.to_string())
+    }
 }
 
 /// Node metrics for election
@@ -53,6 +282,12 @@ pub trait NodeMetrics {
     fn get_bandwidth(&self, node: &NodeId) -> u64;
     fn get_reputation(&self, node: &NodeId) -> f64;
     fn get_connection_count(&self, node: &NodeId) -> u32;
+
+    /// Most leaves `node` could carry as nexus, e.g. from
+    /// [`optimal_leaf_capacity`]
+    fn get_leaf_capacity(&self, _node: &NodeId) -> u32 {
+        MAX_LEAVES_PER_NEXUS
+    }
 }
 
 /// Check if a node meets minimum nexus eligibility requirements
@@ -65,11 +300,39 @@ pub fn is_nexus_eligible(uptime: f64, bandwidth: u64, reputation: f64) -> bool {
 /// Nexus elector
 pub struct NexusElector<M: NodeMetrics> {
     metrics: M,
+    connectivity_mode: ConnectivityMode,
+    fallback_reputation_floor: f64,
+    fallback_requires_reachable: bool,
 }
 
 impl<M: NodeMetrics> NexusElector<M> {
     pub fn new(metrics: M) -> Self {
-        Self { metrics }
+        Self {
+            metrics,
+            connectivity_mode: ConnectivityMode::default(),
+            fallback_reputation_floor: 0.0,
+            fallback_requires_reachable: false,
+        }
+    }
+
+    /// Set how connectivity is scored
+    pub fn with_connectivity_mode(mut self, mode: ConnectivityMode) -> Self {
+        self.connectivity_mode = mode;
+        self
+    }
+
+    /// Minimum reputation for the fallback election when no node is fully
+    /// eligible (default: 0.0, any node)
+    pub fn with_fallback_reputation_floor(mut self, floor: f64) -> Self {
+        self.fallback_reputation_floor = floor;
+        self
+    }
+
+    /// Skip nodes with no uptime or bandwidth in the fallback election
+    /// (default: false)
+    pub fn with_fallback_requires_reachable(mut self, required: bool) -> Self {
+        self.fallback_requires_reachable = required;
+        self
     }
 
     /// Build candidate from node
@@ -86,9 +349,15 @@ impl<M: NodeMetrics> NexusElector<M> {
 
     /// Elect a nexus for the given region
     /// From dol/nexus.dol lines 275-338
-    pub fn elect(&self, region: &Region) -> Option<NodeId> {
+    ///
+    /// `EmptyRegion` and `NoEligibleCandidates` are worth retrying once the
+    /// region changes; `AllBelowReputationFloor` should be escalated. The
+    /// latter two only arise when the fallback election is restricted with
+    /// [`Self::with_fallback_requires_reachable`] or
+    /// [`Self::with_fallback_reputation_floor`].
+    pub fn elect(&self, region: &Region) -> Result<NodeId, ElectionError> {
         if region.nodes.is_empty() {
-            return None;
+            return Err(ElectionError::EmptyRegion);
         }
 
         // Step 1: Gather qualified candidates
@@ -101,28 +370,40 @@ impl<M: NodeMetrics> NexusElector<M> {
 
         // Step 2: If no qualified candidates, use best available by reputation
         if candidates.is_empty() {
-            let mut all_candidates: Vec<NexusCandidate> = region
+            let reachable: Vec<NexusCandidate> = region
                 .nodes
                 .iter()
                 .map(|n| self.build_candidate(*n))
+                .filter(|c| {
+                    !self.fallback_requires_reachable || (c.uptime > 0.0 && c.bandwidth > 0)
+                })
                 .collect();
+            if reachable.is_empty() {
+                return Err(ElectionError::NoEligibleCandidates);
+            }
 
-            all_candidates.sort_by(|a, b| {
+            let mut above_floor: Vec<NexusCandidate> = reachable
+                .into_iter()
+                .filter(|c| c.reputation >= self.fallback_reputation_floor)
+                .collect();
+            if above_floor.is_empty() {
+                return Err(ElectionError::AllBelowReputationFloor);
+            }
+
+            above_floor.sort_by(|a, b| {
                 b.reputation
                     .partial_cmp(&a.reputation)
                     .unwrap_or(std::cmp::Ordering::Equal)
             });
 
-            candidates = all_candidates.into_iter().take(3).collect();
-        }
-
-        if candidates.is_empty() {
-            return None;
+            candidates = above_floor.into_iter().take(3).collect();
         }
 
         // Step 3: Score candidates
         for candidate in &mut candidates {
-            candidate.election_score = calculate_election_score(candidate);
+            let capacity = self.metrics.get_leaf_capacity(&candidate.node);
+            candidate.election_score =
+                calculate_election_score_for_capacity(candidate, capacity, self.connectivity_mode);
         }
 
         // Step 4: Elect highest scorer
@@ -134,6 +415,7 @@ impl<M: NodeMetrics> NexusElector<M> {
                     .unwrap_or(std::cmp::Ordering::Equal)
             })
             .map(|c| c.node)
+            .ok_or(ElectionError::NoEligibleCandidates)
     }
 }
 
@@ -174,6 +456,38 @@ mod tests {
         assert!((normalize_bandwidth(200_000_000) - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_optimal_leaf_capacity() {
+        let idle = ResourceGradient {
+            cpu_available: 1.0,
+            memory_available: 1.0,
+            bandwidth_available: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            optimal_leaf_capacity(&idle, MIN_NEXUS_BANDWIDTH),
+            MAX_LEAVES_PER_NEXUS
+        );
+        assert_eq!(optimal_leaf_capacity(&idle, 30_000_000), 150);
+        assert_eq!(
+            optimal_leaf_capacity(&idle, 1_000_000_000),
+            ABSOLUTE_MAX_LEAVES_PER_NEXUS
+        );
+
+        // Half the headroom carries half the leaves
+        let busy = ResourceGradient {
+            cpu_available: 0.5,
+            memory_available: 0.5,
+            bandwidth_available: 0.5,
+            ..Default::default()
+        };
+        assert_eq!(optimal_leaf_capacity(&busy, 30_000_000), 75);
+        assert_eq!(
+            optimal_leaf_capacity(&ResourceGradient::zero(), 30_000_000),
+            MIN_LEAVES_PER_NEXUS
+        );
+    }
+
     #[test]
     fn test_normalize_connectivity() {
         let optimal = (MIN_LEAVES_PER_NEXUS + MAX_LEAVES_PER_NEXUS) / 2;
@@ -182,6 +496,156 @@ mod tests {
         assert!(normalize_connectivity(100) < normalize_connectivity(optimal));
     }
 
+    #[test]
+    fn test_normalize_connectivity_headroom() {
+        let headroom = ConnectivityMode::Headroom;
+        assert!((normalize_connectivity_with_mode(0, headroom) - 1.0).abs() < 0.001);
+        assert!(normalize_connectivity_with_mode(MAX_LEAVES_PER_NEXUS, headroom).abs() < 0.001);
+        assert!(normalize_connectivity_with_mode(100, headroom).abs() < 0.001);
+        assert!(
+            normalize_connectivity_with_mode(5, headroom)
+                > normalize_connectivity_with_mode(27, headroom)
+        );
+        assert_eq!(
+            normalize_connectivity_with_mode(10, ConnectivityMode::Midpoint),
+            normalize_connectivity(10)
+        );
+
+        // A larger capacity leaves more room at the same leaf count
+        assert!(
+            normalize_connectivity_for_capacity(40, 150, headroom)
+                > normalize_connectivity_for_capacity(40, MAX_LEAVES_PER_NEXUS, headroom)
+        );
+        let midpoint = (MIN_LEAVES_PER_NEXUS + 150) / 2;
+        assert!(
+            (normalize_connectivity_for_capacity(midpoint, 150, ConnectivityMode::Midpoint) - 1.0)
+                .abs()
+                < 0.001
+        );
+    }
+
+    #[test]
+    fn test_election_connectivity_mode() {
+        let busy = NodeId::from_bytes([1u8; 32]);
+        let fresh = NodeId::from_bytes([2u8; 32]);
+        let metrics = || {
+            let mut metrics = MockMetrics {
+                uptimes: HashMap::new(),
+                bandwidths: HashMap::new(),
+                reputations: HashMap::new(),
+                connections: HashMap::new(),
+            };
+            for node in [busy, fresh] {
+                metrics.uptimes.insert(node, 0.99);
+                metrics.bandwidths.insert(node, 50_000_000);
+                metrics.reputations.insert(node, 0.9);
+            }
+            metrics.connections.insert(busy, 27);
+            metrics.connections.insert(fresh, 0);
+            metrics
+        };
+        let region = Region::with_nodes("test", vec![busy, fresh]);
+
+        assert_eq!(NexusElector::new(metrics()).elect(&region), Ok(busy));
+        let elector =
+            NexusElector::new(metrics()).with_connectivity_mode(ConnectivityMode::Headroom);
+        assert_eq!(elector.elect(&region), Ok(fresh));
+    }
+
+    #[test]
+    fn test_election_uses_leaf_capacity() {
+        struct WithCapacity(MockMetrics, NodeId, u32);
+        impl NodeMetrics for WithCapacity {
+            fn get_uptime(&self, node: &NodeId) -> f64 {
+                self.0.get_uptime(node)
+            }
+            fn get_bandwidth(&self, node: &NodeId) -> u64 {
+                self.0.get_bandwidth(node)
+            }
+            fn get_reputation(&self, node: &NodeId) -> f64 {
+                self.0.get_reputation(node)
+            }
+            fn get_connection_count(&self, node: &NodeId) -> u32 {
+                self.0.get_connection_count(node)
+            }
+            fn get_leaf_capacity(&self, node: &NodeId) -> u32 {
+                if *node == self.1 {
+                    self.2
+                } else {
+                    MAX_LEAVES_PER_NEXUS
+                }
+            }
+        }
+
+        let strong = NodeId::from_bytes([1u8; 32]);
+        let weak = NodeId::from_bytes([2u8; 32]);
+        let mut metrics = MockMetrics {
+            uptimes: HashMap::new(),
+            bandwidths: HashMap::new(),
+            reputations: HashMap::new(),
+            connections: HashMap::new(),
+        };
+        for node in [strong, weak] {
+            metrics.uptimes.insert(node, 0.99);
+            metrics.bandwidths.insert(node, 50_000_000);
+            metrics.reputations.insert(node, 0.9);
+        }
+        metrics.connections.insert(strong, 40);
+        metrics.connections.insert(weak, 20);
+        let region = Region::with_nodes("test", vec![strong, weak]);
+
+        // 40 of 150 leaves leaves more headroom than 20 of 50
+        let elector = NexusElector::new(WithCapacity(metrics, strong, 150))
+            .with_connectivity_mode(ConnectivityMode::Headroom);
+        assert_eq!(elector.elect(&region), Ok(strong));
+    }
+
+    #[test]
+    fn test_election_config_timing() {
+        let config = ElectionConfig::default();
+        assert_eq!(config.estimated_duration(), Duration::seconds(50));
+
+        let start = Timestamp::new(1_000_000);
+        let at = |secs: u64| Timestamp::new(start.millis + secs * 1000);
+        assert_eq!(
+            config.phase_at(start, at(0)),
+            Some(ElectionPhase::Announcement)
+        );
+        assert_eq!(
+            config.phase_at(start, at(5)),
+            Some(ElectionPhase::Candidacy)
+        );
+        assert_eq!(config.phase_at(start, at(49)), Some(ElectionPhase::Voting));
+        assert_eq!(config.phase_at(start, at(50)), None);
+    }
+
+    #[test]
+    fn test_election_round_advances_on_timeout() {
+        let config = ElectionConfig {
+            max_rounds: 2,
+            ..ElectionConfig::default()
+        };
+        let start = Timestamp::new(1_000_000);
+        let at = |secs: u64| Timestamp::new(start.millis + secs * 1000);
+        let mut round = ElectionRound::new(config, start);
+
+        assert_eq!(
+            round.poll(at(10)),
+            RoundStatus::InProgress(ElectionPhase::Candidacy)
+        );
+        assert_eq!(round.poll(at(50)), RoundStatus::Advanced(2));
+        assert_eq!(round.started(), at(50));
+        assert_eq!(
+            round.poll(at(51)),
+            RoundStatus::InProgress(ElectionPhase::Announcement)
+        );
+        assert_eq!(round.poll(at(100)), RoundStatus::GaveUp);
+
+        let mut concluded = ElectionRound::new(config, start);
+        concluded.conclude();
+        assert_eq!(concluded.poll(at(100)), RoundStatus::Concluded);
+    }
+
     #[test]
     fn test_nexus_eligibility() {
         assert!(is_nexus_eligible(0.96, 15_000_000, 0.75));
@@ -225,6 +689,51 @@ mod tests {
         let region = Region::with_nodes("test", vec![node1, node2, node3]);
 
         let winner = elector.elect(&region);
-        assert_eq!(winner, Some(node1)); // node1 should win with highest score
+        assert_eq!(winner, Ok(node1)); // node1 should win with highest score
+    }
+
+    #[test]
+    fn test_election_errors() {
+        let node1 = NodeId::from_bytes([1u8; 32]);
+        let node2 = NodeId::from_bytes([2u8; 32]);
+
+        // node1: reachable but below the reputation floor; node2: unreachable
+        let metrics = || {
+            let mut metrics = MockMetrics {
+                uptimes: HashMap::new(),
+                bandwidths: HashMap::new(),
+                reputations: HashMap::new(),
+                connections: HashMap::new(),
+            };
+            metrics.uptimes.insert(node1, 0.5);
+            metrics.bandwidths.insert(node1, 1_000_000);
+            metrics.reputations.insert(node1, 0.1);
+            metrics
+        };
+
+        // Unrestricted fallback still elects the best of a weak region
+        let lenient = NexusElector::new(metrics());
+        let weak = Region::with_nodes("weak", vec![node1, node2]);
+        assert_eq!(lenient.elect(&weak), Ok(node1));
+
+        let elector = NexusElector::new(metrics())
+            .with_fallback_requires_reachable(true)
+            .with_fallback_reputation_floor(0.3);
+
+        let empty = Region::with_nodes("empty", vec![]);
+        assert_eq!(elector.elect(&empty), Err(ElectionError::EmptyRegion));
+
+        let offline = Region::with_nodes("offline", vec![node2]);
+        assert_eq!(
+            elector.elect(&offline),
+            Err(ElectionError::NoEligibleCandidates)
+        );
+
+        let err = elector.elect(&weak).unwrap_err();
+        assert_eq!(err, ElectionError::AllBelowReputationFloor);
+        assert!(matches!(
+            EnrError::from(err),
+            EnrError::NexusElectionFailed(_)
+        ));
     }
 }
@@ -3,7 +3,28 @@
 //! Implements market making from dol/nexus.dol lines 340-448
 
 use super::types::*;
-use crate::core::Credits;
+use crate::core::{Credits, Timestamp};
+use crate::entropy::{weighted_entropy_sum, EntropyAccount, EntropyWeights};
+
+/// Per-component contributions to a market-making spread
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpreadBreakdown {
+    /// Configured minimum spread
+    pub base: f64,
+    /// Contribution from price volatility
+    pub volatility: f64,
+    /// Contribution from inventory imbalance
+    pub inventory: f64,
+    /// Contribution from local entropy
+    pub entropy: f64,
+}
+
+impl SpreadBreakdown {
+    /// Total spread (sum of all contributions)
+    pub fn total(&self) -> f64 {
+        self.base + self.volatility + self.inventory + self.entropy
+    }
+}
 
 /// Calculate the bid/ask spread for market making.
 ///
@@ -20,7 +41,20 @@ pub fn calculate_spread(
     local_entropy: f64,
     price_history: &[Credits],
 ) -> f64 {
-    let mut spread = config.minimum_spread;
+    calculate_spread_detailed(order_book, config, local_entropy, price_history).total()
+}
+
+/// Calculate the spread with each component's contribution exposed.
+pub fn calculate_spread_detailed(
+    order_book: &OrderBook,
+    config: &MarketMakerConfig,
+    local_entropy: f64,
+    price_history: &[Credits],
+) -> SpreadBreakdown {
+    let mut breakdown = SpreadBreakdown {
+        base: config.minimum_spread,
+        ..Default::default()
+    };
 
     // Volatility adjustment
     if price_history.len() > 1 {
@@ -35,7 +69,7 @@ pub fn calculate_spread(
                 / price_history.len() as f64;
 
             let volatility = variance.sqrt() / mean_price;
-            spread += volatility * config.volatility_factor;
+            breakdown.volatility = volatility * config.volatility_factor;
         }
     }
 
@@ -45,41 +79,107 @@ pub fn calculate_spread(
         let inventory_imbalance = (current_inventory as i64 - config.target_inventory as i64).abs()
             as f64
             / config.target_inventory as f64;
-        spread += inventory_imbalance * config.inventory_factor;
+        breakdown.inventory = inventory_imbalance * config.inventory_factor;
     }
 
     // Entropy adjustment
-    spread += local_entropy * config.entropy_spread_factor;
+    breakdown.entropy = local_entropy * config.entropy_spread_factor;
 
-    spread
+    breakdown
+}
+
+/// Select the entropy component relevant to a resource market.
+///
+/// Storage maps to storage entropy, bandwidth to network entropy, and
+/// CPU/memory to compute entropy. Resources without a direct mapping
+/// (GPU) fall back to the default weighted sum.
+pub fn resource_entropy(resource: ResourceType, account: &EntropyAccount) -> f64 {
+    match resource {
+        ResourceType::Storage => account.storage,
+        ResourceType::Bandwidth => account.network,
+        ResourceType::Cpu | ResourceType::Memory => account.compute,
+        ResourceType::Gpu => weighted_entropy_sum(account, &EntropyWeights::default()),
+    }
 }
 
 /// Calculate bid price given mid price and spread
 pub fn calculate_bid_price(mid_price: Credits, spread: f64) -> Credits {
-    let bid = (mid_price.amount as f64 * (1.0 - spread / 2.0)).floor() as u64;
-    Credits::new(bid)
+    Credits::from_f64_saturating((mid_price.amount as f64 * (1.0 - spread / 2.0)).floor())
 }
 
 /// Calculate ask price given mid price and spread
 pub fn calculate_ask_price(mid_price: Credits, spread: f64) -> Credits {
-    let ask = (mid_price.amount as f64 * (1.0 + spread / 2.0)).ceil() as u64;
-    Credits::new(ask)
+    Credits::from_f64_saturating((mid_price.amount as f64 * (1.0 + spread / 2.0)).ceil())
+}
+
+/// Two-sided quote with quantities sized to the maker's exposure limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizedQuote {
+    pub bid: Credits,
+    pub ask: Credits,
+    /// Units bid for; zero once the exposure limit is reached
+    pub bid_quantity: u64,
+    pub ask_quantity: u64,
 }
 
 /// Market maker that provides liquidity for resources
 pub struct MarketMaker {
     config: MarketMakerConfig,
+    /// Mid price and time of the last emitted quote
+    last_quote: Option<(Credits, Timestamp)>,
 }
 
 impl MarketMaker {
     pub fn new(config: MarketMakerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            last_quote: None,
+        }
     }
 
     pub fn with_default_config() -> Self {
-        Self {
-            config: MarketMakerConfig::default(),
+        Self::new(MarketMakerConfig::default())
+    }
+
+    /// Whether a quote at `new_mid` should replace the last one emitted
+    ///
+    /// True if nothing has been quoted yet, `min_requote_interval` has
+    /// passed since the last quote, or the mid has moved by at least
+    /// `min_requote_change` of the last quoted mid.
+    pub fn should_requote(&self, new_mid: Credits, now: Timestamp) -> bool {
+        let Some((last_mid, last_at)) = self.last_quote else {
+            return true;
+        };
+        if now.millis.saturating_sub(last_at.millis) >= self.config.min_requote_interval.millis {
+            return true;
+        }
+        let moved = last_mid.amount.abs_diff(new_mid.amount) as f64;
+        match last_mid.amount {
+            0 => moved > 0.0,
+            mid => moved / mid as f64 >= self.config.min_requote_change,
+        }
+    }
+
+    /// Note that a quote at `mid` was emitted at `now`
+    pub fn record_quote(&mut self, mid: Credits, now: Timestamp) {
+        self.last_quote = Some((mid, now));
+    }
+
+    /// Quote like [`Self::quote`] only if [`Self::should_requote`] allows
+    /// it, recording the quote when emitted
+    pub fn quote_throttled(
+        &mut self,
+        order_book: &OrderBook,
+        mid_price: Credits,
+        local_entropy: f64,
+        price_history: &[Credits],
+        now: Timestamp,
+    ) -> Option<(Credits, Credits)> {
+        if !self.should_requote(mid_price, now) {
+            return None;
         }
+        self.record_quote(mid_price, now);
+        Some(self.quote(order_book, mid_price, local_entropy, price_history))
     }
 
     /// Calculate bid and ask prices for a resource
@@ -98,11 +198,85 @@ impl MarketMaker {
         (bid, ask)
     }
 
+    /// Quote prices and size the bid so outstanding exposure stays within
+    /// `max_quote_exposure`
+    ///
+    /// `outstanding` is the value of the maker's bids already resting
+    /// (see [`OrderBook::bid_exposure`]). The bid quantity is `quantity`,
+    /// shrunk to what the remaining exposure can pay for at the bid price.
+    pub fn quote_sized(
+        &self,
+        order_book: &OrderBook,
+        mid_price: Credits,
+        local_entropy: f64,
+        price_history: &[Credits],
+        quantity: u64,
+        outstanding: Credits,
+    ) -> SizedQuote {
+        let (bid, ask) = self.quote(order_book, mid_price, local_entropy, price_history);
+        let remaining = self.config.max_quote_exposure.saturating_sub(outstanding);
+        let affordable = match bid.amount {
+            0 => quantity,
+            price => remaining.amount / price,
+        };
+
+        SizedQuote {
+            bid,
+            ask,
+            bid_quantity: quantity.min(affordable),
+            ask_quantity: quantity,
+        }
+    }
+
+    /// Calculate bid and ask prices using the entropy dimension matching
+    /// the book's resource
+    pub fn quote_with_account(
+        &self,
+        order_book: &OrderBook,
+        mid_price: Credits,
+        account: &EntropyAccount,
+        price_history: &[Credits],
+    ) -> (Credits, Credits) {
+        let local_entropy = resource_entropy(order_book.resource, account);
+        self.quote(order_book, mid_price, local_entropy, price_history)
+    }
+
+    /// Minimum spread at which market making still breaks even
+    ///
+    /// Each fill earns half the spread, which must cover the expected
+    /// adverse-selection loss (as a fraction of mid price) plus the
+    /// entropy-driven risk premium:
+    ///
+    /// breakeven = max(minimum_spread,
+    ///                 2 * (adverse_selection + entropy * entropy_spread_factor))
+    pub fn breakeven_spread(&self, entropy: f64, expected_adverse_selection: f64) -> f64 {
+        let risk = expected_adverse_selection.max(0.0)
+            + entropy.max(0.0) * self.config.entropy_spread_factor;
+        (2.0 * risk).max(self.config.minimum_spread)
+    }
+
+    /// Quote like [`Self::quote`], but never tighter than the breakeven spread
+    pub fn quote_risk_aware(
+        &self,
+        order_book: &OrderBook,
+        mid_price: Credits,
+        local_entropy: f64,
+        price_history: &[Credits],
+        expected_adverse_selection: f64,
+    ) -> (Credits, Credits) {
+        let spread = calculate_spread(order_book, &self.config, local_entropy, price_history)
+            .max(self.breakeven_spread(local_entropy, expected_adverse_selection));
+
+        (
+            calculate_bid_price(mid_price, spread),
+            calculate_ask_price(mid_price, spread),
+        )
+    }
+
     /// Calculate expected revenue from market making
     /// spread_revenue = volume * spread / 2
     pub fn expected_revenue(&self, volume: u64, spread: f64) -> Credits {
-        let revenue = (volume as f64 * spread / 2.0).floor() as u64;
-        Credits::new(revenue)
+        Credits::from_f64_saturating((volume as f64 * spread / 2.0).floor())
     }
 }
 
@@ -124,6 +298,44 @@ mod tests {
         assert!((spread - config.minimum_spread).abs() < 0.001);
     }
 
+    #[test]
+    fn test_quote_sized_respects_exposure() {
+        let mm = MarketMaker::new(MarketMakerConfig {
+            target_inventory: 0,
+            max_quote_exposure: Credits::new(10_000),
+            ..Default::default()
+        });
+        let maker = crate::core::NodeId::from_bytes([1u8; 32]);
+        let mut book = OrderBook::new(ResourceType::Cpu);
+        let mid = Credits::new(1_000);
+
+        // 1% spread: bid 995, so 10 units fit under 10_000
+        let quote = mm.quote_sized(&book, mid, 0.0, &[], 50, Credits::ZERO);
+        assert_eq!(quote.bid, Credits::new(995));
+        assert_eq!(quote.bid_quantity, 10);
+        assert_eq!(quote.ask_quantity, 50);
+
+        book.insert_bid(Order {
+            price: quote.bid,
+            quantity: quote.bid_quantity,
+            node: maker,
+            timestamp: crate::core::Timestamp::now(),
+        })
+        .unwrap();
+        let outstanding = book.bid_exposure(&maker);
+        assert_eq!(outstanding, Credits::new(9_950));
+        assert!(outstanding <= Credits::new(10_000));
+
+        // Limit reached: no bid
+        let quote = mm.quote_sized(&book, mid, 0.0, &[], 50, outstanding);
+        assert_eq!(quote.bid_quantity, 0);
+
+        // Unlimited by default
+        let quote =
+            MarketMaker::with_default_config().quote_sized(&book, mid, 0.0, &[], 50, outstanding);
+        assert_eq!(quote.bid_quantity, 50);
+    }
+
     #[test]
     fn test_calculate_spread_with_entropy() {
         let book = OrderBook::new(ResourceType::Cpu);
@@ -156,6 +368,23 @@ mod tests {
         assert!(spread_with_volatility > spread_no_volatility);
     }
 
+    #[test]
+    fn test_calculate_spread_detailed() {
+        let book = OrderBook::new(ResourceType::Cpu);
+        let config = MarketMakerConfig::default();
+        let prices = vec![Credits::new(100), Credits::new(150), Credits::new(80)];
+
+        let breakdown = calculate_spread_detailed(&book, &config, 2.0, &prices);
+        assert_eq!(breakdown.base, config.minimum_spread);
+        assert!(breakdown.volatility > 0.0);
+        assert!((breakdown.entropy - 2.0 * config.entropy_spread_factor).abs() < 0.001);
+        assert!((breakdown.total() - calculate_spread(&book, &config, 2.0, &prices)).abs() < 1e-12);
+
+        let quiet = calculate_spread_detailed(&book, &config, 0.0, &[]);
+        assert_eq!(quiet.volatility, 0.0);
+        assert_eq!(quiet.entropy, 0.0);
+    }
+
     #[test]
     fn test_bid_ask_prices() {
         let mid = Credits::new(100);
@@ -182,6 +411,46 @@ mod tests {
         assert!(ask.amount > bid.amount);
     }
 
+    #[test]
+    fn test_resource_entropy_selection() {
+        let account = EntropyAccount {
+            network: 1.0,
+            compute: 2.0,
+            storage: 3.0,
+            temporal: 4.0,
+        };
+
+        assert_eq!(resource_entropy(ResourceType::Storage, &account), 3.0);
+        assert_eq!(resource_entropy(ResourceType::Bandwidth, &account), 1.0);
+        assert_eq!(resource_entropy(ResourceType::Cpu, &account), 2.0);
+        assert_eq!(resource_entropy(ResourceType::Memory, &account), 2.0);
+        // 0.3*1 + 0.3*2 + 0.2*3 + 0.2*4 = 2.3
+        assert!((resource_entropy(ResourceType::Gpu, &account) - 2.3).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_quote_with_account() {
+        let mm = MarketMaker::with_default_config();
+        let account = EntropyAccount {
+            storage: 8.0,
+            ..Default::default()
+        };
+
+        let storage_book = OrderBook::new(ResourceType::Storage);
+        let cpu_book = OrderBook::new(ResourceType::Cpu);
+        let (storage_bid, storage_ask) =
+            mm.quote_with_account(&storage_book, Credits::new(10_000), &account, &[]);
+        let (cpu_bid, cpu_ask) =
+            mm.quote_with_account(&cpu_book, Credits::new(10_000), &account, &[]);
+
+        // Storage entropy only widens the storage market
+        assert!(storage_ask.amount - storage_bid.amount > cpu_ask.amount - cpu_bid.amount);
+        assert_eq!(
+            (cpu_bid, cpu_ask),
+            mm.quote(&cpu_book, Credits::new(10_000), 0.0, &[])
+        );
+    }
+
     #[test]
     fn test_expected_revenue() {
         let mm = MarketMaker::with_default_config();
@@ -194,4 +463,57 @@ mod tests {
         // 10000 * 0.05 / 2 = 250
         assert_eq!(revenue.amount, 250);
     }
+
+    #[test]
+    fn test_breakeven_spread() {
+        let mm = MarketMaker::with_default_config();
+
+        // No risk: falls back to the configured minimum
+        assert_eq!(mm.breakeven_spread(0.0, 0.0), 0.01);
+
+        // 2 * (0.02 + 1.0 * 0.1) = 0.24
+        assert!((mm.breakeven_spread(1.0, 0.02) - 0.24).abs() < 1e-9);
+
+        // Risk-aware quote widens to the breakeven floor
+        let mm = MarketMaker::new(MarketMakerConfig {
+            target_inventory: 0,
+            ..MarketMakerConfig::default()
+        });
+        let book = OrderBook::new(ResourceType::Cpu);
+        let (bid, ask) = mm.quote_risk_aware(&book, Credits::new(10_000), 1.0, &[], 0.02);
+        let (plain_bid, plain_ask) = mm.quote(&book, Credits::new(10_000), 1.0, &[]);
+        assert!(bid < plain_bid && ask > plain_ask);
+        assert!((ask.amount - bid.amount).abs_diff(2_400) <= 2);
+    }
+
+    #[test]
+    fn test_requote_throttling() {
+        use crate::core::Duration;
+
+        let mut mm = MarketMaker::new(MarketMakerConfig {
+            min_requote_interval: Duration::seconds(5),
+            min_requote_change: 0.02,
+            ..Default::default()
+        });
+        let book = OrderBook::new(ResourceType::Cpu);
+        let t0 = Timestamp::new(1_000_000);
+        let mid = Credits::new(1000);
+
+        assert!(mm.quote_throttled(&book, mid, 0.0, &[], t0).is_some());
+        // Small move, too soon
+        let soon = Timestamp::new(t0.millis + 1_000);
+        assert!(!mm.should_requote(Credits::new(1010), soon));
+        assert!(mm
+            .quote_throttled(&book, Credits::new(1010), 0.0, &[], soon)
+            .is_none());
+        // Large enough move
+        assert!(mm.should_requote(Credits::new(980), soon));
+        // Interval elapsed
+        assert!(mm.should_requote(mid, Timestamp::new(t0.millis + 5_000)));
+
+        // Defaults never throttle
+        let mut eager = MarketMaker::with_default_config();
+        eager.record_quote(mid, t0);
+        assert!(eager.should_requote(mid, t0));
+    }
 }
@@ -0,0 +1,196 @@
+//! Order Matching
+//!
+//! Deterministic price-time matching for nexus order books
+
+use super::types::*;
+use crate::core::{Credits, Duration, NodeId, Timestamp};
+use std::cmp::Ordering;
+
+/// How orders at the same price are prioritized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Earliest order first, then by node id
+    #[default]
+    TimePriority,
+    /// Lowest node id first, then by time
+    NodeIdOrder,
+}
+
+/// Matching engine configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchConfig {
+    pub tie_break: TieBreak,
+    /// Minimum time an order must rest before it can match (zero = no window)
+    pub stability_window: Duration,
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self {
+            tie_break: TieBreak::default(),
+            stability_window: Duration::new(0),
+        }
+    }
+}
+
+/// A fill between a resting bid and ask
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trade {
+    pub buyer: NodeId,
+    pub seller: NodeId,
+    pub price: Credits,
+    pub quantity: u64,
+}
+
+fn tie_break_cmp(a: &Order, b: &Order, tie_break: TieBreak) -> Ordering {
+    let by_time = a.timestamp.millis.cmp(&b.timestamp.millis);
+    let by_node = a.node.0.cmp(&b.node.0);
+    match tie_break {
+        TieBreak::TimePriority => by_time.then(by_node),
+        TieBreak::NodeIdOrder => by_node.then(by_time),
+    }
+}
+
+/// Match crossing orders in the book, removing filled quantity
+///
+/// Matching is deterministic for a given book state: bids are taken by
+/// highest price, asks by lowest, with ties resolved by `config.tie_break`.
+/// Each trade executes at the price of the earlier (resting) order. Orders
+/// younger than `config.stability_window` at `now` are skipped, so an order
+/// placed just before a matching pass can't jump the queue. Running again on
+/// the resulting book yields no trades.
+pub fn match_orders(book: &mut OrderBook, config: &MatchConfig, now: Timestamp) -> Vec<Trade> {
+    let window = config.stability_window.millis;
+    let eligible = |o: &Order| now.millis.saturating_sub(o.timestamp.millis) >= window;
+
+    let mut bid_idx: Vec<usize> = (0..book.bids.len())
+        .filter(|i| eligible(&book.bids[*i]))
+        .collect();
+    bid_idx.sort_by(|a, b| {
+        let (a, b) = (&book.bids[*a], &book.bids[*b]);
+        b.price
+            .cmp(&a.price)
+            .then(tie_break_cmp(a, b, config.tie_break))
+    });
+
+    let mut ask_idx: Vec<usize> = (0..book.asks.len())
+        .filter(|i| eligible(&book.asks[*i]))
+        .collect();
+    ask_idx.sort_by(|a, b| {
+        let (a, b) = (&book.asks[*a], &book.asks[*b]);
+        a.price
+            .cmp(&b.price)
+            .then(tie_break_cmp(a, b, config.tie_break))
+    });
+
+    let mut trades = Vec::new();
+    let (mut bi, mut ai) = (0, 0);
+    while bi < bid_idx.len() && ai < ask_idx.len() {
+        let (b, a) = (bid_idx[bi], ask_idx[ai]);
+        let (bid, ask) = (&book.bids[b], &book.asks[a]);
+        if bid.price < ask.price {
+            break;
+        }
+
+        let quantity = bid.quantity.min(ask.quantity);
+        let price = if bid.timestamp.millis < ask.timestamp.millis {
+            bid.price
+        } else {
+            ask.price
+        };
+        if quantity > 0 {
+            trades.push(Trade {
+                buyer: bid.node,
+                seller: ask.node,
+                price,
+                quantity,
+            });
+        }
+
+        book.bids[b].quantity -= quantity;
+        book.asks[a].quantity -= quantity;
+        if book.bids[b].quantity == 0 {
+            bi += 1;
+        }
+        if book.asks[a].quantity == 0 {
+            ai += 1;
+        }
+    }
+
+    book.bids.retain(|o| o.quantity > 0);
+    book.asks.retain(|o| o.quantity > 0);
+    trades
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(node: u8, price: u64, quantity: u64, millis: u64) -> Order {
+        Order {
+            price: Credits::new(price),
+            quantity,
+            node: NodeId::from_bytes([node; 32]),
+            timestamp: Timestamp::new(millis),
+        }
+    }
+
+    #[test]
+    fn test_match_orders_price_time_priority() {
+        let mut book = OrderBook::new(ResourceType::Cpu);
+        book.insert_bid(order(1, 105, 5, 2_000)).unwrap();
+        book.insert_bid(order(2, 105, 5, 1_000)).unwrap();
+        book.insert_ask(order(3, 100, 7, 500)).unwrap();
+
+        let trades = match_orders(&mut book, &MatchConfig::default(), Timestamp::new(10_000));
+        assert_eq!(trades.len(), 2);
+        // Earlier bid at the same price fills first, at the resting ask price
+        assert_eq!(trades[0].buyer, NodeId::from_bytes([2u8; 32]));
+        assert_eq!(trades[0].quantity, 5);
+        assert_eq!(trades[0].price, Credits::new(100));
+        assert_eq!(trades[1].quantity, 2);
+
+        assert!(book.asks.is_empty());
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.bids[0].quantity, 3);
+
+        // Idempotent once nothing crosses
+        assert!(
+            match_orders(&mut book, &MatchConfig::default(), Timestamp::new(10_000)).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_match_orders_node_id_tie_break() {
+        let mut book = OrderBook::new(ResourceType::Cpu);
+        book.insert_bid(order(2, 100, 1, 1_000)).unwrap();
+        book.insert_bid(order(1, 100, 1, 2_000)).unwrap();
+        book.insert_ask(order(3, 100, 1, 500)).unwrap();
+
+        let config = MatchConfig {
+            tie_break: TieBreak::NodeIdOrder,
+            ..Default::default()
+        };
+        let trades = match_orders(&mut book, &config, Timestamp::new(10_000));
+        assert_eq!(trades[0].buyer, NodeId::from_bytes([1u8; 32]));
+    }
+
+    #[test]
+    fn test_match_orders_stability_window() {
+        let mut book = OrderBook::new(ResourceType::Cpu);
+        book.insert_ask(order(3, 100, 1, 1_000)).unwrap();
+        // Placed 10ms before matching: too fresh
+        book.insert_bid(order(1, 110, 1, 9_990)).unwrap();
+
+        let config = MatchConfig {
+            stability_window: Duration::seconds(1),
+            ..Default::default()
+        };
+        assert!(match_orders(&mut book, &config, Timestamp::new(10_000)).is_empty());
+        assert_eq!(book.bids.len(), 1);
+
+        let trades = match_orders(&mut book, &config, Timestamp::new(11_000));
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].price, Credits::new(100));
+    }
+}
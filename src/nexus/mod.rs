@@ -10,11 +10,13 @@
 pub mod aggregation;
 pub mod election;
 pub mod market_maker;
+pub mod matching;
 pub mod topology;
 pub mod types;
 
 pub use aggregation::*;
 pub use election::*;
 pub use market_maker::*;
+pub use matching::*;
 pub use topology::*;
 pub use types::*;
@@ -2,8 +2,10 @@
 //!
 //! Implements gossip routing from dol/nexus.dol lines 58-157
 
+use super::election::{MAX_LEAVES_PER_NEXUS, MIN_LEAVES_PER_NEXUS};
 use super::types::*;
-use crate::core::NodeId;
+use crate::core::{InvariantViolation, NodeId, Timestamp};
+use std::collections::{HashMap, HashSet};
 
 /// Estimate entropy for direct node-to-node path.
 /// From dol/nexus.dol lines 77-87
@@ -73,10 +75,37 @@ pub fn determine_gossip_path(
     }
 }
 
+/// Structural health of the stored hierarchy
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TopologyMetrics {
+    /// Longest chain of parent links, e.g. 2 for leaf → nexus → poteau-mitan
+    pub max_depth: usize,
+    pub nexus_count: usize,
+    pub avg_leaves_per_nexus: f64,
+    /// Population variance of leaf counts across nexuses; high values mean
+    /// the tree needs rebalancing
+    pub balance_factor: f64,
+}
+
+/// A single mutation to the stored topology
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopologyChange {
+    /// Insert or replace a node's topology
+    Set(NexusTopology),
+    /// Drop a node, as [`TopologyManager::remove_node`]
+    Remove(NodeId),
+    /// Move a leaf under another nexus
+    Reparent { leaf: NodeId, nexus: NodeId },
+    /// Hand the nexus role over, as [`TopologyManager::handoff_nexus`]
+    Handoff { old: NodeId, new: NodeId },
+}
+
 /// Topology manager for tracking node roles and routing
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct TopologyManager {
-    topologies: std::collections::HashMap<NodeId, NexusTopology>,
+    topologies: HashMap<NodeId, NexusTopology>,
+    /// Per-nexus leaf limits, e.g. from `optimal_leaf_capacity`
+    leaf_capacities: HashMap<NodeId, u32>,
 }
 
 impl TopologyManager {
@@ -99,6 +128,56 @@ impl TopologyManager {
         self.topologies.get(node)
     }
 
+    /// Set the most leaves `nexus` may carry, overriding
+    /// `MAX_LEAVES_PER_NEXUS` for it
+    pub fn set_leaf_capacity(&mut self, nexus: NodeId, capacity: u32) {
+        self.leaf_capacities.insert(nexus, capacity);
+    }
+
+    /// Leaf limit for `nexus` (`MAX_LEAVES_PER_NEXUS` unless set)
+    pub fn leaf_capacity(&self, nexus: &NodeId) -> u32 {
+        self.leaf_capacities
+            .get(nexus)
+            .copied()
+            .unwrap_or(MAX_LEAVES_PER_NEXUS)
+    }
+
+    /// Nexus with the most room left under its leaf capacity
+    ///
+    /// Ties go to the lowest node ID. `None` if every nexus is full.
+    pub fn nexus_for_new_leaf(&self) -> Option<NodeId> {
+        let mut nexuses = self.get_nexuses();
+        nexuses.sort_by_key(|id| id.0);
+        nexuses
+            .into_iter()
+            .map(|nexus| {
+                let leaves = self.get_leaves_of(&nexus).len() as u32;
+                (nexus, self.leaf_capacity(&nexus).saturating_sub(leaves))
+            })
+            .filter(|(_, room)| *room > 0)
+            .min_by_key(|(_, room)| std::cmp::Reverse(*room))
+            .map(|(nexus, _)| nexus)
+    }
+
+    /// Attach `leaf` to [`Self::nexus_for_new_leaf`]
+    ///
+    /// Returns the chosen nexus, or `None` (leaving `leaf` untouched) if
+    /// every nexus is full.
+    pub fn assign_leaf(&mut self, leaf: NodeId) -> Option<NodeId> {
+        let nexus = self.nexus_for_new_leaf()?;
+        self.topologies
+            .entry(leaf)
+            .or_insert_with(|| NexusTopology {
+                node: leaf,
+                role: NexusRole::leaf(nexus),
+                aggregated_gradient: ResourceGradient::default(),
+                leaf_count: 0,
+                last_election: Timestamp::now(),
+            });
+        self.reparent_leaf(leaf, nexus);
+        Some(nexus)
+    }
+
     pub fn update_gradient(&mut self, node: &NodeId, gradient: ResourceGradient) {
         if let Some(topo) = self.topologies.get_mut(node) {
             topo.aggregated_gradient = gradient;
@@ -121,6 +200,24 @@ impl TopologyManager {
             .collect()
     }
 
+    /// Nexuses whose aggregated gradient averages below `threshold`
+    ///
+    /// Low aggregate availability means the nexus's leaves are saturated;
+    /// these are candidates for shedding leaves or rebalancing. Sorted by
+    /// node ID.
+    pub fn overloaded_nexuses(&self, threshold: f64) -> Vec<NodeId> {
+        let mut overloaded: Vec<NodeId> = self
+            .topologies
+            .iter()
+            .filter(|(_, t)| {
+                t.role.is_nexus() && t.aggregated_gradient.average_availability() < threshold
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        overloaded.sort_by_key(|id| id.0);
+        overloaded
+    }
+
     pub fn get_leaves_of(&self, nexus: &NodeId) -> Vec<NodeId> {
         self.topologies
             .iter()
@@ -128,12 +225,328 @@ impl TopologyManager {
             .map(|(id, _)| *id)
             .collect()
     }
+
+    /// Number of parent links from `node` up to a root
+    ///
+    /// Stops after visiting every known node, so a corrupt parent cycle
+    /// can't loop forever.
+    fn depth_of(&self, node: &NodeId) -> usize {
+        let mut depth = 0;
+        let mut current = *node;
+        while let Some(parent) = self.topologies.get(&current).and_then(|t| t.role.parent) {
+            depth += 1;
+            if depth > self.topologies.len() {
+                break;
+            }
+            current = parent;
+        }
+        depth
+    }
+
+    /// Depth, nexus fan-out, and balance of the stored hierarchy
+    pub fn structure_metrics(&self) -> TopologyMetrics {
+        let max_depth = self
+            .topologies
+            .keys()
+            .map(|node| self.depth_of(node))
+            .max()
+            .unwrap_or(0);
+
+        let leaf_counts: Vec<f64> = self
+            .get_nexuses()
+            .iter()
+            .map(|nexus| self.get_leaves_of(nexus).len() as f64)
+            .collect();
+        if leaf_counts.is_empty() {
+            return TopologyMetrics {
+                max_depth,
+                ..Default::default()
+            };
+        }
+
+        let n = leaf_counts.len() as f64;
+        let mean = leaf_counts.iter().sum::<f64>() / n;
+        let variance = leaf_counts.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / n;
+
+        TopologyMetrics {
+            max_depth,
+            nexus_count: leaf_counts.len(),
+            avg_leaves_per_nexus: mean,
+            balance_factor: variance,
+        }
+    }
+
+    /// Move `leaf` from its current parent to `nexus`, keeping both
+    /// nexuses' children and leaf counts in step
+    pub fn reparent_leaf(&mut self, leaf: NodeId, nexus: NodeId) {
+        let old_parent = match self.topologies.get_mut(&leaf) {
+            Some(topo) => topo.role.parent.replace(nexus),
+            None => return,
+        };
+        if let Some(topo) = old_parent.and_then(|p| self.topologies.get_mut(&p)) {
+            topo.role.children.retain(|c| *c != leaf);
+            topo.leaf_count = topo.role.children.len() as u32;
+        }
+        if let Some(topo) = self.topologies.get_mut(&nexus) {
+            if !topo.role.children.contains(&leaf) {
+                topo.role.children.push(leaf);
+            }
+            topo.leaf_count = topo.role.children.len() as u32;
+        }
+    }
+
+    pub fn apply_change(&mut self, change: &TopologyChange) {
+        match change {
+            TopologyChange::Set(topology) => self.set_topology(topology.node, topology.clone()),
+            TopologyChange::Remove(node) => {
+                self.remove_node(node);
+            }
+            TopologyChange::Reparent { leaf, nexus } => self.reparent_leaf(*leaf, *nexus),
+            TopologyChange::Handoff { old, new } => {
+                self.handoff_nexus(*old, *new);
+            }
+        }
+    }
+
+    /// Structural invariant violations in the stored hierarchy
+    ///
+    /// Checks that every nexus holds between `MIN_LEAVES_PER_NEXUS` and
+    /// its [`Self::leaf_capacity`] leaves, that every leaf it serves is in its
+    /// view, and that no node is orphaned: every leaf has a parent, and
+    /// every parent is a known nexus or poteau-mitan. Sorted by node ID.
+    pub fn check_structure(&self) -> Vec<InvariantViolation> {
+        let mut nodes: Vec<&NodeId> = self.topologies.keys().collect();
+        nodes.sort_by_key(|id| id.0);
+
+        let mut violations = Vec::new();
+        for node in nodes {
+            let role = &self.topologies[node].role;
+
+            let parent_valid = match role.parent {
+                Some(parent) => self
+                    .topologies
+                    .get(&parent)
+                    .is_some_and(|t| !t.role.is_leaf()),
+                None => !role.is_leaf(),
+            };
+            if !parent_valid {
+                violations.push(InvariantViolation::OrphanedNode { node: *node });
+            }
+
+            if role.is_nexus() {
+                let mut leaves = self.get_leaves_of(node);
+                leaves.sort_by_key(|id| id.0);
+                let count = leaves.len() as u32;
+                let max = self.leaf_capacity(node);
+                if !(MIN_LEAVES_PER_NEXUS..=max).contains(&count) {
+                    violations.push(InvariantViolation::LeafCountOutOfBounds {
+                        nexus: *node,
+                        count,
+                        min: MIN_LEAVES_PER_NEXUS,
+                        max,
+                    });
+                }
+                for leaf in leaves {
+                    if !role.children.contains(&leaf) {
+                        violations
+                            .push(InvariantViolation::NexusInconsistency { nexus: *node, leaf });
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// Check `changes` against a copy of the topology without touching it
+    ///
+    /// Applies the changes in order to a clone and runs
+    /// [`Self::check_structure`] on the result.
+    pub fn validate_proposed(
+        &self,
+        changes: &[TopologyChange],
+    ) -> Result<(), Vec<InvariantViolation>> {
+        let mut proposed = self.clone();
+        for change in changes {
+            proposed.apply_change(change);
+        }
+        let violations = proposed.check_structure();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Groups of nodes joined by parent or child links
+    ///
+    /// Links are followed in both directions; links to unknown nodes are
+    /// ignored. Each group is sorted by node ID, and groups are ordered by
+    /// their first node.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut adjacency: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for (node, topo) in &self.topologies {
+            for other in topo.role.parent.iter().chain(&topo.role.children) {
+                if other != node && self.topologies.contains_key(other) {
+                    adjacency.entry(*node).or_default().push(*other);
+                    adjacency.entry(*other).or_default().push(*node);
+                }
+            }
+        }
+
+        let mut nodes: Vec<NodeId> = self.topologies.keys().copied().collect();
+        nodes.sort_by_key(|id| id.0);
+
+        let mut visited = HashSet::new();
+        let mut components = Vec::new();
+        for start in nodes {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                for next in adjacency.get(&node).into_iter().flatten() {
+                    if visited.insert(*next) {
+                        component.push(*next);
+                        stack.push(*next);
+                    }
+                }
+            }
+            component.sort_by_key(|id| id.0);
+            components.push(component);
+        }
+        components
+    }
+
+    /// True if every known node is reachable from every other; a split
+    /// topology needs a reunification election
+    pub fn is_fully_connected(&self) -> bool {
+        self.connected_components().len() <= 1
+    }
+
+    /// Drop a node, detaching it from its parent's children and forgetting
+    /// its leaf capacity
+    pub fn remove_node(&mut self, node: &NodeId) -> Option<NexusTopology> {
+        let removed = self.topologies.remove(node)?;
+        self.leaf_capacities.remove(node);
+        if let Some(parent) = removed.role.parent {
+            if let Some(topo) = self.topologies.get_mut(&parent) {
+                topo.role.children.retain(|c| c != node);
+                if topo.role.is_nexus() {
+                    topo.leaf_count = topo.role.children.len() as u32;
+                }
+            }
+        }
+        Some(removed)
+    }
+
+    /// Hand the nexus role from `old` to `new`, carrying its leaves along
+    ///
+    /// `new` is first detached from its previous parent, then every leaf of
+    /// `old` is reparented to `new`, `new` becomes a nexus under `old`'s
+    /// parent, and `old` is demoted to a leaf of `new`. Returns the
+    /// reparented leaves (excluding `old` itself).
+    pub fn handoff_nexus(&mut self, old: NodeId, new: NodeId) -> Vec<NodeId> {
+        if old == new {
+            return Vec::new();
+        }
+
+        if let Some(previous) = self.get_role(&new).parent {
+            if let Some(topo) = self.topologies.get_mut(&previous) {
+                topo.role.children.retain(|c| *c != new);
+                if topo.role.is_nexus() {
+                    topo.leaf_count = topo.role.children.len() as u32;
+                }
+            }
+        }
+
+        let old_role = self.get_role(&old);
+
+        // Leaves from both the nexus view and the leaves' own parent pointers
+        let mut leaves = old_role.children.clone();
+        for leaf in self.get_leaves_of(&old) {
+            if !leaves.contains(&leaf) {
+                leaves.push(leaf);
+            }
+        }
+        leaves.retain(|leaf| *leaf != new && *leaf != old);
+
+        for leaf in &leaves {
+            if let Some(topo) = self.topologies.get_mut(leaf) {
+                topo.role.parent = Some(new);
+            }
+        }
+
+        // Point old's parent (e.g. a poteau-mitan) at the new nexus
+        if let Some(parent) = old_role.parent {
+            if let Some(topo) = self.topologies.get_mut(&parent) {
+                topo.role.children.retain(|c| *c != old && *c != new);
+                topo.role.children.push(new);
+            }
+        }
+
+        let mut children = leaves.clone();
+        children.push(old);
+        let leaf_count = children.len() as u32;
+        let new_role = NexusRole::nexus(old_role.parent, children);
+        match self.topologies.get_mut(&new) {
+            Some(topo) => {
+                topo.role = new_role;
+                topo.leaf_count = leaf_count;
+                topo.last_election = Timestamp::now();
+            }
+            None => {
+                self.topologies.insert(
+                    new,
+                    NexusTopology {
+                        node: new,
+                        role: new_role,
+                        aggregated_gradient: ResourceGradient::default(),
+                        leaf_count,
+                        last_election: Timestamp::now(),
+                    },
+                );
+            }
+        }
+
+        match self.topologies.get_mut(&old) {
+            Some(topo) => {
+                topo.role = NexusRole::leaf(new);
+                topo.leaf_count = 0;
+            }
+            None => {
+                self.topologies.insert(
+                    old,
+                    NexusTopology {
+                        node: old,
+                        role: NexusRole::leaf(new),
+                        aggregated_gradient: ResourceGradient::default(),
+                        leaf_count: 0,
+                        last_election: Timestamp::now(),
+                    },
+                );
+            }
+        }
+
+        leaves
+    }
+}
+
+/// A topology entry with default gradient and no leaves, for tests
+#[cfg(test)]
+pub(crate) fn test_topology(node: NodeId, role: NexusRole) -> NexusTopology {
+    NexusTopology {
+        node,
+        role,
+        aggregated_gradient: ResourceGradient::default(),
+        leaf_count: 0,
+        last_election: Timestamp::now(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::Timestamp;
 
     #[test]
     fn test_estimate_direct_entropy() {
@@ -215,4 +628,264 @@ mod tests {
         assert!(role.is_leaf());
         assert_eq!(role.parent, Some(nexus));
     }
+
+    #[test]
+    fn test_overloaded_nexuses() {
+        let mut manager = TopologyManager::new();
+        let busy = NodeId::from_bytes([1u8; 32]);
+        let idle = NodeId::from_bytes([2u8; 32]);
+        let leaf = NodeId::from_bytes([3u8; 32]);
+
+        let topo = |node, role, available: f64| NexusTopology {
+            node,
+            role,
+            aggregated_gradient: ResourceGradient {
+                cpu_available: available,
+                memory_available: available,
+                gpu_available: available,
+                storage_available: available,
+                bandwidth_available: available,
+                credit_balance: 0.0,
+            },
+            leaf_count: 0,
+            last_election: Timestamp::now(),
+        };
+        manager.set_topology(busy, topo(busy, NexusRole::nexus(None, vec![]), 0.1));
+        manager.set_topology(idle, topo(idle, NexusRole::nexus(None, vec![]), 0.8));
+        // Leaves never count, however saturated
+        manager.set_topology(leaf, topo(leaf, NexusRole::leaf(busy), 0.0));
+
+        assert_eq!(manager.overloaded_nexuses(0.2), vec![busy]);
+        assert_eq!(manager.overloaded_nexuses(0.9), vec![busy, idle]);
+        assert!(manager.overloaded_nexuses(0.05).is_empty());
+    }
+
+    #[test]
+    fn test_handoff_nexus() {
+        let mut manager = TopologyManager::new();
+        let poteau = NodeId::from_bytes([9u8; 32]);
+        let old = NodeId::from_bytes([1u8; 32]);
+        let new = NodeId::from_bytes([2u8; 32]);
+        let leaf_a = NodeId::from_bytes([3u8; 32]);
+        let leaf_b = NodeId::from_bytes([4u8; 32]);
+
+        manager.set_topology(
+            poteau,
+            test_topology(poteau, NexusRole::poteau_mitan(vec![old])),
+        );
+        manager.set_topology(
+            old,
+            test_topology(
+                old,
+                NexusRole::nexus(Some(poteau), vec![new, leaf_a, leaf_b]),
+            ),
+        );
+        for leaf in [new, leaf_a, leaf_b] {
+            manager.set_topology(leaf, test_topology(leaf, NexusRole::leaf(old)));
+        }
+
+        let mut moved = manager.handoff_nexus(old, new);
+        moved.sort_by_key(|n| n.0);
+        assert_eq!(moved, vec![leaf_a, leaf_b]);
+
+        let new_role = manager.get_role(&new);
+        assert!(new_role.is_nexus());
+        assert_eq!(new_role.parent, Some(poteau));
+        assert_eq!(new_role.children.len(), 3);
+        assert!(new_role.children.contains(&old));
+        assert_eq!(manager.get_topology(&new).unwrap().leaf_count, 3);
+
+        assert_eq!(manager.get_role(&old), NexusRole::leaf(new));
+        assert_eq!(manager.get_role(&leaf_a).parent, Some(new));
+        assert!(manager.get_leaves_of(&old).is_empty());
+        assert_eq!(manager.get_role(&poteau).children, vec![new]);
+        assert_eq!(manager.get_nexuses(), vec![new]);
+    }
+
+    #[test]
+    fn test_handoff_nexus_detaches_new_from_other_nexus() {
+        let mut manager = TopologyManager::new();
+        let old = NodeId::from_bytes([1u8; 32]);
+        let new = NodeId::from_bytes([2u8; 32]);
+        let other = NodeId::from_bytes([3u8; 32]);
+        let leaf = NodeId::from_bytes([4u8; 32]);
+
+        let topo = |node, role, leaf_count| NexusTopology {
+            node,
+            role,
+            aggregated_gradient: ResourceGradient::default(),
+            leaf_count,
+            last_election: Timestamp::now(),
+        };
+        manager.set_topology(old, topo(old, NexusRole::nexus(None, vec![leaf]), 1));
+        manager.set_topology(leaf, topo(leaf, NexusRole::leaf(old), 0));
+        manager.set_topology(other, topo(other, NexusRole::nexus(None, vec![new]), 1));
+        manager.set_topology(new, topo(new, NexusRole::leaf(other), 0));
+
+        manager.handoff_nexus(old, new);
+
+        // `other` no longer lists the node that just became a nexus
+        assert!(manager.get_role(&other).children.is_empty());
+        assert_eq!(manager.get_topology(&other).unwrap().leaf_count, 0);
+        assert!(manager.get_leaves_of(&other).is_empty());
+        assert_eq!(manager.get_role(&new).parent, None);
+        assert_eq!(manager.get_role(&leaf).parent, Some(new));
+    }
+
+    #[test]
+    fn test_structure_metrics() {
+        let mut manager = TopologyManager::new();
+        assert_eq!(manager.structure_metrics(), TopologyMetrics::default());
+
+        let poteau = NodeId::from_bytes([9u8; 32]);
+        let small = NodeId::from_bytes([1u8; 32]);
+        let large = NodeId::from_bytes([2u8; 32]);
+
+        manager.set_topology(
+            poteau,
+            test_topology(poteau, NexusRole::poteau_mitan(vec![small, large])),
+        );
+        manager.set_topology(
+            small,
+            test_topology(small, NexusRole::nexus(Some(poteau), vec![])),
+        );
+        manager.set_topology(
+            large,
+            test_topology(large, NexusRole::nexus(Some(poteau), vec![])),
+        );
+        manager.set_topology(
+            NodeId::from_bytes([10u8; 32]),
+            test_topology(NodeId::from_bytes([10u8; 32]), NexusRole::leaf(small)),
+        );
+        for i in 20..23u8 {
+            let leaf = NodeId::from_bytes([i; 32]);
+            manager.set_topology(leaf, test_topology(leaf, NexusRole::leaf(large)));
+        }
+
+        // One nexus with 1 leaf, one with 3
+        let metrics = manager.structure_metrics();
+        assert_eq!(metrics.max_depth, 2);
+        assert_eq!(metrics.nexus_count, 2);
+        assert_eq!(metrics.avg_leaves_per_nexus, 2.0);
+        assert_eq!(metrics.balance_factor, 1.0);
+    }
+
+    #[test]
+    fn test_validate_proposed() {
+        let mut manager = TopologyManager::new();
+        let poteau = NodeId::from_bytes([9u8; 32]);
+        let a = NodeId::from_bytes([1u8; 32]);
+        let b = NodeId::from_bytes([2u8; 32]);
+
+        let leaves_a: Vec<NodeId> = (10..16u8).map(|i| NodeId::from_bytes([i; 32])).collect();
+        let leaves_b: Vec<NodeId> = (20..25u8).map(|i| NodeId::from_bytes([i; 32])).collect();
+        manager.set_topology(
+            poteau,
+            test_topology(poteau, NexusRole::poteau_mitan(vec![a, b])),
+        );
+        manager.set_topology(
+            a,
+            test_topology(a, NexusRole::nexus(Some(poteau), leaves_a.clone())),
+        );
+        manager.set_topology(
+            b,
+            test_topology(b, NexusRole::nexus(Some(poteau), leaves_b.clone())),
+        );
+        for leaf in &leaves_a {
+            manager.set_topology(*leaf, test_topology(*leaf, NexusRole::leaf(a)));
+        }
+        for leaf in &leaves_b {
+            manager.set_topology(*leaf, test_topology(*leaf, NexusRole::leaf(b)));
+        }
+        assert!(manager.check_structure().is_empty());
+
+        // a has one leaf to spare; b has none
+        let spare = TopologyChange::Reparent {
+            leaf: leaves_a[0],
+            nexus: b,
+        };
+        assert_eq!(manager.validate_proposed(&[spare]), Ok(()));
+
+        let starve = TopologyChange::Reparent {
+            leaf: leaves_b[0],
+            nexus: a,
+        };
+        let violations = manager.validate_proposed(&[starve]).unwrap_err();
+        assert_eq!(
+            violations,
+            vec![InvariantViolation::LeafCountOutOfBounds {
+                nexus: b,
+                count: 4,
+                min: MIN_LEAVES_PER_NEXUS,
+                max: MAX_LEAVES_PER_NEXUS,
+            }]
+        );
+
+        // Removing a nexus orphans its leaves
+        let violations = manager
+            .validate_proposed(&[TopologyChange::Remove(a)])
+            .unwrap_err();
+        assert_eq!(violations.len(), leaves_a.len());
+        assert!(violations
+            .iter()
+            .all(|v| matches!(v, InvariantViolation::OrphanedNode { .. })));
+
+        // Nothing was applied to the real topology
+        assert_eq!(manager.get_leaves_of(&a).len(), 6);
+        assert_eq!(manager.get_leaves_of(&b).len(), 5);
+
+        // A per-nexus capacity tightens the upper bound
+        manager.set_leaf_capacity(a, 5);
+        assert_eq!(manager.nexus_for_new_leaf(), Some(b));
+        let violations = manager.check_structure();
+        assert!(matches!(
+            violations[..],
+            [InvariantViolation::LeafCountOutOfBounds {
+                count: 6,
+                max: 5,
+                ..
+            }]
+        ));
+
+        // New leaves go where there is room
+        let newcomer = NodeId::from_bytes([99u8; 32]);
+        assert_eq!(manager.assign_leaf(newcomer), Some(b));
+        assert_eq!(manager.get_role(&newcomer).parent, Some(b));
+        assert!(manager.get_leaves_of(&b).contains(&newcomer));
+
+        manager.remove_node(&a);
+        assert_eq!(manager.leaf_capacity(&a), MAX_LEAVES_PER_NEXUS);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut manager = TopologyManager::new();
+        assert!(manager.is_fully_connected());
+
+        let id = |i: u8| NodeId::from_bytes([i; 32]);
+        manager.set_topology(
+            id(1),
+            test_topology(id(1), NexusRole::poteau_mitan(vec![id(2)])),
+        );
+        manager.set_topology(
+            id(2),
+            test_topology(id(2), NexusRole::nexus(Some(id(1)), vec![])),
+        );
+        manager.set_topology(id(3), test_topology(id(3), NexusRole::leaf(id(2))));
+        assert!(manager.is_fully_connected());
+
+        // A nexus that lost its poteau-mitan, and a leaf of a vanished nexus
+        manager.set_topology(
+            id(4),
+            test_topology(id(4), NexusRole::nexus(None, vec![id(5)])),
+        );
+        manager.set_topology(id(5), test_topology(id(5), NexusRole::leaf(id(4))));
+        manager.set_topology(id(6), test_topology(id(6), NexusRole::leaf(id(9))));
+
+        assert!(!manager.is_fully_connected());
+        assert_eq!(
+            manager.connected_components(),
+            vec![vec![id(1), id(2), id(3)], vec![id(4), id(5)], vec![id(6)]]
+        );
+    }
 }
@@ -2,13 +2,15 @@
 //!
 //! Rust implementation from dol/nexus.dol and dol/core.dol
 
-use crate::core::{Credits, NodeId, Timestamp};
+use crate::core::{Credits, Duration, NodeId, Timestamp};
+use crate::entropy::{EntropyAccount, MultiplierCurve};
 use serde::{Deserialize, Serialize};
 
 /// NexusRoleType - from dol/core.dol line 257
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum NexusRoleType {
     /// Regular node
+    #[default]
     Leaf,
     /// Hub node
     Nexus,
@@ -16,12 +18,6 @@ pub enum NexusRoleType {
     PoteauMitan,
 }
 
-impl Default for NexusRoleType {
-    fn default() -> Self {
-        NexusRoleType::Leaf
-    }
-}
-
 /// NexusRole - from dol/core.dol line 242
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NexusRole {
@@ -117,6 +113,38 @@ impl ResourceGradient {
     pub fn zero() -> Self {
         Self::default()
     }
+
+    /// Mean availability across the five resource dimensions
+    ///
+    /// `credit_balance` is not a resource and is excluded.
+    pub fn average_availability(&self) -> f64 {
+        (self.cpu_available
+            + self.memory_available
+            + self.gpu_available
+            + self.storage_available
+            + self.bandwidth_available)
+            / 5.0
+    }
+
+    /// Estimate entropy from resource scarcity
+    ///
+    /// Each component scales with unavailability up to
+    /// `EntropyAccount::MAX_COMPONENT`: compute from CPU and memory,
+    /// storage from storage, network from bandwidth. Temporal entropy
+    /// has no resource signal and is left at zero.
+    pub fn estimated_entropy(&self) -> EntropyAccount {
+        let scarcity = |available: f64| {
+            let available = if available.is_nan() { 0.0 } else { available };
+            (1.0 - available.clamp(0.0, 1.0)) * EntropyAccount::MAX_COMPONENT
+        };
+
+        EntropyAccount {
+            network: scarcity(self.bandwidth_available),
+            compute: (scarcity(self.cpu_available) + scarcity(self.memory_available)) / 2.0,
+            storage: scarcity(self.storage_available),
+            temporal: 0.0,
+        }
+    }
 }
 
 /// NexusTopology - from dol/core.dol line 308
@@ -164,6 +192,12 @@ impl GossipPath {
             estimated_entropy: entropy,
         }
     }
+
+    /// Price of sending along this path: `base` scaled by the curve's
+    /// multiplier for the path's estimated entropy
+    pub fn credit_cost(&self, base: Credits, curve: &MultiplierCurve) -> Credits {
+        Credits::from_f64_saturating(base.amount as f64 * curve.evaluate(self.estimated_entropy))
+    }
 }
 
 /// LeafGradientReport - from dol/nexus.dol line 164
@@ -221,6 +255,34 @@ pub struct Order {
     pub timestamp: Timestamp,
 }
 
+/// Which side of an order book
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+/// Default maximum orders per side of a book
+pub const DEFAULT_MAX_BOOK_DEPTH: usize = 1_000;
+
+fn default_max_book_depth() -> usize {
+    DEFAULT_MAX_BOOK_DEPTH
+}
+
+/// Order book insertion errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum OrderBookError {
+    #[error("order book side is full (max depth {max_depth})")]
+    BookFull { max_depth: usize },
+}
+
+/// Total ask inventory, with a flag set if the sum hit `u64::MAX`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inventory {
+    pub quantity: u64,
+    pub saturated: bool,
+}
+
 /// OrderBook - from dol/nexus.dol line 355
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrderBook {
@@ -229,15 +291,47 @@ pub struct OrderBook {
     pub bids: Vec<Order>,
     /// Sorted by price ascending
     pub asks: Vec<Order>,
+    /// Maximum orders accepted per side by `insert_bid`/`insert_ask`
+    #[serde(default = "default_max_book_depth")]
+    pub max_depth: usize,
 }
 
 impl OrderBook {
     pub fn new(resource: ResourceType) -> Self {
+        Self::with_max_depth(resource, DEFAULT_MAX_BOOK_DEPTH)
+    }
+
+    pub fn with_max_depth(resource: ResourceType, max_depth: usize) -> Self {
         Self {
             resource,
             bids: Vec::new(),
             asks: Vec::new(),
+            max_depth,
+        }
+    }
+
+    /// Insert a bid, keeping bids sorted by price descending
+    pub fn insert_bid(&mut self, order: Order) -> Result<(), OrderBookError> {
+        if self.bids.len() >= self.max_depth {
+            return Err(OrderBookError::BookFull {
+                max_depth: self.max_depth,
+            });
+        }
+        let pos = self.bids.partition_point(|o| o.price >= order.price);
+        self.bids.insert(pos, order);
+        Ok(())
+    }
+
+    /// Insert an ask, keeping asks sorted by price ascending
+    pub fn insert_ask(&mut self, order: Order) -> Result<(), OrderBookError> {
+        if self.asks.len() >= self.max_depth {
+            return Err(OrderBookError::BookFull {
+                max_depth: self.max_depth,
+            });
         }
+        let pos = self.asks.partition_point(|o| o.price <= order.price);
+        self.asks.insert(pos, order);
+        Ok(())
     }
 
     pub fn best_bid(&self) -> Option<Credits> {
@@ -248,8 +342,65 @@ impl OrderBook {
         self.asks.first().map(|o| o.price)
     }
 
+    /// Resting orders on one side, best price first
+    pub fn orders(&self, side: Side) -> &[Order] {
+        match side {
+            Side::Bid => &self.bids,
+            Side::Ask => &self.asks,
+        }
+    }
+
+    /// Midpoint of best bid and best ask, rounded down
+    pub fn mid_price(&self) -> Option<Credits> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        let mid = (bid.amount as u128 + ask.amount as u128) / 2;
+        Some(Credits::new(mid as u64))
+    }
+
+    /// Volume-weighted average price of one side, rounded down
+    ///
+    /// `None` if the side holds no quantity.
+    pub fn vwap(&self, side: Side) -> Option<Credits> {
+        let (value, quantity) =
+            self.orders(side)
+                .iter()
+                .fold((0u128, 0u128), |(value, quantity), o| {
+                    (
+                        value.saturating_add(o.price.amount as u128 * o.quantity as u128),
+                        quantity + o.quantity as u128,
+                    )
+                });
+        (quantity > 0).then(|| Credits::new((value / quantity) as u64))
+    }
+
+    /// Total ask inventory, saturating at `u64::MAX`
     pub fn total_inventory(&self) -> u64 {
-        self.asks.iter().map(|o| o.quantity).sum()
+        self.inventory().quantity
+    }
+
+    /// Total ask inventory with saturation reported
+    pub fn inventory(&self) -> Inventory {
+        let mut saturated = false;
+        let quantity = self.asks.iter().fold(0u64, |acc, o| {
+            acc.checked_add(o.quantity).unwrap_or_else(|| {
+                saturated = true;
+                u64::MAX
+            })
+        });
+        Inventory {
+            quantity,
+            saturated,
+        }
+    }
+
+    /// Value (price × quantity) of a node's resting bids, saturating
+    pub fn bid_exposure(&self, node: &NodeId) -> Credits {
+        self.bids
+            .iter()
+            .filter(|o| o.node == *node)
+            .fold(Credits::ZERO, |acc, o| {
+                acc.saturating_add(Credits::new(o.price.amount.saturating_mul(o.quantity)))
+            })
     }
 
     pub fn spread(&self) -> Option<Credits> {
@@ -270,6 +421,24 @@ pub struct MarketMakerConfig {
     pub inventory_factor: f64,
     pub entropy_spread_factor: f64,
     pub target_inventory: u64,
+    /// Cap on the total value of the maker's outstanding bids
+    #[serde(default = "unlimited_exposure")]
+    pub max_quote_exposure: Credits,
+    /// Time after which a re-quote is always allowed (zero: every update)
+    #[serde(default = "no_requote_interval")]
+    pub min_requote_interval: Duration,
+    /// Fractional mid-price move that allows a re-quote before the
+    /// interval elapses (zero: any move)
+    #[serde(default)]
+    pub min_requote_change: f64,
+}
+
+fn unlimited_exposure() -> Credits {
+    Credits::new(u64::MAX)
+}
+
+fn no_requote_interval() -> Duration {
+    Duration::new(0)
 }
 
 impl Default for MarketMakerConfig {
@@ -280,6 +449,9 @@ impl Default for MarketMakerConfig {
             inventory_factor: 0.3,
             entropy_spread_factor: 0.1,
             target_inventory: 1000,
+            max_quote_exposure: unlimited_exposure(),
+            min_requote_interval: no_requote_interval(),
+            min_requote_change: 0.0,
         }
     }
 }
@@ -321,6 +493,52 @@ mod tests {
         assert!(!invalid.is_valid());
     }
 
+    #[test]
+    fn test_estimated_entropy() {
+        let idle = ResourceGradient {
+            cpu_available: 1.0,
+            memory_available: 1.0,
+            gpu_available: 1.0,
+            storage_available: 1.0,
+            bandwidth_available: 1.0,
+            credit_balance: 0.0,
+        };
+        assert_eq!(idle.estimated_entropy(), EntropyAccount::zero());
+
+        let busy = ResourceGradient {
+            cpu_available: 0.2,
+            memory_available: 0.6,
+            storage_available: 0.5,
+            bandwidth_available: 0.0,
+            ..Default::default()
+        };
+        let entropy = busy.estimated_entropy();
+        assert!((entropy.compute - 6.0).abs() < 0.001);
+        assert!((entropy.storage - 5.0).abs() < 0.001);
+        assert!((entropy.network - EntropyAccount::MAX_COMPONENT).abs() < 0.001);
+        assert!(entropy.is_valid());
+
+        // Out-of-range gradients still yield a valid account
+        let bogus = ResourceGradient {
+            cpu_available: -3.0,
+            memory_available: f64::NAN,
+            storage_available: 4.0,
+            ..Default::default()
+        };
+        assert!(bogus.estimated_entropy().is_valid());
+    }
+
+    #[test]
+    fn test_gossip_path_credit_cost() {
+        let target = NodeId::from_bytes([2u8; 32]);
+        let curve = MultiplierCurve::new(vec![(0.0, 1.0), (10.0, 3.0)], 5.0);
+
+        let calm = GossipPath::direct(target, 0.0);
+        let noisy = GossipPath::via_hub(NodeId::from_bytes([3u8; 32]), target, 5.0);
+        assert_eq!(calm.credit_cost(Credits::new(100), &curve).amount, 100);
+        assert_eq!(noisy.credit_cost(Credits::new(100), &curve).amount, 200);
+    }
+
     #[test]
     fn test_order_book() {
         let node = NodeId::from_bytes([1u8; 32]);
@@ -345,4 +563,79 @@ mod tests {
         assert_eq!(book.spread(), Some(Credits::new(10)));
         assert_eq!(book.total_inventory(), 5);
     }
+
+    #[test]
+    fn test_order_book_insert_and_depth() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let order = |price, quantity| Order {
+            price: Credits::new(price),
+            quantity,
+            node,
+            timestamp: Timestamp::now(),
+        };
+        let mut book = OrderBook::with_max_depth(ResourceType::Storage, 2);
+
+        book.insert_ask(order(120, 1)).unwrap();
+        book.insert_ask(order(110, 1)).unwrap();
+        assert_eq!(book.best_ask(), Some(Credits::new(110)));
+        assert_eq!(
+            book.insert_ask(order(100, 1)),
+            Err(OrderBookError::BookFull { max_depth: 2 })
+        );
+
+        book.insert_bid(order(90, 1)).unwrap();
+        book.insert_bid(order(95, 1)).unwrap();
+        assert_eq!(book.best_bid(), Some(Credits::new(95)));
+        assert!(book.insert_bid(order(80, 1)).is_err());
+    }
+
+    #[test]
+    fn test_order_book_inventory_saturates() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let mut book = OrderBook::new(ResourceType::Storage);
+        for _ in 0..2 {
+            book.asks.push(Order {
+                price: Credits::new(1),
+                quantity: u64::MAX - 1,
+                node,
+                timestamp: Timestamp::now(),
+            });
+        }
+
+        let inventory = book.inventory();
+        assert!(inventory.saturated);
+        assert_eq!(inventory.quantity, u64::MAX);
+        assert_eq!(book.total_inventory(), u64::MAX);
+        assert!(!OrderBook::new(ResourceType::Cpu).inventory().saturated);
+    }
+
+    #[test]
+    fn test_order_book_vwap_and_mid() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let order = |price, quantity| Order {
+            price: Credits::new(price),
+            quantity,
+            node,
+            timestamp: Timestamp::now(),
+        };
+        let mut book = OrderBook::new(ResourceType::Cpu);
+        assert_eq!(book.vwap(Side::Bid), None);
+        assert_eq!(book.mid_price(), None);
+
+        book.insert_bid(order(100, 1)).unwrap();
+        book.insert_bid(order(90, 3)).unwrap();
+        book.insert_ask(order(110, 2)).unwrap();
+        book.insert_ask(order(130, 2)).unwrap();
+
+        // (100 + 270) / 4, rounded down
+        assert_eq!(book.vwap(Side::Bid), Some(Credits::new(92)));
+        assert_eq!(book.vwap(Side::Ask), Some(Credits::new(120)));
+        assert_eq!(book.mid_price(), Some(Credits::new(105)));
+
+        // Products past u64 don't overflow
+        let mut deep = OrderBook::new(ResourceType::Cpu);
+        deep.insert_ask(order(u64::MAX, u64::MAX)).unwrap();
+        deep.insert_ask(order(u64::MAX, 1)).unwrap();
+        assert_eq!(deep.vwap(Side::Ask), Some(Credits::new(u64::MAX)));
+    }
 }
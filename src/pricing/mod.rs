@@ -8,26 +8,35 @@
 //! - Auction: Market-based price discovery
 
 use crate::core::Credits;
-use crate::entropy::{EntropyAccount, EntropyWeights};
+use crate::entropy::calculator::{
+    weighted_entropy_sum, EntropyCalculator, VersionedWeights, CACHE_MISS_FACTOR,
+    CLOCK_DRIFT_FACTOR, COMPACTION_DEBT_FACTOR, CONTEXT_SWITCH_FACTOR, CYCLE_ENTROPY_FACTOR,
+    FRAGMENTATION_FACTOR, HOP_ENTROPY_BASE, LATENCY_ENTROPY_FACTOR, LOSS_ENTROPY_FACTOR,
+    MAX_COMPUTE_ENTROPY, MAX_NETWORK_ENTROPY, MAX_STORAGE_ENTROPY, MAX_TEMPORAL_ENTROPY,
+    MEMORY_ENTROPY_FACTOR, ORDERING_UNCERTAINTY_FACTOR, REPLICA_DIVERGENCE_FACTOR,
+    SATURATION_ENTROPY_FACTOR, SIZE_ENTROPY_FACTOR, STALENESS_ENTROPY_FACTOR,
+    VERSION_DIVERGENCE_FACTOR,
+};
+use crate::entropy::{
+    ComputeEntropyInput, EntropyAccount, EntropyWeights, MultiplierCurve, NetworkEntropyInput,
+    StorageEntropyInput, TemporalEntropyInput,
+};
+use crate::nexus::ResourceType;
+use crate::revival::ENTROPY_TAX_BPS;
 use serde::{Deserialize, Serialize};
 
 /// Pricing model types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum PricingModel {
     /// Static prices for predictable workloads
     Fixed,
     /// Entropy-adjusted prices
+    #[default]
     Dynamic,
     /// Market-based price discovery
     Auction,
 }
 
-impl Default for PricingModel {
-    fn default() -> Self {
-        PricingModel::Dynamic
-    }
-}
-
 /// Fixed price configuration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FixedPriceConfig {
@@ -48,6 +57,18 @@ impl Default for FixedPriceConfig {
     }
 }
 
+impl FixedPriceConfig {
+    /// Price per unit of a resource (GPU is priced at the CPU rate)
+    pub fn unit_price(&self, resource: ResourceType) -> u64 {
+        match resource {
+            ResourceType::Cpu | ResourceType::Gpu => self.cpu_per_cycle,
+            ResourceType::Memory => self.memory_per_mb,
+            ResourceType::Storage => self.storage_per_gb,
+            ResourceType::Bandwidth => self.bandwidth_per_mb,
+        }
+    }
+}
+
 /// Dynamic price configuration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DynamicPriceConfig {
@@ -90,8 +111,273 @@ pub fn calculate_dynamic_price(config: &DynamicPriceConfig, entropy: &EntropyAcc
     let multiplier =
         entropy_price_multiplier(entropy).clamp(config.min_multiplier, config.max_multiplier);
 
-    let adjusted = (config.base_price.amount as f64 * multiplier) as u64;
-    Credits::new(adjusted)
+    Credits::from_f64_saturating(config.base_price.amount as f64 * multiplier)
+}
+
+/// Entropy level implied by charging `charged` against a `base` price
+///
+/// Inverts price → multiplier → weighted total entropy along `curve`,
+/// then spreads the total evenly: the result is the level every entropy
+/// component would need for this price. Returns `None` for a zero base or
+/// weights, or a multiplier the curve can't produce.
+pub fn implied_entropy(
+    base: Credits,
+    charged: Credits,
+    weights: &EntropyWeights,
+    curve: &MultiplierCurve,
+) -> Option<f64> {
+    if base.is_zero() {
+        return None;
+    }
+    let weight_sum = weights.network_weight
+        + weights.compute_weight
+        + weights.storage_weight
+        + weights.temporal_weight;
+    if weight_sum <= 0.0 {
+        return None;
+    }
+    let multiplier = charged.amount as f64 / base.amount as f64;
+    curve.invert(multiplier).map(|total| total / weight_sum)
+}
+
+/// Upper bound on the congestion price multiplier
+pub const MAX_CONGESTION_MULTIPLIER: f64 = 10.0;
+
+/// Calculate a congestion price for a resource at a network saturation level
+///
+/// Formula: price = base * (1 + s² / (1 - s)), capped at
+/// `MAX_CONGESTION_MULTIPLIER`. Nearly flat at low saturation, rising
+/// sharply as saturation approaches 1.0. Saturation is clamped to [0, 1];
+/// NaN is treated as uncongested.
+pub fn congestion_price(base: Credits, network_saturation: f64) -> Credits {
+    let s = if network_saturation.is_nan() {
+        0.0
+    } else {
+        network_saturation.clamp(0.0, 1.0)
+    };
+    let multiplier = if s >= 1.0 {
+        MAX_CONGESTION_MULTIPLIER
+    } else {
+        (1.0 + s * s / (1.0 - s)).min(MAX_CONGESTION_MULTIPLIER)
+    };
+    Credits::from_f64_saturating(base.amount as f64 * multiplier)
+}
+
+/// Entropy calculation coefficients from dol/entropy.dol lines 28-62
+///
+/// Each array holds the four factors of one component, in formula order.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropyCoefficients {
+    /// α: hops, latency variance, loss, saturation
+    pub network: [f64; 4],
+    /// β: cycles, memory, context switches, cache misses
+    pub compute: [f64; 4],
+    /// γ: size, replica divergence, fragmentation, compaction debt
+    pub storage: [f64; 4],
+    /// δ: staleness, clock drift, ordering, version divergence
+    pub temporal: [f64; 4],
+}
+
+impl Default for EntropyCoefficients {
+    fn default() -> Self {
+        Self {
+            network: [
+                HOP_ENTROPY_BASE,
+                LATENCY_ENTROPY_FACTOR,
+                LOSS_ENTROPY_FACTOR,
+                SATURATION_ENTROPY_FACTOR,
+            ],
+            compute: [
+                CYCLE_ENTROPY_FACTOR,
+                MEMORY_ENTROPY_FACTOR,
+                CONTEXT_SWITCH_FACTOR,
+                CACHE_MISS_FACTOR,
+            ],
+            storage: [
+                SIZE_ENTROPY_FACTOR,
+                REPLICA_DIVERGENCE_FACTOR,
+                FRAGMENTATION_FACTOR,
+                COMPACTION_DEBT_FACTOR,
+            ],
+            temporal: [
+                STALENESS_ENTROPY_FACTOR,
+                CLOCK_DRIFT_FACTOR,
+                ORDERING_UNCERTAINTY_FACTOR,
+                VERSION_DIVERGENCE_FACTOR,
+            ],
+        }
+    }
+}
+
+impl EntropyCoefficients {
+    /// Entropy of each component under these coefficients
+    ///
+    /// The formulas of `calculate_*_entropy` with these factors in place of
+    /// the built-in constants; missing inputs contribute zero.
+    pub fn calculate(
+        &self,
+        network: Option<&NetworkEntropyInput>,
+        compute: Option<&ComputeEntropyInput>,
+        storage: Option<&StorageEntropyInput>,
+        temporal: Option<&TemporalEntropyInput>,
+    ) -> EntropyAccount {
+        fn combine(terms: [f64; 4], factors: &[f64; 4], max: f64) -> f64 {
+            let entropy: f64 = terms.iter().zip(factors).map(|(t, f)| t * f).sum();
+            entropy.min(max)
+        }
+
+        EntropyAccount {
+            network: network
+                .map(|n| {
+                    let terms = [
+                        n.hops as f64,
+                        n.latency_variance_ms,
+                        n.packet_loss_probability,
+                        n.bandwidth_saturation,
+                    ];
+                    combine(terms, &self.network, MAX_NETWORK_ENTROPY)
+                })
+                .unwrap_or(0.0),
+            compute: compute
+                .map(|c| {
+                    let terms = [
+                        c.cpu_cycles as f64,
+                        c.memory_bytes as f64,
+                        c.context_switches as f64,
+                        c.cache_miss_rate,
+                    ];
+                    combine(terms, &self.compute, MAX_COMPUTE_ENTROPY)
+                })
+                .unwrap_or(0.0),
+            storage: storage
+                .map(|s| {
+                    let terms = [
+                        s.size_bytes as f64,
+                        s.replica_divergence,
+                        s.fragmentation_ratio,
+                        s.compaction_debt,
+                    ];
+                    combine(terms, &self.storage, MAX_STORAGE_ENTROPY)
+                })
+                .unwrap_or(0.0),
+            temporal: temporal
+                .map(|t| {
+                    let terms = [
+                        t.staleness_seconds,
+                        t.clock_drift_ms,
+                        t.ordering_uncertainty,
+                        t.version_divergence,
+                    ];
+                    combine(terms, &self.temporal, MAX_TEMPORAL_ENTROPY)
+                })
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+/// FNV-1a, for hashes that are the same across builds and platforms
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+/// Every parameter that determines a dynamic price
+///
+/// Published alongside quotes so a price can be traced back to the exact
+/// rules it was computed under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricingParameters {
+    pub coefficients: EntropyCoefficients,
+    pub weights: EntropyWeights,
+    pub curve: MultiplierCurve,
+    /// Entropy tax rate in basis points
+    pub tax_bps: u32,
+}
+
+impl Default for PricingParameters {
+    fn default() -> Self {
+        Self {
+            coefficients: EntropyCoefficients::default(),
+            weights: EntropyWeights::default(),
+            curve: MultiplierCurve::default(),
+            tax_bps: ENTROPY_TAX_BPS,
+        }
+    }
+}
+
+impl PricingParameters {
+    /// Stable content hash of the parameters
+    ///
+    /// FNV-1a over the little-endian bytes of every field, so the value is
+    /// the same across builds and platforms.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a(Fnv1a::OFFSET);
+        self.write_hash(&mut hasher);
+        hasher.0
+    }
+
+    /// Hash of these parameters applied under `config`: the value stamped
+    /// on quotes, covering the base price and multiplier bounds too
+    pub fn quote_hash(&self, config: &DynamicPriceConfig) -> u64 {
+        let mut hasher = Fnv1a(Fnv1a::OFFSET);
+        self.write_hash(&mut hasher);
+        hasher.write(&config.base_price.amount.to_le_bytes());
+        hasher.write(&config.min_multiplier.to_bits().to_le_bytes());
+        hasher.write(&config.max_multiplier.to_bits().to_le_bytes());
+        hasher.0
+    }
+
+    fn write_hash(&self, hasher: &mut Fnv1a) {
+        let mut write = |bytes: &[u8]| hasher.write(bytes);
+
+        let c = &self.coefficients;
+        for factor in c
+            .network
+            .iter()
+            .chain(&c.compute)
+            .chain(&c.storage)
+            .chain(&c.temporal)
+        {
+            write(&factor.to_bits().to_le_bytes());
+        }
+        let w = &self.weights;
+        for weight in [
+            w.network_weight,
+            w.compute_weight,
+            w.storage_weight,
+            w.temporal_weight,
+        ] {
+            write(&weight.to_bits().to_le_bytes());
+        }
+        write(&(self.curve.points.len() as u64).to_le_bytes());
+        for (entropy, multiplier) in &self.curve.points {
+            write(&entropy.to_bits().to_le_bytes());
+            write(&multiplier.to_bits().to_le_bytes());
+        }
+        write(&self.curve.cap.to_bits().to_le_bytes());
+        match self.curve.dynamic_cap {
+            Some(dynamic) => {
+                write(&[1]);
+                write(&dynamic.base_cap.to_bits().to_le_bytes());
+                write(&dynamic.demand_factor.to_bits().to_le_bytes());
+            }
+            None => write(&[0]),
+        }
+        write(&self.tax_bps.to_le_bytes());
+        // Appended only when set so uncapped hashes are unchanged
+        if let Some(cap) = self.weights.max_contribution {
+            write(&cap.to_bits().to_le_bytes());
+        }
+    }
 }
 
 /// Price quote for a resource request
@@ -102,6 +388,16 @@ pub struct PriceQuote {
     pub total_price: Credits,
     pub entropy_multiplier: f64,
     pub model: PricingModel,
+    /// Revival entropy tax included in `total_price` (zero unless all-in)
+    #[serde(default)]
+    pub entropy_tax: Credits,
+    /// `PricingParameters::quote_hash` of the rules and dynamic price config
+    /// that produced this quote
+    #[serde(default)]
+    pub parameters_hash: Option<u64>,
+    /// Version of the calculator weights that produced this quote
+    #[serde(default)]
+    pub weights_version: Option<u64>,
 }
 
 impl PriceQuote {
@@ -112,11 +408,14 @@ impl PriceQuote {
             total_price: price,
             entropy_multiplier: 1.0,
             model: PricingModel::Fixed,
+            entropy_tax: Credits::ZERO,
+            parameters_hash: None,
+            weights_version: None,
         }
     }
 
     pub fn dynamic(base: Credits, multiplier: f64) -> Self {
-        let total = Credits::new((base.amount as f64 * multiplier) as u64);
+        let total = Credits::from_f64_saturating(base.amount as f64 * multiplier);
         let adjustment = if total.amount > base.amount {
             Credits::new(total.amount - base.amount)
         } else {
@@ -129,8 +428,19 @@ impl PriceQuote {
             total_price: total,
             entropy_multiplier: multiplier,
             model: PricingModel::Dynamic,
+            entropy_tax: Credits::ZERO,
+            parameters_hash: None,
+            weights_version: None,
         }
     }
+
+    /// Whether this quote was priced under weights other than `current_version`
+    ///
+    /// Quotes that didn't record a weights version are never considered stale.
+    pub fn is_stale(&self, current_version: u64) -> bool {
+        self.weights_version
+            .is_some_and(|version| version != current_version)
+    }
 }
 
 /// Pricer for generating quotes
@@ -138,6 +448,8 @@ pub struct Pricer {
     fixed_config: FixedPriceConfig,
     dynamic_config: DynamicPriceConfig,
     default_model: PricingModel,
+    parameters: Option<PricingParameters>,
+    calculator: Option<EntropyCalculator>,
 }
 
 impl Default for Pricer {
@@ -152,6 +464,8 @@ impl Pricer {
             fixed_config: FixedPriceConfig::default(),
             dynamic_config: DynamicPriceConfig::default(),
             default_model: PricingModel::Dynamic,
+            parameters: None,
+            calculator: None,
         }
     }
 
@@ -165,6 +479,70 @@ impl Pricer {
         self
     }
 
+    /// Price dynamically under explicit parameters instead of the built-in
+    /// curve and tax rate, stamping their hash on every dynamic quote
+    pub fn with_parameters(mut self, parameters: PricingParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn parameters(&self) -> Option<&PricingParameters> {
+        self.parameters.as_ref()
+    }
+
+    /// Weight entropy with a calculator's weights, stamping their version
+    /// on every dynamic quote
+    ///
+    /// The calculator's weights take precedence over any in
+    /// [`PricingParameters`]; the parameters' curve still applies. Retune
+    /// through [`Self::calculator_mut`].
+    pub fn with_calculator(mut self, calculator: EntropyCalculator) -> Self {
+        self.calculator = Some(calculator);
+        self
+    }
+
+    pub fn calculator(&self) -> Option<&EntropyCalculator> {
+        self.calculator.as_ref()
+    }
+
+    pub fn calculator_mut(&mut self) -> Option<&mut EntropyCalculator> {
+        self.calculator.as_mut()
+    }
+
+    /// Measure entropy from raw inputs under the published coefficients
+    /// (the built-in ones without [`PricingParameters`]), ready to quote
+    pub fn measure_entropy(
+        &self,
+        network: Option<&NetworkEntropyInput>,
+        compute: Option<&ComputeEntropyInput>,
+        storage: Option<&StorageEntropyInput>,
+        temporal: Option<&TemporalEntropyInput>,
+    ) -> EntropyAccount {
+        let coefficients = self
+            .parameters
+            .as_ref()
+            .map(|p| p.coefficients)
+            .unwrap_or_default();
+        coefficients.calculate(network, compute, storage, temporal)
+    }
+
+    /// Record the parameters hash and weights version on `quote`
+    ///
+    /// When a calculator supplied the weights, the hash covers those
+    /// weights rather than the ones in the parameters.
+    fn stamp(&self, mut quote: PriceQuote, weights: Option<VersionedWeights>) -> PriceQuote {
+        quote.parameters_hash = self.parameters.as_ref().map(|p| match weights {
+            Some(used) => PricingParameters {
+                weights: used.weights,
+                ..p.clone()
+            }
+            .quote_hash(&self.dynamic_config),
+            None => p.quote_hash(&self.dynamic_config),
+        });
+        quote.weights_version = weights.map(|used| used.version);
+        quote
+    }
+
     pub fn quote_fixed(
         &self,
         cpu_cycles: u64,
@@ -182,22 +560,80 @@ impl Pricer {
         PriceQuote::fixed(price)
     }
 
-    pub fn quote_dynamic(&self, entropy: &EntropyAccount) -> PriceQuote {
+    /// Entropy multiplier under the configured curve and bounds, with the
+    /// calculator weights it was computed under
+    fn multiplier(&self, entropy: &EntropyAccount) -> (f64, Option<VersionedWeights>) {
         use crate::entropy::entropy_price_multiplier;
 
-        let multiplier = entropy_price_multiplier(entropy).clamp(
+        let (multiplier, weights) = match (&self.calculator, &self.parameters) {
+            (Some(calc), params) => {
+                let weights = calc.versioned_weights();
+                let total = weighted_entropy_sum(entropy, &weights.weights);
+                let multiplier = match params {
+                    Some(p) => p.curve.evaluate(total),
+                    None => MultiplierCurve::default().evaluate(total),
+                };
+                (multiplier, Some(weights))
+            }
+            (None, Some(p)) => (
+                p.curve.evaluate(weighted_entropy_sum(entropy, &p.weights)),
+                None,
+            ),
+            (None, None) => (entropy_price_multiplier(entropy), None),
+        };
+        let multiplier = multiplier.clamp(
             self.dynamic_config.min_multiplier,
             self.dynamic_config.max_multiplier,
         );
+        (multiplier, weights)
+    }
 
-        PriceQuote::dynamic(self.dynamic_config.base_price, multiplier)
+    pub fn quote_dynamic(&self, entropy: &EntropyAccount) -> PriceQuote {
+        let (multiplier, weights) = self.multiplier(entropy);
+        self.stamp(
+            PriceQuote::dynamic(self.dynamic_config.base_price, multiplier),
+            weights,
+        )
+    }
+
+    /// Quote `usage` units of one resource: the fixed price for that usage,
+    /// scaled by the entropy multiplier
+    pub fn quote_resource(
+        &self,
+        resource: ResourceType,
+        usage: u64,
+        entropy: &EntropyAccount,
+    ) -> PriceQuote {
+        let base = Credits::new(usage.saturating_mul(self.fixed_config.unit_price(resource)));
+        let (multiplier, weights) = self.multiplier(entropy);
+        self.stamp(PriceQuote::dynamic(base, multiplier), weights)
+    }
+
+    /// Dynamic quote with the revival entropy tax added on top
+    ///
+    /// `total_price` is the all-in cost; the tax is broken out in
+    /// `entropy_tax`. From dol/revival.dol lines 377-388
+    pub fn quote_all_in(&self, entropy: &EntropyAccount) -> PriceQuote {
+        use crate::revival::calculate_entropy_tax;
+
+        let mut quote = self.quote_dynamic(entropy);
+        quote.entropy_tax = match &self.parameters {
+            Some(p) => quote.total_price.basis_points(p.tax_bps),
+            None => calculate_entropy_tax(quote.total_price),
+        };
+        quote.total_price = quote.total_price.saturating_add(quote.entropy_tax);
+        quote
     }
 
     pub fn quote(&self, entropy: Option<&EntropyAccount>) -> PriceQuote {
         match (self.default_model, entropy) {
             (PricingModel::Dynamic, Some(e)) => self.quote_dynamic(e),
             (PricingModel::Dynamic, None) => {
-                PriceQuote::dynamic(self.dynamic_config.base_price, 1.0)
+                let weights = self.calculator.as_ref().map(|c| c.versioned_weights());
+                self.stamp(
+                    PriceQuote::dynamic(self.dynamic_config.base_price, 1.0),
+                    weights,
+                )
             }
             _ => self.quote_fixed(0, 0, 0, 0),
         }
@@ -245,4 +681,227 @@ mod tests {
         let quote = pricer.quote_fixed(100, 10, 1, 50);
         assert!(quote.total_price.amount > 0);
     }
+
+    #[test]
+    fn test_congestion_price() {
+        let base = Credits::new(1_000);
+        assert_eq!(congestion_price(base, 0.0), base);
+        assert_eq!(congestion_price(base, 0.5), Credits::new(1_500));
+        assert_eq!(congestion_price(base, 0.9), Credits::new(9_100));
+        assert_eq!(congestion_price(base, 1.0), Credits::new(10_000));
+        assert_eq!(congestion_price(base, 2.0), Credits::new(10_000));
+        assert_eq!(congestion_price(base, -1.0), base);
+        assert_eq!(congestion_price(base, f64::NAN), base);
+
+        // Monotonic and steepening
+        let low = congestion_price(base, 0.2).amount - congestion_price(base, 0.1).amount;
+        let high = congestion_price(base, 0.8).amount - congestion_price(base, 0.7).amount;
+        assert!(high > low);
+    }
+
+    #[test]
+    fn test_quote_all_in() {
+        let pricer = Pricer::new().with_dynamic_config(DynamicPriceConfig {
+            base_price: Credits::new(1_000),
+            ..Default::default()
+        });
+        let entropy = EntropyAccount::zero();
+
+        let dynamic = pricer.quote_dynamic(&entropy);
+        let all_in = pricer.quote_all_in(&entropy);
+
+        // 2% entropy tax on the entropy-adjusted price
+        assert_eq!(all_in.entropy_tax, Credits::new(20));
+        assert_eq!(all_in.total_price, dynamic.total_price + Credits::new(20));
+        assert_eq!(all_in.entropy_adjustment, dynamic.entropy_adjustment);
+        assert_eq!(dynamic.entropy_tax, Credits::ZERO);
+    }
+
+    #[test]
+    fn test_implied_entropy() {
+        let weights = EntropyWeights::default();
+        let curve = MultiplierCurve::default();
+
+        // 100 → 140 is the 1.4x breakpoint at total entropy 5
+        let implied = implied_entropy(Credits::new(100), Credits::new(140), &weights, &curve);
+        assert!((implied.unwrap() - 5.0).abs() < 1e-9);
+
+        let level = 6.5;
+        let account = EntropyAccount {
+            network: level,
+            compute: level,
+            storage: level,
+            temporal: level,
+        };
+        let quote = Pricer::new().quote_dynamic(&account);
+        let implied =
+            implied_entropy(quote.base_price, quote.total_price, &weights, &curve).unwrap();
+        assert!((implied - level).abs() < 0.01);
+
+        assert_eq!(
+            implied_entropy(Credits::ZERO, Credits::new(140), &weights, &curve),
+            None
+        );
+        assert_eq!(
+            implied_entropy(Credits::new(100), Credits::new(50), &weights, &curve),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quote_resource() {
+        let pricer = Pricer::new();
+        let calm = pricer.quote_resource(ResourceType::Storage, 10, &EntropyAccount::zero());
+        assert_eq!(calm.base_price, Credits::new(1_000));
+        assert_eq!(calm.total_price, Credits::new(1_000));
+
+        let noisy = EntropyAccount {
+            network: 10.0,
+            compute: 10.0,
+            storage: 10.0,
+            temporal: 10.0,
+        };
+        let quote = pricer.quote_resource(ResourceType::Storage, 10, &noisy);
+        assert_eq!(quote.total_price, Credits::new(5_000));
+        assert_eq!(quote.entropy_adjustment, Credits::new(4_000));
+    }
+
+    #[test]
+    fn test_pricing_parameters_hash() {
+        let params = PricingParameters::default();
+        assert_eq!(params.content_hash(), params.clone().content_hash());
+
+        let mut taxed = params.clone();
+        taxed.tax_bps = 300;
+        assert_ne!(taxed.content_hash(), params.content_hash());
+
+        let mut steeper = params.clone();
+        steeper.curve.points[1].1 = 1.2;
+        assert_ne!(steeper.content_hash(), params.content_hash());
+
+        // Default parameters reproduce the built-in pricing
+        let entropy = EntropyAccount {
+            network: 4.0,
+            compute: 2.0,
+            storage: 1.0,
+            temporal: 3.0,
+        };
+        let plain = Pricer::new();
+        let published = Pricer::new().with_parameters(params.clone());
+        let quote = published.quote_all_in(&entropy);
+        let config = DynamicPriceConfig::default();
+        assert_eq!(quote.parameters_hash, Some(params.quote_hash(&config)));
+        let expected = plain.quote_all_in(&entropy).entropy_multiplier;
+        assert!((quote.entropy_multiplier - expected).abs() < 1e-9);
+        assert_eq!(plain.quote_dynamic(&entropy).parameters_hash, None);
+
+        let dynamic = published.quote_dynamic(&entropy).total_price;
+        let quote = Pricer::new().with_parameters(taxed).quote_all_in(&entropy);
+        assert_eq!(quote.entropy_tax.amount, dynamic.amount * 3 / 100);
+
+        // The base price and multiplier bounds are part of the quoted rules
+        let pricier = DynamicPriceConfig {
+            base_price: Credits::new(200),
+            ..DynamicPriceConfig::default()
+        };
+        let quote = Pricer::new()
+            .with_dynamic_config(pricier.clone())
+            .with_parameters(params.clone())
+            .quote_dynamic(&entropy);
+        assert_eq!(quote.parameters_hash, Some(params.quote_hash(&pricier)));
+        assert_ne!(params.quote_hash(&pricier), params.quote_hash(&config));
+        let capped = DynamicPriceConfig {
+            max_multiplier: 2.0,
+            ..DynamicPriceConfig::default()
+        };
+        assert_ne!(params.quote_hash(&capped), params.quote_hash(&config));
+    }
+
+    #[test]
+    fn test_measure_entropy_with_coefficients() {
+        let network = NetworkEntropyInput {
+            hops: 3,
+            latency_variance_ms: 10.0,
+            packet_loss_probability: 0.01,
+            bandwidth_saturation: 0.5,
+        };
+        let temporal = TemporalEntropyInput {
+            staleness_seconds: 100.0,
+            clock_drift_ms: 50.0,
+            ordering_uncertainty: 0.2,
+            version_divergence: 1.0,
+        };
+
+        // Built-in coefficients match the calculator
+        let builtin =
+            EntropyCalculator::new().calculate(Some(&network), None, None, Some(&temporal));
+        let measured = Pricer::new().measure_entropy(Some(&network), None, None, Some(&temporal));
+        assert!((measured.network - builtin.network).abs() < 1e-9);
+        assert!((measured.temporal - builtin.temporal).abs() < 1e-9);
+        assert_eq!(measured.compute, 0.0);
+
+        // Published coefficients change both the entropy and the hash
+        let mut params = PricingParameters::default();
+        params.coefficients.network[0] = 1.0;
+        let pricer = Pricer::new().with_parameters(params.clone());
+        let measured = pricer.measure_entropy(Some(&network), None, None, None);
+        // 3*1.0 + 10*0.01 + 0.01*5.0 + 0.5*2.0
+        assert!((measured.network - 4.15).abs() < 1e-9);
+        assert_ne!(
+            params.content_hash(),
+            PricingParameters::default().content_hash()
+        );
+    }
+
+    #[test]
+    fn test_quotes_record_weights_version() {
+        let mut pricer = Pricer::new().with_calculator(EntropyCalculator::new());
+        let entropy = EntropyAccount {
+            network: 6.0,
+            compute: 6.0,
+            storage: 6.0,
+            temporal: 6.0,
+        };
+
+        let before = pricer.quote_dynamic(&entropy);
+        assert_eq!(before.weights_version, Some(0));
+        assert_eq!(
+            before.total_price,
+            Pricer::new().quote_dynamic(&entropy).total_price
+        );
+        assert!(!before.is_stale(pricer.calculator().unwrap().weights_version()));
+
+        let network_heavy = EntropyWeights {
+            network_weight: 0.7,
+            compute_weight: 0.1,
+            storage_weight: 0.1,
+            temporal_weight: 0.1,
+            max_contribution: None,
+        };
+        let version = pricer
+            .calculator_mut()
+            .unwrap()
+            .swap_weights(network_heavy)
+            .unwrap();
+        assert!(before.is_stale(version));
+
+        let after = pricer.quote_dynamic(&entropy);
+        assert_eq!(after.weights_version, Some(version));
+        assert!(!after.is_stale(version));
+        assert!(!PriceQuote::fixed(Credits::new(1)).is_stale(version));
+
+        // With parameters too, the hash covers the calculator's weights
+        let params = PricingParameters::default();
+        let pricer = Pricer::new()
+            .with_parameters(params.clone())
+            .with_calculator(EntropyCalculator::with_weights(network_heavy));
+        let used = PricingParameters {
+            weights: network_heavy,
+            ..params.clone()
+        };
+        let quote = pricer.quote_dynamic(&entropy);
+        let config = DynamicPriceConfig::default();
+        assert_eq!(quote.parameters_hash, Some(used.quote_hash(&config)));
+        assert_ne!(quote.parameters_hash, Some(params.quote_hash(&config)));
+    }
 }
@@ -4,7 +4,9 @@
 
 use super::events::RevivalEvent;
 use crate::core::{Credits, NodeId, Timestamp};
+use crate::nexus::{ElectionError, NexusElector, NodeMetrics, Region, TopologyManager};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// DecompositionPhase - from dol/revival.dol line 57
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -73,9 +75,23 @@ impl DecompositionState {
 }
 
 /// Decomposer for managing node decomposition
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Decomposer {
     states: std::collections::HashMap<NodeId, DecompositionState>,
+    /// Distinct reporters required before leaving the freeze phase
+    quorum: usize,
+    /// Reporters that have confirmed each node's failure
+    confirmations: std::collections::HashMap<NodeId, HashSet<NodeId>>,
+}
+
+impl Default for Decomposer {
+    fn default() -> Self {
+        Self {
+            states: std::collections::HashMap::new(),
+            quorum: 1,
+            confirmations: std::collections::HashMap::new(),
+        }
+    }
 }
 
 impl Decomposer {
@@ -83,6 +99,44 @@ impl Decomposer {
         Self::default()
     }
 
+    /// Require `quorum` distinct failure confirmations (minimum 1)
+    pub fn with_quorum(mut self, quorum: usize) -> Self {
+        self.quorum = quorum.max(1);
+        self
+    }
+
+    /// Record a reporter's confirmation that `node` has failed
+    ///
+    /// Duplicate reports from the same reporter count once. Returns true
+    /// once the quorum is reached.
+    pub fn confirm_failure(&mut self, node: NodeId, reporter: NodeId) -> bool {
+        self.confirmations.entry(node).or_default().insert(reporter);
+        self.has_quorum(&node)
+    }
+
+    /// Number of distinct reporters confirming `node` has failed
+    pub fn confirmation_count(&self, node: &NodeId) -> usize {
+        self.confirmations.get(node).map_or(0, HashSet::len)
+    }
+
+    /// Whether enough reporters agree `node` has failed
+    pub fn has_quorum(&self, node: &NodeId) -> bool {
+        self.confirmation_count(node) >= self.quorum
+    }
+
+    /// Advance a decomposition to its next phase
+    ///
+    /// Decomposition holds at `CreditsFrozen` until the failure quorum is
+    /// reached, since later phases destroy the node's economic identity.
+    pub fn advance(&mut self, node: &NodeId) -> bool {
+        let has_quorum = self.has_quorum(node);
+        match self.states.get_mut(node) {
+            Some(state) if state.phase == DecompositionPhase::CreditsFrozen && !has_quorum => false,
+            Some(state) => state.advance(),
+            None => false,
+        }
+    }
+
     /// Check if node is being decomposed
     pub fn is_decomposing(&self, node: &NodeId) -> bool {
         self.states.contains_key(node)
@@ -111,6 +165,7 @@ impl Decomposer {
 
     /// Complete decomposition and return events
     pub fn complete_decomposition(&mut self, node: &NodeId) -> Option<Vec<RevivalEvent>> {
+        self.confirmations.remove(node);
         self.states.remove(node).map(|s| s.events_emitted)
     }
 
@@ -125,6 +180,26 @@ impl Decomposer {
 pub fn decompose_failed_node<C: DecompositionContext>(
     context: &C,
     node: NodeId,
+) -> Vec<RevivalEvent> {
+    decompose_with_quorum(context, node, true)
+}
+
+/// Decompose a failed node only once the decomposer's quorum agrees
+///
+/// Without quorum the node's credits are frozen but decomposition stops
+/// there, so only the freeze event is returned.
+pub fn decompose_failed_node_with_quorum<C: DecompositionContext>(
+    context: &C,
+    node: NodeId,
+    decomposer: &Decomposer,
+) -> Vec<RevivalEvent> {
+    decompose_with_quorum(context, node, decomposer.has_quorum(&node))
+}
+
+fn decompose_with_quorum<C: DecompositionContext>(
+    context: &C,
+    node: NodeId,
+    has_quorum: bool,
 ) -> Vec<RevivalEvent> {
     let mut events = Vec::new();
 
@@ -136,6 +211,9 @@ pub fn decompose_failed_node<C: DecompositionContext>(
     // Step 2: Freeze credits
     let frozen_credits = context.freeze_node_credits(&node);
     events.push(RevivalEvent::node_failure(node, frozen_credits).with_metadata("phase", "freeze"));
+    if !has_quorum {
+        return events;
+    }
 
     // Step 3: Release held reservations
     let reservations = context.get_held_reservations(&node);
@@ -156,7 +234,7 @@ pub fn decompose_failed_node<C: DecompositionContext>(
         }
     }
 
-    // Step 5: Update topology (done by caller)
+    // Step 5: Update topology (done by caller, see `redistribute_orphaned_leaves`)
 
     // Step 6: Final event
     events
@@ -165,6 +243,37 @@ pub fn decompose_failed_node<C: DecompositionContext>(
     events
 }
 
+/// Elect a replacement for a failed nexus and reparent its leaves to it
+///
+/// The replacement is elected from the failed nexus's own leaves. On
+/// success every leaf points at the replacement, which takes over the
+/// failed nexus's parent, and the failed node is removed from the topology.
+/// On election failure the topology is left untouched.
+pub fn redistribute_orphaned_leaves<M: NodeMetrics>(
+    topology: &mut TopologyManager,
+    failed_nexus: NodeId,
+    elector: &NexusElector<M>,
+) -> Result<NodeId, ElectionError> {
+    let mut leaves = topology.get_role(&failed_nexus).children;
+    for leaf in topology.get_leaves_of(&failed_nexus) {
+        if !leaves.contains(&leaf) {
+            leaves.push(leaf);
+        }
+    }
+    leaves.retain(|leaf| *leaf != failed_nexus);
+
+    let region = Region {
+        id: failed_nexus.to_hex(),
+        nodes: leaves,
+        current_nexus: None,
+    };
+    let replacement = elector.elect(&region)?;
+
+    topology.handoff_nexus(failed_nexus, replacement);
+    topology.remove_node(&failed_nexus);
+    Ok(replacement)
+}
+
 /// Context required for decomposition operations
 pub trait DecompositionContext {
     fn confirm_failure(&self, node: &NodeId) -> bool;
@@ -222,4 +331,126 @@ mod tests {
         assert!(events.is_some());
         assert!(!decomposer.is_decomposing(&node));
     }
+
+    struct LeafMetrics {
+        strong: NodeId,
+    }
+
+    impl NodeMetrics for LeafMetrics {
+        fn get_uptime(&self, _node: &NodeId) -> f64 {
+            0.99
+        }
+        fn get_bandwidth(&self, node: &NodeId) -> u64 {
+            if *node == self.strong {
+                100_000_000
+            } else {
+                20_000_000
+            }
+        }
+        fn get_reputation(&self, _node: &NodeId) -> f64 {
+            0.9
+        }
+        fn get_connection_count(&self, _node: &NodeId) -> u32 {
+            5
+        }
+    }
+
+    #[test]
+    fn test_redistribute_orphaned_leaves() {
+        use crate::nexus::{topology::test_topology, NexusRole};
+
+        let poteau = NodeId::from_bytes([9u8; 32]);
+        let failed = NodeId::from_bytes([1u8; 32]);
+        let leaves: Vec<NodeId> = (2..=4).map(|i| NodeId::from_bytes([i as u8; 32])).collect();
+        let mut topology = TopologyManager::new();
+        topology.set_topology(
+            poteau,
+            test_topology(poteau, NexusRole::poteau_mitan(vec![failed])),
+        );
+        topology.set_topology(
+            failed,
+            test_topology(failed, NexusRole::nexus(Some(poteau), leaves.clone())),
+        );
+        for leaf in &leaves {
+            topology.set_topology(*leaf, test_topology(*leaf, NexusRole::leaf(failed)));
+        }
+
+        let elector = NexusElector::new(LeafMetrics { strong: leaves[1] });
+        let replacement = redistribute_orphaned_leaves(&mut topology, failed, &elector).unwrap();
+        assert_eq!(replacement, leaves[1]);
+
+        assert!(topology.get_topology(&failed).is_none());
+        let role = topology.get_role(&replacement);
+        assert!(role.is_nexus());
+        assert_eq!(role.parent, Some(poteau));
+        assert_eq!(role.children.len(), 2);
+        assert_eq!(topology.get_topology(&replacement).unwrap().leaf_count, 2);
+        for leaf in [leaves[0], leaves[2]] {
+            assert_eq!(topology.get_role(&leaf).parent, Some(replacement));
+        }
+        assert_eq!(topology.get_role(&poteau).children, vec![replacement]);
+
+        // A nexus with no leaves can't be replaced; topology is unchanged
+        let lonely = NodeId::from_bytes([8u8; 32]);
+        topology.set_topology(
+            lonely,
+            test_topology(lonely, NexusRole::nexus(None, Vec::new())),
+        );
+        assert_eq!(
+            redistribute_orphaned_leaves(&mut topology, lonely, &elector),
+            Err(ElectionError::EmptyRegion)
+        );
+        assert!(topology.get_topology(&lonely).is_some());
+    }
+
+    struct FailedContext;
+
+    impl DecompositionContext for FailedContext {
+        fn confirm_failure(&self, _node: &NodeId) -> bool {
+            true
+        }
+        fn freeze_node_credits(&self, _node: &NodeId) -> Credits {
+            Credits::new(100)
+        }
+        fn get_held_reservations(&self, _node: &NodeId) -> Vec<(u64, Credits, bool)> {
+            vec![(1, Credits::new(10), false)]
+        }
+        fn get_stored_items(&self, _node: &NodeId) -> Vec<(String, bool)> {
+            Vec::new()
+        }
+        fn estimate_storage_credits(&self, _key: &str) -> Credits {
+            Credits::zero()
+        }
+    }
+
+    #[test]
+    fn test_failure_quorum() {
+        let mut decomposer = Decomposer::new().with_quorum(2);
+        let node = NodeId::from_bytes([1u8; 32]);
+        let observer_a = NodeId::from_bytes([2u8; 32]);
+        let observer_b = NodeId::from_bytes([3u8; 32]);
+
+        decomposer.start_decomposition(node, Credits::new(100));
+        assert!(!decomposer.confirm_failure(node, observer_a));
+        assert!(!decomposer.confirm_failure(node, observer_a));
+        assert_eq!(decomposer.confirmation_count(&node), 1);
+
+        // Held at freeze without quorum
+        let events = decompose_failed_node_with_quorum(&FailedContext, node, &decomposer);
+        assert_eq!(events.len(), 1);
+        assert!(!decomposer.advance(&node));
+        assert_eq!(
+            decomposer.get_state(&node).unwrap().phase,
+            DecompositionPhase::CreditsFrozen
+        );
+
+        assert!(decomposer.confirm_failure(node, observer_b));
+        let events = decompose_failed_node_with_quorum(&FailedContext, node, &decomposer);
+        assert_eq!(events.len(), 3);
+        assert!(decomposer.advance(&node));
+        assert_eq!(
+            decomposer.get_state(&node).unwrap().phase,
+            DecompositionPhase::ReservationsReleased
+        );
+    }
 }
@@ -21,6 +21,8 @@ pub enum RevivalEventType {
     SeptalIsolation,
     /// Node gracefully left network
     VoluntaryExit,
+    /// Subsidy paid out to a joining node
+    OnboardingGrant,
 }
 
 /// RevivalEvent - from dol/core.dol line 371
@@ -100,6 +102,16 @@ impl RevivalEvent {
         }
     }
 
+    pub fn onboarding_grant(node: NodeId, credits: Credits) -> Self {
+        Self {
+            event_type: RevivalEventType::OnboardingGrant,
+            source: node,
+            credits,
+            timestamp: Timestamp::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
         self
@@ -17,7 +17,9 @@
 pub mod decomposer;
 pub mod events;
 pub mod pool;
+pub mod simulation;
 
 pub use decomposer::*;
 pub use events::*;
 pub use pool::*;
+pub use simulation::*;
@@ -2,8 +2,10 @@
 //!
 //! Implements revival pool from dol/revival.dol
 
-use crate::core::{Credits, NodeId};
+use super::events::RevivalEvent;
+use crate::core::{CreditLedger, Credits, EnrError, EnrResult, NodeId};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Tax and timing constants - from dol/revival.dol lines 36-39
 pub const ENTROPY_TAX_RATE: f64 = 0.02;
@@ -14,11 +16,21 @@ pub const NEW_NODE_SUBSIDY_ALLOCATION: f64 = 0.25;
 pub const LOW_BALANCE_SUPPORT_ALLOCATION: f64 = 0.20;
 pub const RESERVE_BUFFER_ALLOCATION: f64 = 0.15;
 
+/// Tax rate and allocations in basis points, for integer credit math
+pub const ENTROPY_TAX_BPS: u32 = 200;
+pub const NETWORK_MAINTENANCE_BPS: u32 = 4_000;
+pub const NEW_NODE_SUBSIDY_BPS: u32 = 2_500;
+pub const LOW_BALANCE_SUPPORT_BPS: u32 = 2_000;
+pub const RESERVE_BUFFER_BPS: u32 = 1_500;
+
 /// Eligibility thresholds - from dol/revival.dol lines 48-51
 pub const SUBSIDY_THRESHOLD: u64 = 100;
 pub const MIN_NEXUS_UPTIME_FOR_MAINTENANCE: f64 = 0.95;
 pub const MIN_REPUTATION_FOR_SUPPORT: f64 = 0.5;
 
+/// Lifetime cap on onboarding credit granted to a single node
+pub const MAX_ONBOARDING_CREDIT: u64 = SUBSIDY_THRESHOLD;
+
 /// RevivalPool - from dol/core.dol line 335
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct RevivalPool {
@@ -30,6 +42,12 @@ pub struct RevivalPool {
     pub maintenance_fund: Credits,
     /// Emergency reserve
     pub reserve_buffer: Credits,
+    /// Onboarding credit granted so far, per node
+    #[serde(default)]
+    pub onboarding_granted: HashMap<NodeId, Credits>,
+    /// Onboarding credit granted since the pools were last cleared
+    #[serde(default)]
+    pub onboarding_granted_this_cycle: Credits,
 }
 
 impl RevivalPool {
@@ -72,10 +90,76 @@ impl RevivalPool {
         self.reserve_buffer = self.reserve_buffer.saturating_add(amount);
     }
 
-    /// Clear redistribution pools after distribution
+    /// Clear redistribution pools after distribution, starting a new
+    /// onboarding cycle
     pub fn clear_redistribution_pools(&mut self) {
         self.recycled_credits = Credits::ZERO;
         self.entropy_tax_collected = Credits::ZERO;
+        self.onboarding_granted_this_cycle = Credits::ZERO;
+    }
+
+    /// Grant a joining node credit immediately, ahead of the next cycle
+    ///
+    /// Grants in one cycle (until [`Self::clear_redistribution_pools`])
+    /// share the new-node-subsidy allocation of what was available at the
+    /// start of the cycle, and each node is limited to what remains of its
+    /// `MAX_ONBOARDING_CREDIT`. It is drawn from entropy tax first, then
+    /// recycled credits. Returns `None` if nothing can be granted.
+    pub fn grant_onboarding_credit(
+        &mut self,
+        node: NodeId,
+        amount: Credits,
+    ) -> Option<RevivalEvent> {
+        let granted = self
+            .onboarding_granted
+            .get(&node)
+            .copied()
+            .unwrap_or(Credits::ZERO);
+        let remaining_cap = Credits::new(MAX_ONBOARDING_CREDIT).saturating_sub(granted);
+        let subsidy_budget = self
+            .available_for_redistribution()
+            .saturating_add(self.onboarding_granted_this_cycle)
+            .basis_points(NEW_NODE_SUBSIDY_BPS)
+            .saturating_sub(self.onboarding_granted_this_cycle);
+        let grant = amount.min(remaining_cap).min(subsidy_budget);
+        if grant.is_zero() {
+            return None;
+        }
+
+        let from_tax = grant.min(self.entropy_tax_collected);
+        self.entropy_tax_collected = self.entropy_tax_collected.saturating_sub(from_tax);
+        self.recycled_credits = self
+            .recycled_credits
+            .saturating_sub(grant.saturating_sub(from_tax));
+        self.onboarding_granted
+            .insert(node, granted.saturating_add(grant));
+        self.onboarding_granted_this_cycle =
+            self.onboarding_granted_this_cycle.saturating_add(grant);
+
+        Some(RevivalEvent::onboarding_grant(node, grant))
+    }
+
+    /// Whole periods the reserve buffer lasts at `drain_per_period`
+    ///
+    /// Returns `None` if nothing drains (infinite runway).
+    pub fn runway(&self, drain_per_period: Credits) -> Option<u64> {
+        self.runway_with_inflow(drain_per_period, Credits::ZERO)
+    }
+
+    /// Whole periods the reserve buffer lasts when `inflow_per_period`
+    /// offsets the drain
+    ///
+    /// Returns `None` if inflow matches or exceeds the drain.
+    pub fn runway_with_inflow(
+        &self,
+        drain_per_period: Credits,
+        inflow_per_period: Credits,
+    ) -> Option<u64> {
+        let net_drain = drain_per_period.checked_sub(inflow_per_period)?;
+        if net_drain.is_zero() {
+            return None;
+        }
+        Some(self.reserve_buffer.amount / net_drain.amount)
     }
 
     /// Constraint: non_negative from dol/core.dol line 352
@@ -90,8 +174,34 @@ impl RevivalPool {
 /// Calculate entropy tax for a transaction
 /// From dol/revival.dol lines 377-388
 pub fn calculate_entropy_tax(transaction_amount: Credits) -> Credits {
-    let tax_amount = (transaction_amount.amount as f64 * ENTROPY_TAX_RATE).floor() as u64;
-    Credits::new(tax_amount)
+    transaction_amount.basis_points(ENTROPY_TAX_BPS)
+}
+
+/// Slash a fraction of a byzantine node's balance into recycled credits
+///
+/// `fraction` is clamped to [0, 1] (NaN slashes nothing) and the slashed
+/// amount is rounded down. Whatever leaves `node_balance` lands in the pool,
+/// so credits are conserved. The returned `SeptalIsolation` event records the
+/// slashed amount and fraction.
+pub fn slash(
+    node: NodeId,
+    node_balance: &mut Credits,
+    fraction: f64,
+    pool: &mut RevivalPool,
+) -> RevivalEvent {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    let slashed = Credits::from_f64_saturating((node_balance.amount as f64 * fraction).floor())
+        .min(*node_balance);
+
+    *node_balance = node_balance.saturating_sub(slashed);
+    pool.add_recycled(slashed);
+
+    RevivalEvent::septal_isolation(node, slashed, "byzantine_slash")
+        .with_metadata("slash_fraction", fraction.to_string())
 }
 
 /// Redistribution plan - from dol/revival.dol line 215
@@ -115,6 +225,113 @@ impl RedistributionPlan {
 
         Credits::new(maintenance + subsidy + support + self.reserve_addition.amount)
     }
+
+    /// Everything a node is assigned across maintenance, subsidy, and support
+    pub fn amount_for(&self, node: &NodeId) -> Credits {
+        let total = self
+            .maintenance_recipients
+            .iter()
+            .chain(&self.subsidy_recipients)
+            .chain(&self.support_recipients)
+            .filter(|(recipient, _)| recipient == node)
+            .map(|(_, amount)| amount.amount)
+            .sum();
+        Credits::new(total)
+    }
+
+    /// Credit every recipient in `ledger` and settle the pool
+    ///
+    /// The pool's redistribution buckets are emptied; the reserve addition
+    /// and anything left undistributed go to the reserve buffer. Fails
+    /// before touching anything if the plan is over budget; if any credit
+    /// fails, the ones already made are debited back and the pool is left
+    /// as it was. Checks that ledger plus pool totals are unchanged
+    /// afterwards.
+    pub fn apply_to<L: CreditLedger>(
+        &self,
+        ledger: &mut L,
+        pool: &mut RevivalPool,
+    ) -> EnrResult<()> {
+        let available = pool.available_for_redistribution();
+        self.assert_within_budget(available)?;
+        let before = ledger.total_supply().saturating_add(pool.total_balance());
+
+        let recipients: Vec<&(NodeId, Credits)> = self
+            .maintenance_recipients
+            .iter()
+            .chain(&self.subsidy_recipients)
+            .chain(&self.support_recipients)
+            .collect();
+        for (applied, (node, amount)) in recipients.iter().enumerate() {
+            if let Err(err) = ledger.credit(node, *amount) {
+                for (node, amount) in recipients[..applied].iter().rev() {
+                    ledger.debit(node, *amount)?;
+                }
+                return Err(err);
+            }
+        }
+
+        pool.clear_redistribution_pools();
+        pool.add_reserve(self.reserve_addition);
+        pool.add_reserve(available.saturating_sub(self.total_distributed()));
+
+        let after = ledger.total_supply().saturating_add(pool.total_balance());
+        if after != before {
+            return Err(EnrError::ConservationViolation {
+                expected: before,
+                actual: after,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the plan doesn't allocate more than `available`
+    ///
+    /// Budgets are floored independently, so today's policy always comes in
+    /// at or under budget; this guards against future policy changes.
+    pub fn assert_within_budget(&self, available: Credits) -> EnrResult<()> {
+        let planned = self.total_distributed();
+        if planned > available {
+            return Err(EnrError::RedistributionOverBudget { planned, available });
+        }
+        Ok(())
+    }
+}
+
+/// How the low-balance support budget is split among recipients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DistributionStrategy {
+    /// Equal share per recipient
+    #[default]
+    EqualSplit,
+    /// Weighted by distance below `SUBSIDY_THRESHOLD` (more to the poorest);
+    /// shares sum exactly to the budget
+    InverseBalance,
+}
+
+/// Split `budget` proportionally to `weights`, summing exactly to `budget`
+///
+/// Uses largest-remainder rounding; ties go to the earlier recipient.
+fn split_weighted(budget: u64, weights: &[u64]) -> Vec<u64> {
+    let total: u128 = weights.iter().map(|w| *w as u128).sum();
+    if total == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let mut shares: Vec<u64> = Vec::with_capacity(weights.len());
+    let mut remainders: Vec<(u128, usize)> = Vec::with_capacity(weights.len());
+    for (i, w) in weights.iter().enumerate() {
+        let scaled = budget as u128 * *w as u128;
+        shares.push((scaled / total) as u64);
+        remainders.push((scaled % total, i));
+    }
+
+    let leftover = budget - shares.iter().sum::<u64>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in remainders.into_iter().take(leftover as usize) {
+        shares[i] += 1;
+    }
+    shares
 }
 
 /// Plan redistribution of revival pool credits
@@ -123,17 +340,26 @@ pub fn plan_redistribution<M: NodeMetricsProvider>(
     pool: &RevivalPool,
     metrics: &M,
 ) -> RedistributionPlan {
-    let total_available = pool.available_for_redistribution().amount;
+    plan_redistribution_with_strategy(pool, metrics, DistributionStrategy::EqualSplit)
+}
+
+/// Plan redistribution with a chosen support distribution strategy
+pub fn plan_redistribution_with_strategy<M: NodeMetricsProvider>(
+    pool: &RevivalPool,
+    metrics: &M,
+    strategy: DistributionStrategy,
+) -> RedistributionPlan {
+    let available = pool.available_for_redistribution();
 
-    if total_available == 0 {
+    if available.is_zero() {
         return RedistributionPlan::default();
     }
 
     // Calculate budgets
-    let maintenance_budget = (total_available as f64 * NETWORK_MAINTENANCE_ALLOCATION) as u64;
-    let subsidy_budget = (total_available as f64 * NEW_NODE_SUBSIDY_ALLOCATION) as u64;
-    let support_budget = (total_available as f64 * LOW_BALANCE_SUPPORT_ALLOCATION) as u64;
-    let reserve_budget = (total_available as f64 * RESERVE_BUFFER_ALLOCATION) as u64;
+    let maintenance_budget = available.basis_points(NETWORK_MAINTENANCE_BPS).amount;
+    let subsidy_budget = available.basis_points(NEW_NODE_SUBSIDY_BPS).amount;
+    let support_budget = available.basis_points(LOW_BALANCE_SUPPORT_BPS).amount;
+    let reserve_budget = available.basis_points(RESERVE_BUFFER_BPS).amount;
 
     // Find maintenance recipients (high-uptime nexus nodes)
     let nexus_nodes: Vec<NodeId> = metrics
@@ -179,23 +405,40 @@ pub fn plan_redistribution<M: NodeMetricsProvider>(
         .filter(|n| metrics.get_reputation(n) >= MIN_REPUTATION_FOR_SUPPORT)
         .collect();
 
-    let support_per_node = if !struggling_nodes.is_empty() {
-        support_budget / struggling_nodes.len() as u64
-    } else {
-        0
+    let support_recipients: Vec<(NodeId, Credits)> = match strategy {
+        DistributionStrategy::EqualSplit => {
+            let support_per_node = if !struggling_nodes.is_empty() {
+                support_budget / struggling_nodes.len() as u64
+            } else {
+                0
+            };
+
+            struggling_nodes
+                .into_iter()
+                .map(|n| (n, Credits::new(support_per_node)))
+                .collect()
+        }
+        DistributionStrategy::InverseBalance => {
+            let needs: Vec<u64> = struggling_nodes
+                .iter()
+                .map(|n| SUBSIDY_THRESHOLD.saturating_sub(metrics.get_balance(n).amount))
+                .collect();
+            struggling_nodes
+                .into_iter()
+                .zip(split_weighted(support_budget, &needs))
+                .map(|(n, share)| (n, Credits::new(share)))
+                .collect()
+        }
     };
 
-    let support_recipients: Vec<(NodeId, Credits)> = struggling_nodes
-        .into_iter()
-        .map(|n| (n, Credits::new(support_per_node)))
-        .collect();
-
-    RedistributionPlan {
+    let plan = RedistributionPlan {
         maintenance_recipients,
         subsidy_recipients,
         support_recipients,
         reserve_addition: Credits::new(reserve_budget),
-    }
+    };
+    debug_assert!(plan.assert_within_budget(available).is_ok());
+    plan
 }
 
 /// Node metrics provider trait for redistribution
@@ -226,6 +469,78 @@ mod tests {
         assert!(pool.is_valid());
     }
 
+    #[test]
+    fn test_grant_onboarding_credit() {
+        let node = NodeId::from_bytes([5u8; 32]);
+        let mut pool = RevivalPool::new();
+        assert!(pool
+            .grant_onboarding_credit(node, Credits::new(10))
+            .is_none());
+
+        pool.add_tax(Credits::new(40));
+        pool.add_recycled(Credits::new(960));
+
+        // Subsidy share of 1000 is 250; the per-node cap is 100
+        let event = pool
+            .grant_onboarding_credit(node, Credits::new(500))
+            .unwrap();
+        assert_eq!(event.credits.amount, MAX_ONBOARDING_CREDIT);
+        assert!(pool.entropy_tax_collected.is_zero());
+        assert_eq!(pool.recycled_credits.amount, 900);
+        assert_eq!(pool.total_balance().amount, 900);
+
+        // Cap reached for this node, not for others
+        assert!(pool
+            .grant_onboarding_credit(node, Credits::new(1))
+            .is_none());
+        let other = NodeId::from_bytes([6u8; 32]);
+        assert!(pool
+            .grant_onboarding_credit(other, Credits::new(1))
+            .is_some());
+    }
+
+    #[test]
+    fn test_onboarding_grants_share_cycle_allocation() {
+        let mut pool = RevivalPool::new();
+        pool.add_recycled(Credits::new(400));
+
+        // Subsidy share of 400 is 100 for the whole cycle, not per grant
+        let granted: u64 = (10..20u8)
+            .filter_map(|i| {
+                pool.grant_onboarding_credit(NodeId::from_bytes([i; 32]), Credits::new(30))
+            })
+            .map(|event| event.credits.amount)
+            .sum();
+        assert_eq!(granted, 100);
+        assert_eq!(pool.onboarding_granted_this_cycle, Credits::new(100));
+        assert_eq!(pool.recycled_credits, Credits::new(300));
+
+        // A new cycle starts a new allocation
+        pool.clear_redistribution_pools();
+        assert!(pool.onboarding_granted_this_cycle.is_zero());
+        pool.add_recycled(Credits::new(400));
+        assert!(pool
+            .grant_onboarding_credit(NodeId::from_bytes([30u8; 32]), Credits::new(30))
+            .is_some());
+    }
+
+    #[test]
+    fn test_reserve_runway() {
+        let mut pool = RevivalPool::new();
+        pool.add_reserve(Credits::new(1000));
+
+        assert_eq!(pool.runway(Credits::new(300)), Some(3));
+        assert_eq!(pool.runway(Credits::ZERO), None);
+        assert_eq!(
+            pool.runway_with_inflow(Credits::new(300), Credits::new(100)),
+            Some(5)
+        );
+        assert_eq!(
+            pool.runway_with_inflow(Credits::new(100), Credits::new(300)),
+            None
+        );
+    }
+
     #[test]
     fn test_entropy_tax() {
         let amount = Credits::new(1000);
@@ -234,6 +549,215 @@ mod tests {
         assert_eq!(tax.amount, 20);
     }
 
+    #[test]
+    fn test_assert_within_budget() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let plan = RedistributionPlan {
+            maintenance_recipients: vec![(node, Credits::new(60))],
+            reserve_addition: Credits::new(40),
+            ..Default::default()
+        };
+        assert!(plan.assert_within_budget(Credits::new(100)).is_ok());
+        assert!(matches!(
+            plan.assert_within_budget(Credits::new(99)),
+            Err(EnrError::RedistributionOverBudget { .. })
+        ));
+    }
+
+    #[test]
+    fn test_amount_for() {
+        let nexus = NodeId::from_bytes([1u8; 32]);
+        let other = NodeId::from_bytes([2u8; 32]);
+        let plan = RedistributionPlan {
+            maintenance_recipients: vec![(nexus, Credits::new(40))],
+            support_recipients: vec![(other, Credits::new(5)), (nexus, Credits::new(7))],
+            reserve_addition: Credits::new(15),
+            ..Default::default()
+        };
+        assert_eq!(plan.amount_for(&nexus), Credits::new(47));
+        assert_eq!(plan.amount_for(&other), Credits::new(5));
+        assert!(plan.amount_for(&NodeId::from_bytes([3u8; 32])).is_zero());
+    }
+
+    #[test]
+    fn test_apply_plan_to_ledger() {
+        use crate::core::MemoryLedger;
+
+        let nexus = NodeId::from_bytes([1u8; 32]);
+        let newcomer = NodeId::from_bytes([2u8; 32]);
+        let plan = RedistributionPlan {
+            maintenance_recipients: vec![(nexus, Credits::new(400))],
+            subsidy_recipients: vec![(newcomer, Credits::new(250))],
+            reserve_addition: Credits::new(150),
+            ..Default::default()
+        };
+
+        let mut pool = RevivalPool::new();
+        pool.add_recycled(Credits::new(1000));
+        let mut ledger = MemoryLedger::new();
+        ledger.credit(&nexus, Credits::new(50)).unwrap();
+
+        plan.apply_to(&mut ledger, &mut pool).unwrap();
+        assert_eq!(ledger.balance(&nexus), Credits::new(450));
+        assert_eq!(ledger.balance(&newcomer), Credits::new(250));
+        assert!(pool.available_for_redistribution().is_zero());
+        // 150 reserve + 200 unallocated support budget
+        assert_eq!(pool.reserve_buffer, Credits::new(350));
+
+        // Over budget: nothing is applied
+        let mut empty = RevivalPool::new();
+        assert!(plan.apply_to(&mut ledger, &mut empty).is_err());
+        assert_eq!(ledger.balance(&nexus), Credits::new(450));
+    }
+
+    #[test]
+    fn test_apply_plan_rolls_back_failed_credit() {
+        use crate::core::MemoryLedger;
+
+        /// Refuses credits to one node
+        struct Frozen(MemoryLedger, NodeId);
+        impl CreditLedger for Frozen {
+            fn balance(&self, node: &NodeId) -> Credits {
+                self.0.balance(node)
+            }
+            fn credit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+                if *node == self.1 {
+                    return Err(EnrError::TransferFailed("account frozen".to_string()));
+                }
+                self.0.credit(node, amount)
+            }
+            fn debit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+                self.0.debit(node, amount)
+            }
+            fn total_supply(&self) -> Credits {
+                self.0.total_supply()
+            }
+        }
+
+        let nexus = NodeId::from_bytes([1u8; 32]);
+        let frozen = NodeId::from_bytes([2u8; 32]);
+        let plan = RedistributionPlan {
+            maintenance_recipients: vec![(nexus, Credits::new(400))],
+            subsidy_recipients: vec![(frozen, Credits::new(250))],
+            ..Default::default()
+        };
+
+        let mut pool = RevivalPool::new();
+        pool.add_recycled(Credits::new(1000));
+        let mut ledger = Frozen(MemoryLedger::new(), frozen);
+        ledger.credit(&nexus, Credits::new(50)).unwrap();
+
+        // The second credit fails: the first is undone
+        assert!(plan.apply_to(&mut ledger, &mut pool).is_err());
+        assert_eq!(ledger.balance(&nexus), Credits::new(50));
+        assert!(ledger.balance(&frozen).is_zero());
+        assert_eq!(pool.available_for_redistribution(), Credits::new(1000));
+        assert!(pool.reserve_buffer.is_zero());
+    }
+
+    #[test]
+    fn test_slash_conserves_credits() {
+        let node = NodeId::from_bytes([7u8; 32]);
+        let mut balance = Credits::new(1000);
+        let mut pool = RevivalPool::new();
+
+        let event = slash(node, &mut balance, 0.25, &mut pool);
+        assert_eq!(
+            event.event_type,
+            crate::revival::RevivalEventType::SeptalIsolation
+        );
+        assert_eq!(event.credits.amount, 250);
+        assert_eq!(balance.amount, 750);
+        assert_eq!(pool.recycled_credits.amount, 250);
+
+        // Out-of-range fractions clamp; total is unchanged
+        slash(node, &mut balance, 2.0, &mut pool);
+        assert!(balance.is_zero());
+        assert_eq!(pool.total_balance().amount, 1000);
+    }
+
+    struct MockProvider {
+        balances: Vec<(NodeId, Credits)>,
+    }
+
+    impl NodeMetricsProvider for MockProvider {
+        fn get_all_nodes(&self) -> Vec<NodeId> {
+            self.balances.iter().map(|(n, _)| *n).collect()
+        }
+        fn get_nexus_nodes(&self) -> Vec<NodeId> {
+            Vec::new()
+        }
+        fn get_new_nodes(&self) -> Vec<NodeId> {
+            Vec::new()
+        }
+        fn get_uptime(&self, _node: &NodeId) -> f64 {
+            1.0
+        }
+        fn get_reputation(&self, _node: &NodeId) -> f64 {
+            1.0
+        }
+        fn get_balance(&self, node: &NodeId) -> Credits {
+            self.balances
+                .iter()
+                .find(|(n, _)| n == node)
+                .map(|(_, b)| *b)
+                .unwrap_or(Credits::ZERO)
+        }
+        fn is_healthy(&self, _node: &NodeId) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_support_distribution_strategies() {
+        let poor = NodeId::from_bytes([1u8; 32]);
+        let nearly = NodeId::from_bytes([2u8; 32]);
+        let rich = NodeId::from_bytes([3u8; 32]);
+        let metrics = MockProvider {
+            balances: vec![
+                (poor, Credits::new(0)),
+                (nearly, Credits::new(99)),
+                (rich, Credits::new(500)),
+            ],
+        };
+        let mut pool = RevivalPool::new();
+        pool.add_recycled(Credits::new(1_003));
+        // Support budget: 20% of 1003 = 200
+
+        let equal = plan_redistribution(&pool, &metrics);
+        assert_eq!(
+            equal.support_recipients,
+            vec![(poor, Credits::new(100)), (nearly, Credits::new(100))]
+        );
+
+        let weighted = plan_redistribution_with_strategy(
+            &pool,
+            &metrics,
+            DistributionStrategy::InverseBalance,
+        );
+        let shares: Vec<u64> = weighted
+            .support_recipients
+            .iter()
+            .map(|(_, c)| c.amount)
+            .collect();
+        // Needs are 100 and 1: 200 * 100/101 = 198.02, 200 * 1/101 = 1.98
+        assert_eq!(shares, vec![198, 2]);
+        assert_eq!(shares.iter().sum::<u64>(), 200);
+    }
+
+    #[test]
+    fn test_split_weighted_sums_to_budget() {
+        assert_eq!(split_weighted(10, &[1, 1, 1]), vec![4, 3, 3]);
+        assert_eq!(split_weighted(10, &[0, 0]), vec![0, 0]);
+        assert_eq!(
+            split_weighted(u64::MAX, &[u64::MAX, 1])
+                .iter()
+                .map(|s| *s as u128)
+                .sum::<u128>(),
+            u64::MAX as u128
+        );
+    }
+
     #[test]
     fn test_allocation_sum() {
         let sum = NETWORK_MAINTENANCE_ALLOCATION
@@ -241,5 +765,12 @@ mod tests {
             + LOW_BALANCE_SUPPORT_ALLOCATION
             + RESERVE_BUFFER_ALLOCATION;
         assert!((sum - 1.0).abs() < 0.001);
+
+        let bps_sum = NETWORK_MAINTENANCE_BPS
+            + NEW_NODE_SUBSIDY_BPS
+            + LOW_BALANCE_SUPPORT_BPS
+            + RESERVE_BUFFER_BPS;
+        assert_eq!(bps_sum, 10_000);
+        assert_eq!(ENTROPY_TAX_BPS as f64 / 10_000.0, ENTROPY_TAX_RATE);
     }
 }
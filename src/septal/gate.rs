@@ -2,7 +2,8 @@
 //!
 //! Implements septal gate from dol/septal.dol
 
-use crate::core::{Duration, NodeId, Timestamp};
+use crate::core::{Credits, Duration, NodeId, Timestamp};
+use crate::entropy::EntropyAccount;
 use serde::{Deserialize, Serialize};
 
 /// Constants from dol/septal.dol lines 36-43
@@ -13,10 +14,15 @@ pub const ISOLATION_THRESHOLD: f64 = 0.7;
 pub const PING_TIMEOUT_MS: u64 = 5_000;
 pub const HEALTH_CHECK_INTERVAL_MS: u64 = 10_000;
 
+/// Default score below which an isolated node may be restored; together
+/// with `ISOLATION_THRESHOLD` this forms the hysteresis band
+pub const RESET_THRESHOLD: f64 = 0.5;
+
 /// SeptalGateState - from dol/core.dol line 398
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum SeptalGateState {
     /// Normal operation
+    #[default]
     Open,
     /// Testing recovery
     HalfOpen,
@@ -24,12 +30,6 @@ pub enum SeptalGateState {
     Closed,
 }
 
-impl Default for SeptalGateState {
-    fn default() -> Self {
-        SeptalGateState::Open
-    }
-}
-
 impl SeptalGateState {
     pub fn is_open(&self) -> bool {
         matches!(self, SeptalGateState::Open)
@@ -86,6 +86,21 @@ impl SeptalGate {
         self.failure_count >= FAILURE_THRESHOLD
     }
 
+    /// Check if should trip under the current network entropy, using the
+    /// entropy-scaled threshold from [`SeptalGateConfig::failure_threshold`]
+    pub fn should_trip_at(&self, config: &SeptalGateConfig, network_entropy: f64) -> bool {
+        self.failure_count >= config.failure_threshold(network_entropy)
+    }
+
+    /// Confidence in [0, 1) that the node is actually faulty
+    ///
+    /// `failures / (failures + FAILURE_THRESHOLD)`: zero with no failures,
+    /// 0.5 at the trip threshold, approaching 1 as failures accumulate.
+    pub fn failure_confidence(&self) -> f64 {
+        let failures = self.failure_count as f64;
+        failures / (failures + FAILURE_THRESHOLD as f64)
+    }
+
     /// Trip (close) the gate
     pub fn trip(&mut self) {
         self.state = SeptalGateState::Closed;
@@ -131,6 +146,33 @@ pub struct SeptalGateConfig {
     pub credit_default_threshold: u64,
     pub reputation_weight: f64,
     pub reputation_threshold: f64,
+    /// Fraction of the healing probe interval to randomize per target [0, 1]
+    #[serde(default = "default_probe_jitter_fraction")]
+    pub probe_jitter_fraction: f64,
+    /// Weighted score at or above which a node counts as failing
+    #[serde(default = "default_isolation_threshold")]
+    pub isolation_threshold: f64,
+    /// Weighted score below which a node counts as healthy again; scores
+    /// between the two thresholds leave the gate's state unchanged
+    #[serde(default = "default_reset_threshold")]
+    pub reset_threshold: f64,
+    /// How far network entropy raises the failure threshold: at maximum
+    /// entropy the threshold is `FAILURE_THRESHOLD * (1 + scale)`. Zero
+    /// keeps the threshold fixed
+    #[serde(default)]
+    pub entropy_threshold_scale: f64,
+}
+
+fn default_probe_jitter_fraction() -> f64 {
+    0.2
+}
+
+fn default_isolation_threshold() -> f64 {
+    ISOLATION_THRESHOLD
+}
+
+fn default_reset_threshold() -> f64 {
+    RESET_THRESHOLD
 }
 
 impl Default for SeptalGateConfig {
@@ -142,15 +184,111 @@ impl Default for SeptalGateConfig {
             credit_default_threshold: 100,
             reputation_weight: 0.3,
             reputation_threshold: 0.5,
+            probe_jitter_fraction: default_probe_jitter_fraction(),
+            isolation_threshold: default_isolation_threshold(),
+            reset_threshold: default_reset_threshold(),
+            entropy_threshold_scale: 0.0,
         }
     }
 }
 
+/// Why a [`SeptalGateConfig`] was rejected
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfigError {
+    #[error("{field} out of range: {value} (expected {expected})")]
+    OutOfRange {
+        field: &'static str,
+        value: f64,
+        expected: &'static str,
+    },
+    #[error("weights sum to {sum}, expected 1.0")]
+    WeightsNotNormalized { sum: f64 },
+    #[error("reset_threshold {reset} is above isolation_threshold {isolation}")]
+    InvertedHysteresis { reset: f64, isolation: f64 },
+}
+
+/// Reject a value outside [0, 1] (NaN included)
+fn check_unit(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    if (0.0..=1.0).contains(&value) {
+        return Ok(());
+    }
+    Err(ConfigError::OutOfRange {
+        field,
+        value,
+        expected: "0 to 1",
+    })
+}
+
 impl SeptalGateConfig {
-    /// Constraint: weights_sum from dol/core.dol line 438
+    /// Constraint: weights_sum from dol/core.dol line 438, plus sane
+    /// thresholds; see [`Self::validate`]
     pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Check every field is in range, naming the first one that isn't
+    ///
+    /// Weights, scores, and fractions must lie in [0, 1] with the weights
+    /// summing to 1.0; thresholds must be positive; the reset threshold
+    /// can't sit above the isolation threshold.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_unit("timeout_weight", self.timeout_weight)?;
+        check_unit("credit_default_weight", self.credit_default_weight)?;
+        check_unit("reputation_weight", self.reputation_weight)?;
         let sum = self.timeout_weight + self.credit_default_weight + self.reputation_weight;
-        (sum - 1.0).abs() < 0.001
+        if (sum - 1.0).abs() >= 0.001 {
+            return Err(ConfigError::WeightsNotNormalized { sum });
+        }
+
+        if self.timeout_threshold.millis == 0 {
+            return Err(ConfigError::OutOfRange {
+                field: "timeout_threshold",
+                value: 0.0,
+                expected: "positive",
+            });
+        }
+        if self.credit_default_threshold == 0 {
+            return Err(ConfigError::OutOfRange {
+                field: "credit_default_threshold",
+                value: 0.0,
+                expected: "positive",
+            });
+        }
+        check_unit("reputation_threshold", self.reputation_threshold)?;
+        check_unit("probe_jitter_fraction", self.probe_jitter_fraction)?;
+        check_unit("isolation_threshold", self.isolation_threshold)?;
+        check_unit("reset_threshold", self.reset_threshold)?;
+        if !(self.entropy_threshold_scale >= 0.0 && self.entropy_threshold_scale.is_finite()) {
+            return Err(ConfigError::OutOfRange {
+                field: "entropy_threshold_scale",
+                value: self.entropy_threshold_scale,
+                expected: "finite and non-negative",
+            });
+        }
+
+        if self.reset_threshold > self.isolation_threshold {
+            return Err(ConfigError::InvertedHysteresis {
+                reset: self.reset_threshold,
+                isolation: self.isolation_threshold,
+            });
+        }
+        Ok(())
+    }
+
+    /// Failures needed to trip a gate at the given network entropy
+    ///
+    /// Turbulent networks produce more transient failures, so the threshold
+    /// rises linearly with entropy (clamped to [0, `MAX_COMPONENT`]) by
+    /// `entropy_threshold_scale`. Never below `FAILURE_THRESHOLD`.
+    pub fn failure_threshold(&self, network_entropy: f64) -> u32 {
+        let level = if network_entropy.is_nan() {
+            0.0
+        } else {
+            network_entropy.clamp(0.0, EntropyAccount::MAX_COMPONENT)
+                / EntropyAccount::MAX_COMPONENT
+        };
+        let scale = 1.0 + self.entropy_threshold_scale.max(0.0) * level;
+        (FAILURE_THRESHOLD as f64 * scale).round() as u32
     }
 }
 
@@ -175,7 +313,12 @@ impl HealthStatus {
     /// Check if should isolate based on config
     /// From dol/septal.dol lines 99-117
     pub fn should_isolate(&self, config: &SeptalGateConfig) -> bool {
-        self.weighted_score(config) >= ISOLATION_THRESHOLD
+        self.weighted_score(config) >= config.isolation_threshold
+    }
+
+    /// Check if the score has fallen below the reset threshold
+    pub fn should_reset(&self, config: &SeptalGateConfig) -> bool {
+        self.weighted_score(config) < config.reset_threshold
     }
 }
 
@@ -194,13 +337,24 @@ pub fn transition_gate(
     gate: &mut SeptalGate,
     health: &HealthStatus,
     config: &SeptalGateConfig,
+) -> Option<SeptalGateTransition> {
+    transition_gate_at(gate, health, config, 0.0)
+}
+
+/// [`transition_gate`] with the trip threshold scaled by the current
+/// network entropy
+pub fn transition_gate_at(
+    gate: &mut SeptalGate,
+    health: &HealthStatus,
+    config: &SeptalGateConfig,
+    network_entropy: f64,
 ) -> Option<SeptalGateTransition> {
     match gate.state {
         SeptalGateState::Open => {
             if health.should_isolate(config) {
                 gate.record_failure();
 
-                if gate.should_trip() {
+                if gate.should_trip_at(config, network_entropy) {
                     let transition = SeptalGateTransition {
                         from_state: SeptalGateState::Open,
                         to_state: SeptalGateState::Closed,
@@ -213,9 +367,10 @@ pub fn transition_gate(
                     gate.trip();
                     return Some(transition);
                 }
-            } else {
+            } else if health.should_reset(config) {
                 gate.record_success();
             }
+            // Inside the hysteresis band: keep the failure count as is
             None
         }
         SeptalGateState::Closed => {
@@ -231,7 +386,7 @@ pub fn transition_gate(
             }
         }
         SeptalGateState::HalfOpen => {
-            if health.is_healthy {
+            if health.is_healthy && health.should_reset(config) {
                 let transition = SeptalGateTransition {
                     from_state: SeptalGateState::HalfOpen,
                     to_state: SeptalGateState::Open,
@@ -254,6 +409,16 @@ pub fn transition_gate(
     }
 }
 
+/// Expected loss from isolating a node that was actually healthy
+///
+/// `(1 - failure_confidence) * node_value`, where `node_value` is the work
+/// and liquidity lost while the node is cut off. Operators can weigh this
+/// against the cost of routing to a faulty node when tuning thresholds.
+pub fn isolation_cost_estimate(gate: &SeptalGate, node_value: Credits) -> Credits {
+    let false_positive = 1.0 - gate.failure_confidence();
+    Credits::from_f64_saturating(node_value.amount as f64 * false_positive)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,6 +442,21 @@ mod tests {
         assert!(!gate.state.allows_traffic());
     }
 
+    #[test]
+    fn test_isolation_cost_estimate() {
+        let mut gate = SeptalGate::new(NodeId::from_bytes([1u8; 32]));
+        let value = Credits::new(1000);
+
+        // No evidence of failure: isolating loses the full value
+        assert_eq!(isolation_cost_estimate(&gate, value), value);
+
+        gate.failure_count = FAILURE_THRESHOLD;
+        assert_eq!(isolation_cost_estimate(&gate, value), Credits::new(500));
+
+        gate.failure_count = FAILURE_THRESHOLD * 3;
+        assert_eq!(isolation_cost_estimate(&gate, value), Credits::new(250));
+    }
+
     #[test]
     fn test_health_status_isolation() {
         let config = SeptalGateConfig::default();
@@ -312,6 +492,38 @@ mod tests {
             ..Default::default()
         };
         assert!(!invalid.is_valid());
+        assert!(matches!(
+            invalid.validate(),
+            Err(ConfigError::WeightsNotNormalized { .. })
+        ));
+
+        let no_threshold = SeptalGateConfig {
+            credit_default_threshold: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            no_threshold.validate(),
+            Err(ConfigError::OutOfRange {
+                field: "credit_default_threshold",
+                ..
+            })
+        ));
+
+        let bad_reputation = SeptalGateConfig {
+            reputation_threshold: 1.5,
+            ..Default::default()
+        };
+        let err = bad_reputation.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "reputation_threshold out of range: 1.5 (expected 0 to 1)"
+        );
+
+        let nan_scale = SeptalGateConfig {
+            entropy_threshold_scale: f64::NAN,
+            ..Default::default()
+        };
+        assert!(!nan_scale.is_valid());
     }
 
     #[test]
@@ -337,4 +549,61 @@ mod tests {
         assert_eq!(t.from_state, SeptalGateState::Open);
         assert_eq!(t.to_state, SeptalGateState::Closed);
     }
+
+    #[test]
+    fn test_hysteresis_band() {
+        let node = NodeId::from_bytes([1u8; 32]);
+        let config = SeptalGateConfig::default();
+        let scored = |score: f64| HealthStatus {
+            is_healthy: true,
+            timeout_score: score,
+            credit_score: score,
+            reputation_score: score,
+            last_check: Timestamp::now(),
+        };
+
+        // 0.6 sits between reset (0.5) and trip (0.7)
+        let marginal = scored(0.6);
+        assert!(!marginal.should_isolate(&config));
+        assert!(!marginal.should_reset(&config));
+
+        let mut gate = SeptalGate::new(node);
+        gate.failure_count = 2;
+        transition_gate(&mut gate, &marginal, &config);
+        assert_eq!(gate.failure_count, 2);
+        transition_gate(&mut gate, &scored(0.2), &config);
+        assert_eq!(gate.failure_count, 0);
+
+        // A marginal node in half-open doesn't recover
+        gate.state = SeptalGateState::HalfOpen;
+        let t = transition_gate(&mut gate, &marginal, &config).unwrap();
+        assert_eq!(t.to_state, SeptalGateState::Closed);
+
+        let inverted = SeptalGateConfig {
+            reset_threshold: 0.8,
+            ..Default::default()
+        };
+        assert!(!inverted.is_valid());
+    }
+
+    #[test]
+    fn test_entropy_scaled_threshold() {
+        let fixed = SeptalGateConfig::default();
+        assert_eq!(fixed.failure_threshold(10.0), FAILURE_THRESHOLD);
+
+        let scaled = SeptalGateConfig {
+            entropy_threshold_scale: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(scaled.failure_threshold(0.0), FAILURE_THRESHOLD);
+        assert_eq!(scaled.failure_threshold(f64::NAN), FAILURE_THRESHOLD);
+        assert_eq!(scaled.failure_threshold(10.0), FAILURE_THRESHOLD * 2);
+        assert_eq!(scaled.failure_threshold(50.0), FAILURE_THRESHOLD * 2);
+
+        let mut gate = SeptalGate::new(NodeId::from_bytes([1u8; 32]));
+        gate.failure_count = FAILURE_THRESHOLD;
+        assert!(gate.should_trip_at(&scaled, 0.0));
+        assert!(!gate.should_trip_at(&scaled, 8.0));
+        assert!(gate.should_trip_at(&fixed, 8.0));
+    }
 }
@@ -5,6 +5,25 @@
 use super::gate::{HealthStatus, SeptalGate, SeptalGateConfig, SeptalGateState};
 use super::woronin::WoroninManager;
 use crate::core::{NodeId, Timestamp};
+use rand::Rng;
+
+/// Randomize a probe interval by up to `fraction` in either direction
+///
+/// Spreads probes from many nodes so they don't synchronize on a
+/// recovering target. The fraction is clamped to [0, 1].
+pub fn jittered_interval_ms<R: Rng + ?Sized>(base_ms: u64, fraction: f64, rng: &mut R) -> u64 {
+    let fraction = if fraction.is_finite() {
+        fraction.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let spread = (base_ms as f64 * fraction) as u64;
+    if spread == 0 {
+        return base_ms;
+    }
+    let offset = rng.gen_range(0..=spread * 2);
+    base_ms.saturating_sub(spread).saturating_add(offset)
+}
 
 /// Health checker trait
 pub trait HealthChecker {
@@ -15,6 +34,8 @@ pub trait HealthChecker {
 pub struct HealingManager<H: HealthChecker> {
     health_checker: H,
     last_check: std::collections::HashMap<NodeId, Timestamp>,
+    /// Jittered interval until the next probe, per target
+    next_interval_ms: std::collections::HashMap<NodeId, u64>,
     check_interval_ms: u64,
 }
 
@@ -23,6 +44,7 @@ impl<H: HealthChecker> HealingManager<H> {
         Self {
             health_checker,
             last_check: std::collections::HashMap::new(),
+            next_interval_ms: std::collections::HashMap::new(),
             check_interval_ms,
         }
     }
@@ -32,7 +54,12 @@ impl<H: HealthChecker> HealingManager<H> {
         match self.last_check.get(node) {
             Some(last) => {
                 let now = Timestamp::now();
-                now.millis >= last.millis + self.check_interval_ms
+                let interval = self
+                    .next_interval_ms
+                    .get(node)
+                    .copied()
+                    .unwrap_or(self.check_interval_ms);
+                now.millis >= last.millis + interval
             }
             None => true,
         }
@@ -57,8 +84,16 @@ impl<H: HealthChecker> HealingManager<H> {
             return RecoveryResult::TooSoon;
         }
 
-        // Record check time
+        // Record check time and schedule the next probe with jitter
         self.last_check.insert(node, Timestamp::now());
+        self.next_interval_ms.insert(
+            node,
+            jittered_interval_ms(
+                self.check_interval_ms,
+                config.probe_jitter_fraction,
+                &mut rand::thread_rng(),
+            ),
+        );
 
         // Check health
         let health = self.health_checker.check_health(&node);
@@ -74,7 +109,7 @@ impl<H: HealthChecker> HealingManager<H> {
             }
             SeptalGateState::HalfOpen => {
                 // Test recovery
-                if health.is_healthy && !health.should_isolate(config) {
+                if health.is_healthy && health.should_reset(config) {
                     gate.recover();
                     woronin.deactivate(&node);
                     RecoveryResult::Recovered
@@ -86,6 +121,25 @@ impl<H: HealthChecker> HealingManager<H> {
             SeptalGateState::Open => RecoveryResult::NotNeeded,
         }
     }
+
+    /// Attempt recovery on every gate, returning the nodes that recovered
+    ///
+    /// Callers should announce the returned nodes (e.g. via a
+    /// `RecoveryNotice`) so routing to them resumes network-wide.
+    pub fn attempt_recovery_all(
+        &mut self,
+        gates: &mut std::collections::HashMap<NodeId, SeptalGate>,
+        woronin: &mut WoroninManager,
+        config: &SeptalGateConfig,
+    ) -> Vec<NodeId> {
+        gates
+            .values_mut()
+            .filter_map(|gate| {
+                (self.attempt_recovery(gate, woronin, config) == RecoveryResult::Recovered)
+                    .then_some(gate.node)
+            })
+            .collect()
+    }
 }
 
 /// Result of a recovery attempt
@@ -125,6 +179,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_jittered_interval_bounds() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(jittered_interval_ms(1000, 0.0, &mut rng), 1000);
+        assert_eq!(jittered_interval_ms(0, 0.5, &mut rng), 0);
+        assert_eq!(jittered_interval_ms(1000, f64::NAN, &mut rng), 1000);
+
+        let samples: Vec<u64> = (0..100)
+            .map(|_| jittered_interval_ms(1000, 0.2, &mut rng))
+            .collect();
+        assert!(samples.iter().all(|ms| (800..=1200).contains(ms)));
+        assert!(samples.iter().any(|ms| *ms != samples[0]));
+
+        // Fraction is clamped to 1.0
+        assert!(jittered_interval_ms(1000, 5.0, &mut rng) <= 2000);
+    }
+
     #[test]
     fn test_recovery_not_needed() {
         let node = NodeId::from_bytes([1u8; 32]);
@@ -157,6 +231,33 @@ mod tests {
         assert!(!woronin.is_isolated(&node));
     }
 
+    #[test]
+    fn test_attempt_recovery_all() {
+        let recovering = NodeId::from_bytes([1u8; 32]);
+        let closed = NodeId::from_bytes([2u8; 32]);
+        let open = NodeId::from_bytes([3u8; 32]);
+
+        let mut gates = std::collections::HashMap::new();
+        let mut gate = SeptalGate::new(recovering);
+        gate.state = SeptalGateState::HalfOpen;
+        gates.insert(recovering, gate);
+        let mut gate = SeptalGate::new(closed);
+        gate.trip();
+        gates.insert(closed, gate);
+        gates.insert(open, SeptalGate::new(open));
+
+        let mut woronin = WoroninManager::new();
+        woronin.activate(recovering, "test");
+
+        let config = SeptalGateConfig::default();
+        let checker = MockHealthChecker { healthy: true };
+        let mut manager = HealingManager::new(checker, 0);
+
+        let recovered = manager.attempt_recovery_all(&mut gates, &mut woronin, &config);
+        assert_eq!(recovered, vec![recovering]);
+        assert!(!woronin.is_isolated(&recovering));
+    }
+
     #[test]
     fn test_recovery_failed() {
         let node = NodeId::from_bytes([1u8; 32]);
@@ -2,7 +2,7 @@
 //!
 //! Implements Woronin body from dol/septal.dol lines 221-275
 
-use crate::core::{NodeId, Timestamp};
+use crate::core::{Duration, NodeId, Timestamp};
 use serde::{Deserialize, Serialize};
 
 /// WoroninBody - from dol/septal.dol line 221
@@ -63,6 +63,23 @@ impl WoroninManager {
         self.active_bodies.remove(node)
     }
 
+    /// Deactivate every body active longer than `max_isolation`
+    ///
+    /// Returns the released nodes so callers can announce the recovery
+    /// and resume routing to them.
+    pub fn sweep_timed_out(&mut self, max_isolation: Duration) -> Vec<NodeId> {
+        let timed_out: Vec<NodeId> = self
+            .active_bodies
+            .iter()
+            .filter(|(_, body)| body.duration_active() > max_isolation.millis)
+            .map(|(node, _)| *node)
+            .collect();
+        for node in &timed_out {
+            self.active_bodies.remove(node);
+        }
+        timed_out
+    }
+
     /// Check if a node is isolated
     pub fn is_isolated(&self, node: &NodeId) -> bool {
         self.active_bodies.contains_key(node)
@@ -134,4 +151,22 @@ mod tests {
         assert!(body.is_some());
         assert!(!manager.is_isolated(&node));
     }
+
+    #[test]
+    fn test_sweep_timed_out() {
+        let mut manager = WoroninManager::new();
+        let stale = NodeId::from_bytes([1u8; 32]);
+        let fresh = NodeId::from_bytes([2u8; 32]);
+
+        manager.activate(stale, "timeout");
+        manager.activate(fresh, "timeout");
+        manager.get_mut(&stale).unwrap().activated_at =
+            Timestamp::new(Timestamp::now().millis - 120_000);
+
+        let recovered = manager.sweep_timed_out(Duration::seconds(60));
+        assert_eq!(recovered, vec![stale]);
+        assert!(!manager.is_isolated(&stale));
+        assert!(manager.is_isolated(&fresh));
+        assert!(manager.sweep_timed_out(Duration::seconds(60)).is_empty());
+    }
 }
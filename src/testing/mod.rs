@@ -9,8 +9,19 @@
 //! 4. CascadeFailure: Multiple sequential node failures
 //! 5. ByzantineNexus: Nexus node behaves maliciously
 //! 6. EntropySpike: Sudden entropy surge
+//!
+//! [`EconomicScenario`] exercises pricing, transfers, and revival together.
+//! Seeded clusters keep a [`ChaosRecording`] that replays a run exactly.
+
+pub mod recording;
+pub mod scenario;
+
+pub use recording::{ChaosRecording, RecordedChaos};
+pub use scenario::EconomicScenario;
 
 use crate::core::{Credits, NodeId, Timestamp};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 /// Chaos scenario types
@@ -68,7 +79,7 @@ impl ChaosEvent {
 }
 
 /// Test cluster configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TestClusterConfig {
     pub node_count: usize,
     pub nexus_count: usize,
@@ -90,7 +101,7 @@ impl Default for TestClusterConfig {
 }
 
 /// Simulated node for testing
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SimulatedNode {
     pub id: NodeId,
     pub credits: Credits,
@@ -132,10 +143,18 @@ pub struct TestCluster {
     pub config: TestClusterConfig,
     pub nodes: Vec<SimulatedNode>,
     pub chaos_events: Vec<ChaosEvent>,
+    rng: StdRng,
+    recording: ChaosRecording,
 }
 
 impl TestCluster {
+    /// Cluster with a random seed; see [`Self::recording`] to recover it
     pub fn new(config: TestClusterConfig) -> Self {
+        Self::seeded(config, rand::random())
+    }
+
+    /// Cluster whose random chaos is fully determined by `seed`
+    pub fn seeded(config: TestClusterConfig, seed: u64) -> Self {
         let mut nodes = Vec::with_capacity(config.node_count);
 
         // Create nodes
@@ -148,12 +167,28 @@ impl TestCluster {
         }
 
         Self {
+            recording: ChaosRecording::new(seed, config.clone()),
             config,
             nodes,
             chaos_events: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
+    /// Seed and every event injected so far
+    pub fn recording(&self) -> &ChaosRecording {
+        &self.recording
+    }
+
+    fn next_draw(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn record(&mut self, event: ChaosEvent, draws: Vec<u64>) {
+        self.chaos_events.push(event.clone());
+        self.recording.steps.push(RecordedChaos { event, draws });
+    }
+
     /// Get alive nodes
     pub fn alive_nodes(&self) -> Vec<&SimulatedNode> {
         self.nodes.iter().filter(|n| n.is_alive).collect()
@@ -175,6 +210,66 @@ impl TestCluster {
 
     /// Inject chaos event
     pub fn inject_chaos(&mut self, event: ChaosEvent) {
+        self.apply_chaos(&event);
+        self.record(event, Vec::new());
+    }
+
+    /// Inject a chaos event drawn from the cluster's RNG
+    ///
+    /// Picks a scenario, then up to `failure_rate` of the cluster (at least
+    /// one node) to affect. Nexus scenarios target nexuses when any exist.
+    pub fn inject_random_chaos(&mut self) -> ChaosEvent {
+        const SCENARIOS: [ChaosScenario; 6] = [
+            ChaosScenario::NexusFailure,
+            ChaosScenario::NetworkPartition,
+            ChaosScenario::CreditExhaustion,
+            ChaosScenario::CascadeFailure,
+            ChaosScenario::ByzantineNexus,
+            ChaosScenario::EntropySpike,
+        ];
+
+        let mut draws = Vec::new();
+        let mut draw = |cluster: &mut Self| {
+            let value = cluster.next_draw();
+            draws.push(value);
+            value
+        };
+
+        let scenario = SCENARIOS[(draw(self) % SCENARIOS.len() as u64) as usize];
+
+        let nexuses: Vec<NodeId> = self
+            .nodes
+            .iter()
+            .filter(|n| n.is_nexus)
+            .map(|n| n.id)
+            .collect();
+        let candidates: Vec<NodeId> = match scenario {
+            ChaosScenario::NexusFailure | ChaosScenario::ByzantineNexus if !nexuses.is_empty() => {
+                nexuses
+            }
+            _ => self.nodes.iter().map(|n| n.id).collect(),
+        };
+
+        let mut affected = Vec::new();
+        if !candidates.is_empty() {
+            let max_affected =
+                ((self.nodes.len() as f64 * self.config.failure_rate).ceil() as u64).max(1);
+            let count = 1 + draw(self) % max_affected;
+            for _ in 0..count {
+                let pick = candidates[(draw(self) % candidates.len() as u64) as usize];
+                if !affected.contains(&pick) {
+                    affected.push(pick);
+                }
+            }
+        }
+
+        let event = ChaosEvent::new(scenario).with_nodes(affected);
+        self.apply_chaos(&event);
+        self.record(event.clone(), draws);
+        event
+    }
+
+    fn apply_chaos(&mut self, event: &ChaosEvent) {
         match event.scenario {
             ChaosScenario::NexusFailure => {
                 for node_id in &event.affected_nodes {
@@ -217,7 +312,6 @@ impl TestCluster {
                 // Entropy spike doesn't directly affect nodes
             }
         }
-        self.chaos_events.push(event);
     }
 
     /// Check invariants
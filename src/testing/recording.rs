@@ -0,0 +1,105 @@
+//! Chaos Recordings
+//!
+//! A seeded chaos run captured as data, so a failure found by a random run
+//! can be saved and reproduced exactly.
+
+use super::{ChaosEvent, TestCluster, TestClusterConfig};
+use serde::{Deserialize, Serialize};
+
+/// One injected chaos event and the RNG draws that produced it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedChaos {
+    pub event: ChaosEvent,
+    /// Raw RNG values drawn to build the event (empty if injected directly)
+    pub draws: Vec<u64>,
+}
+
+/// Full history of a seeded chaos run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChaosRecording {
+    pub seed: u64,
+    pub config: TestClusterConfig,
+    pub steps: Vec<RecordedChaos>,
+}
+
+impl ChaosRecording {
+    pub fn new(seed: u64, config: TestClusterConfig) -> Self {
+        Self {
+            seed,
+            config,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Total RNG draws across all steps
+    pub fn draw_count(&self) -> usize {
+        self.steps.iter().map(|s| s.draws.len()).sum()
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Rebuild the recorded cluster by re-injecting every event in order
+    ///
+    /// The RNG is advanced through the recorded draws as it goes, so the
+    /// replayed cluster continues exactly as the original would have.
+    ///
+    /// # Panics
+    ///
+    /// If a regenerated draw differs from the recording, meaning the RNG
+    /// no longer reproduces this seed.
+    pub fn replay(&self) -> TestCluster {
+        let mut cluster = TestCluster::seeded(self.config.clone(), self.seed);
+        for step in &self.steps {
+            for (i, recorded) in step.draws.iter().enumerate() {
+                let drawn = cluster.next_draw();
+                assert_eq!(
+                    drawn, *recorded,
+                    "replay diverged at draw {i} of {:?}",
+                    step.event.scenario
+                );
+            }
+            cluster.apply_chaos(&step.event);
+            cluster.record(step.event.clone(), step.draws.clone());
+        }
+        cluster
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recording_replays_exactly() {
+        let config = TestClusterConfig {
+            node_count: 20,
+            nexus_count: 4,
+            ..Default::default()
+        };
+        let mut original = TestCluster::seeded(config, 42);
+        for _ in 0..5 {
+            original.inject_random_chaos();
+        }
+
+        let saved = original.recording().to_json().unwrap();
+        let recording = ChaosRecording::from_json(&saved).unwrap();
+        assert_eq!(&recording, original.recording());
+        assert!(recording.draw_count() > 0);
+
+        let mut replayed = recording.replay();
+        assert_eq!(replayed.nodes, original.nodes);
+        assert_eq!(replayed.recording(), original.recording());
+
+        // Both clusters continue identically after the replay
+        let next = original.inject_random_chaos();
+        let replayed_next = replayed.inject_random_chaos();
+        assert_eq!(replayed_next.scenario, next.scenario);
+        assert_eq!(replayed_next.affected_nodes, next.affected_nodes);
+    }
+}
@@ -0,0 +1,383 @@
+//! Economic Scenario
+//!
+//! End-to-end harness for the full transaction lifecycle: quote a price,
+//! transfer credits, tax into the revival pool, and redistribute — checking
+//! that credits are conserved across the whole cycle. With the `bridge`
+//! feature, transfers can also run through per-node [`EnrBridge`]s that
+//! gossip over an in-memory bus.
+
+#[cfg(feature = "bridge")]
+use std::sync::{Arc, Mutex};
+
+use super::{InvariantViolation, SimulatedNode};
+#[cfg(feature = "bridge")]
+use crate::bridge::{EnrBridge, EnrBridgeConfig};
+use crate::core::{CreditLedger, Credits, EnrError, EnrResult, NodeId};
+use crate::entropy::EntropyAccount;
+use crate::pricing::{PriceQuote, Pricer};
+use crate::revival::{plan_redistribution, NodeMetricsProvider, RedistributionPlan, RevivalPool};
+
+/// Published `(topic, data)` messages awaiting delivery to every bridge
+#[cfg(feature = "bridge")]
+type GossipBus = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+/// Simulated economy wiring pricing, transfers, and the revival pool
+pub struct EconomicScenario {
+    pub pricer: Pricer,
+    pub pool: RevivalPool,
+    pub nodes: Vec<SimulatedNode>,
+    /// Entropy applied to every quote
+    pub entropy: EntropyAccount,
+    /// Transactions that settled (buyer could afford the quote)
+    pub completed: usize,
+    initial_supply: Credits,
+    /// One bridge per node, in node order, once [`Self::with_bridges`] runs
+    #[cfg(feature = "bridge")]
+    bridges: Vec<EnrBridge>,
+    #[cfg(feature = "bridge")]
+    bus: GossipBus,
+}
+
+impl EconomicScenario {
+    /// Create `node_count` nodes with `initial_credits` each; the first
+    /// `nexus_count` are nexuses
+    pub fn new(node_count: usize, nexus_count: usize, initial_credits: Credits) -> Self {
+        let nodes: Vec<SimulatedNode> = (0..node_count)
+            .map(|i| {
+                let mut id_bytes = [0u8; 32];
+                id_bytes[0..8].copy_from_slice(&(i as u64).to_le_bytes());
+                SimulatedNode::new(
+                    NodeId::from_bytes(id_bytes),
+                    initial_credits,
+                    i < nexus_count,
+                )
+            })
+            .collect();
+        let initial_supply = Credits::new(initial_credits.amount * node_count as u64);
+
+        Self {
+            pricer: Pricer::new(),
+            pool: RevivalPool::new(),
+            nodes,
+            entropy: EntropyAccount::default(),
+            completed: 0,
+            initial_supply,
+            #[cfg(feature = "bridge")]
+            bridges: Vec::new(),
+            #[cfg(feature = "bridge")]
+            bus: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn with_entropy(mut self, entropy: EntropyAccount) -> Self {
+        self.entropy = entropy;
+        self
+    }
+
+    pub fn with_pricer(mut self, pricer: Pricer) -> Self {
+        self.pricer = pricer;
+        self
+    }
+
+    /// Credits held by nodes plus everything in the revival pool
+    pub fn total_supply(&self) -> Credits {
+        let held: u64 = self.nodes.iter().map(|n| n.credits.amount).sum();
+        Credits::new(held + self.pool.total_balance().amount)
+    }
+
+    /// Buyer pays the all-in quote; the seller receives it minus the
+    /// entropy tax, which goes to the revival pool
+    ///
+    /// Returns `None` if the buyer can't afford the quote.
+    pub fn transact(&mut self, buyer: usize, seller: usize) -> Option<PriceQuote> {
+        let quote = self.pricer.quote_all_in(&self.entropy);
+        if buyer == seller || self.nodes[buyer].credits < quote.total_price {
+            return None;
+        }
+
+        self.nodes[buyer].credits = self.nodes[buyer].credits.saturating_sub(quote.total_price);
+        let proceeds = quote.total_price.saturating_sub(quote.entropy_tax);
+        self.nodes[seller].credits = self.nodes[seller].credits.saturating_add(proceeds);
+        self.pool.add_tax(quote.entropy_tax);
+        self.completed += 1;
+        Some(quote)
+    }
+
+    /// Run `n` transactions, each node buying from the next in turn
+    pub fn run(&mut self, n: usize) {
+        let count = self.nodes.len();
+        if count < 2 {
+            return;
+        }
+        for i in 0..n {
+            self.transact(i % count, (i + 1) % count);
+        }
+    }
+
+    /// Plan and apply a redistribution of the pool
+    ///
+    /// Anything planned but unallocated (no eligible recipients, rounding)
+    /// is moved to the reserve buffer so no credits leave the system.
+    pub fn redistribute(&mut self) -> RedistributionPlan {
+        let plan = plan_redistribution(&self.pool, self);
+        let mut pool = std::mem::take(&mut self.pool);
+        // Recipients come from our own nodes and budgets from our own pool
+        plan.apply_to(self, &mut pool)
+            .expect("redistribution plan applies to its own scenario");
+        self.pool = pool;
+        plan
+    }
+
+    /// Check that total supply still equals the initial supply
+    pub fn check_conservation(&self) -> Option<InvariantViolation> {
+        let actual = self.total_supply();
+        (actual != self.initial_supply).then_some(InvariantViolation::CreditConservationViolation {
+            expected: self.initial_supply,
+            actual,
+        })
+    }
+
+    fn node(&self, id: &NodeId) -> Option<&SimulatedNode> {
+        self.nodes.iter().find(|n| n.id == *id)
+    }
+
+    fn node_mut(&mut self, id: &NodeId) -> EnrResult<&mut SimulatedNode> {
+        self.nodes
+            .iter_mut()
+            .find(|n| n.id == *id)
+            .ok_or(EnrError::NodeNotFound(*id))
+    }
+}
+
+#[cfg(feature = "bridge")]
+impl EconomicScenario {
+    /// Give every node an [`EnrBridge`] holding its credits
+    ///
+    /// The bridges publish onto a shared in-memory bus that
+    /// [`Self::transact_via_bridge`] delivers to all of them.
+    pub async fn with_bridges(mut self) -> Self {
+        for node in &self.nodes {
+            let mut bridge = EnrBridge::new(node.id, EnrBridgeConfig::default());
+            let bus = self.bus.clone();
+            bridge.connect_publisher(Arc::new(move |topic, data| {
+                bus.lock()
+                    .expect("gossip bus poisoned")
+                    .push((topic.to_string(), data));
+                Ok(())
+            }));
+            bridge.set_balance(node.credits).await;
+            self.bridges.push(bridge);
+        }
+        self
+    }
+
+    /// The bridge for node `index`, if bridges are set up
+    pub fn bridge(&self, index: usize) -> Option<&EnrBridge> {
+        self.bridges.get(index)
+    }
+
+    /// Like [`Self::transact`], but the seller is paid through the buyer's
+    /// bridge
+    ///
+    /// The proceeds move with `EnrBridge::transfer_via_ledger` against
+    /// this scenario as the ledger, and the resulting broadcast (and any
+    /// replies) are gossiped to every bridge. The tax is taken from the
+    /// buyer and its bridge directly. Returns `None` if bridges aren't set
+    /// up, the buyer can't afford the quote, or the transfer fails.
+    pub async fn transact_via_bridge(&mut self, buyer: usize, seller: usize) -> Option<PriceQuote> {
+        let quote = self.pricer.quote_all_in(&self.entropy);
+        if self.bridges.is_empty()
+            || buyer == seller
+            || self.nodes[buyer].credits < quote.total_price
+        {
+            return None;
+        }
+
+        let proceeds = quote.total_price.saturating_sub(quote.entropy_tax);
+        let seller_id = self.nodes[seller].id;
+        // Taken out so the scenario itself can serve as the bridge's ledger
+        let bridges = std::mem::take(&mut self.bridges);
+        let paid = bridges[buyer]
+            .transfer_via_ledger(self, seller_id, proceeds)
+            .await;
+        self.bridges = bridges;
+        paid.ok()?;
+
+        let buyer_id = self.nodes[buyer].id;
+        self.debit(&buyer_id, quote.entropy_tax).ok()?;
+        self.pool.add_tax(quote.entropy_tax);
+        let bridge = &self.bridges[buyer];
+        bridge
+            .set_balance(bridge.balance().await.saturating_sub(quote.entropy_tax))
+            .await;
+
+        self.deliver_gossip().await;
+        self.completed += 1;
+        Some(quote)
+    }
+
+    /// Deliver bus messages to every bridge until none are left
+    ///
+    /// Delivery failures are counted per topic by each bridge.
+    pub async fn deliver_gossip(&self) {
+        loop {
+            let messages = std::mem::take(&mut *self.bus.lock().expect("gossip bus poisoned"));
+            if messages.is_empty() {
+                return;
+            }
+            for (topic, data) in &messages {
+                for bridge in &self.bridges {
+                    let _ = bridge.handle_message(topic, data).await;
+                }
+            }
+        }
+    }
+
+    /// Nodes whose bridge balance differs from the scenario ledger
+    pub async fn bridge_mismatches(&self) -> Vec<NodeId> {
+        let mut mismatched = Vec::new();
+        for (node, bridge) in self.nodes.iter().zip(&self.bridges) {
+            if bridge.balance().await != node.credits {
+                mismatched.push(node.id);
+            }
+        }
+        mismatched
+    }
+}
+
+impl CreditLedger for EconomicScenario {
+    fn balance(&self, node: &NodeId) -> Credits {
+        self.node(node).map_or(Credits::ZERO, |n| n.credits)
+    }
+
+    fn credit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+        let sim = self.node_mut(node)?;
+        sim.credits = sim.credits.saturating_add(amount);
+        Ok(())
+    }
+
+    fn debit(&mut self, node: &NodeId, amount: Credits) -> EnrResult<()> {
+        let sim = self.node_mut(node)?;
+        sim.credits = sim
+            .credits
+            .checked_sub(amount)
+            .ok_or(EnrError::InsufficientCredits {
+                required: amount,
+                available: sim.credits,
+            })?;
+        Ok(())
+    }
+
+    /// Credits held by nodes (the pool is not part of the ledger)
+    fn total_supply(&self) -> Credits {
+        Credits::new(self.nodes.iter().map(|n| n.credits.amount).sum())
+    }
+}
+
+impl NodeMetricsProvider for EconomicScenario {
+    fn get_all_nodes(&self) -> Vec<NodeId> {
+        self.nodes.iter().map(|n| n.id).collect()
+    }
+
+    fn get_nexus_nodes(&self) -> Vec<NodeId> {
+        self.nodes
+            .iter()
+            .filter(|n| n.is_nexus)
+            .map(|n| n.id)
+            .collect()
+    }
+
+    fn get_new_nodes(&self) -> Vec<NodeId> {
+        Vec::new()
+    }
+
+    fn get_uptime(&self, node: &NodeId) -> f64 {
+        self.node(node)
+            .map_or(0.0, |n| if n.is_alive { 1.0 } else { 0.0 })
+    }
+
+    fn get_reputation(&self, node: &NodeId) -> f64 {
+        self.node(node).map_or(0.0, |_| 1.0)
+    }
+
+    fn get_balance(&self, node: &NodeId) -> Credits {
+        self.node(node).map_or(Credits::ZERO, |n| n.credits)
+    }
+
+    fn is_healthy(&self, node: &NodeId) -> bool {
+        self.node(node)
+            .is_some_and(|n| n.is_alive && !n.is_isolated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "bridge")]
+    use crate::core::Timestamp;
+
+    #[test]
+    fn test_full_cycle_conserves_credits() {
+        let mut scenario =
+            EconomicScenario::new(10, 2, Credits::new(10_000)).with_entropy(EntropyAccount {
+                network: 2.0,
+                compute: 1.0,
+                storage: 0.5,
+                temporal: 0.5,
+            });
+
+        scenario.run(100);
+        assert!(scenario.completed > 0);
+        assert!(!scenario.pool.entropy_tax_collected.is_zero());
+        assert_eq!(scenario.check_conservation(), None);
+
+        let plan = scenario.redistribute();
+        assert!(!plan.maintenance_recipients.is_empty());
+        assert!(scenario.pool.available_for_redistribution().is_zero());
+        assert_eq!(scenario.check_conservation(), None);
+    }
+
+    #[cfg(feature = "bridge")]
+    #[tokio::test]
+    async fn test_transactions_via_bridge() {
+        let mut scenario = EconomicScenario::new(4, 1, Credits::new(10_000))
+            .with_entropy(EntropyAccount {
+                network: 2.0,
+                compute: 1.0,
+                storage: 0.5,
+                temporal: 0.5,
+            })
+            .with_bridges()
+            .await;
+
+        for i in 0..8 {
+            assert!(scenario
+                .transact_via_bridge(i % 4, (i + 1) % 4)
+                .await
+                .is_some());
+        }
+        assert_eq!(scenario.completed, 8);
+        assert_eq!(scenario.check_conservation(), None);
+
+        // Every bridge saw every transfer and its confirmation over gossip
+        for i in 0..4 {
+            let bridge = scenario.bridge(i).unwrap();
+            let report = bridge.transfer_report(Timestamp::new(0)).await;
+            assert_eq!(report.confirmed.len(), 8);
+            assert!(bridge
+                .outstanding_outflow(&scenario.nodes[(i + 1) % 4].id)
+                .await
+                .is_zero());
+        }
+        assert!(scenario.bridge_mismatches().await.is_empty());
+        assert!(scenario.transact_via_bridge(0, 0).await.is_none());
+    }
+
+    #[test]
+    fn test_unaffordable_transactions_skip() {
+        let mut scenario = EconomicScenario::new(2, 1, Credits::ZERO);
+        assert!(scenario.transact(0, 1).is_none());
+        assert_eq!(scenario.completed, 0);
+        assert_eq!(scenario.check_conservation(), None);
+    }
+}